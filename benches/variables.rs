@@ -0,0 +1,77 @@
+//! Times variable access on a tight loop: `cargo bench --bench variables`
+//!
+//! Each case reads and writes two variables, which are locals of the function,
+//! variables captured from an enclosing function, or globals. The `loop` cases
+//! run the body in a `for` loop; the `unrolled` cases repeat it inside the
+//! function instead, which is how the bench ran before the language had loops,
+//! and so can be compared with the numbers recorded then. The results are
+//! recorded in the README's Benchmarks section.
+
+extern crate bytelang;
+
+use std::time::{Duration, Instant};
+
+use bytelang::runtime::{EvaluationResult, Runtime};
+
+const ITERATIONS: usize = 100_000;
+const UNROLLED: usize = 1000;
+// how many times the loop body runs in each timing, whichever way it's written
+const TOTAL: usize = 2_000_000;
+// each case is timed this many times and the fastest kept, since the slower
+// runs are mostly measuring whatever else the machine was doing
+const RUNS: usize = 5;
+
+fn bench(name: &str, definition: &str, iterations: usize) {
+    let mut runtime = Runtime::new();
+    match runtime.evaluate(definition) {
+        EvaluationResult::Success(_) => (),
+        result => panic!("{}: {:?}", name, result),
+    }
+
+    let mut fastest = Duration::MAX;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        for _ in 0..TOTAL / iterations {
+            match runtime.evaluate("count()") {
+                EvaluationResult::Success(_) => (),
+                result => panic!("{}: {:?}", name, result),
+            }
+        }
+        fastest = fastest.min(start.elapsed());
+    }
+
+    println!(
+        "{:<18} {:>10.2?} total, {:>8.2?} per iteration",
+        name,
+        fastest,
+        fastest / TOTAL as u32
+    );
+}
+
+fn cases(kind: &str, body: &str, iterations: usize) {
+    bench(
+        &format!("{} locals", kind),
+        &format!("let count = () => {{ let i = 0; let acc = 0; {}; acc }}", body),
+        iterations,
+    );
+    bench(
+        &format!("{} captured", kind),
+        &format!(
+            "let make = () => {{ let i = 0; let acc = 0; () => {{ {}; acc }} }}; \
+             let count = make()",
+            body
+        ),
+        iterations,
+    );
+    bench(
+        &format!("{} globals", kind),
+        &format!("let i = 0; let acc = 0; let count = () => {{ {}; acc }}", body),
+        iterations,
+    );
+}
+
+fn main() {
+    let body = "acc = acc + i; i = i + 1";
+    cases("loop", &format!("for n in 0..{} {{ {} }}", ITERATIONS, body), ITERATIONS);
+    cases("unrolled", &vec![body; UNROLLED].join("; "), UNROLLED);
+}
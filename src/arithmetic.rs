@@ -0,0 +1,334 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+use num_bigint::BigInt;
+use num_traits::{Signed, ToPrimitive, Zero};
+
+use bytecode::Value;
+use pretty_print::pretty_print;
+use vm::EvaluationError;
+
+/// The most bits a big int may be made with by `**` or `<<`, which would
+/// otherwise let a short expression use up all of memory
+pub const MAX_BITS: u64 = 1 << 24;
+
+// the operands of a numeric instruction, promoted to a common representation:
+// ints that overflow are redone as big ints, and anything mixed with a float is a float
+enum Operands {
+    Ints(i64, i64),
+    BigInts(BigInt, BigInt),
+    Floats(f64, f64),
+}
+
+// the operands of a bitwise instruction, which only applies to ints
+enum IntOperands {
+    Ints(i64, i64),
+    BigInts(BigInt, BigInt),
+}
+
+fn big_to_f64(x: &BigInt) -> f64 {
+    x.to_f64().unwrap_or(if x.is_negative() {
+        f64::NEG_INFINITY
+    } else {
+        f64::INFINITY
+    })
+}
+
+/// Big ints that fit in 64 bits are always demoted back to ints
+pub fn normalize(x: BigInt) -> Value {
+    match x.to_i64() {
+        Some(v) => Value::Int(v),
+        None => Value::BigInt(x),
+    }
+}
+
+fn operands(verb: &str, conjunction: &str, a: Value, b: Value) -> Result<Operands, EvaluationError> {
+    match (a, b) {
+        (Value::Int(x), Value::Int(y)) => Ok(Operands::Ints(x, y)),
+        (Value::Int(x), Value::BigInt(y)) => Ok(Operands::BigInts(BigInt::from(x), y)),
+        (Value::BigInt(x), Value::Int(y)) => Ok(Operands::BigInts(x, BigInt::from(y))),
+        (Value::BigInt(x), Value::BigInt(y)) => Ok(Operands::BigInts(x, y)),
+        (Value::Float(x), Value::Float(y)) => Ok(Operands::Floats(x, y)),
+        (Value::Int(x), Value::Float(y)) => Ok(Operands::Floats(x as f64, y)),
+        (Value::Float(x), Value::Int(y)) => Ok(Operands::Floats(x, y as f64)),
+        (Value::BigInt(x), Value::Float(y)) => Ok(Operands::Floats(big_to_f64(&x), y)),
+        (Value::Float(x), Value::BigInt(y)) => Ok(Operands::Floats(x, big_to_f64(&y))),
+        (x, y) => Err(EvaluationError::InvalidOperation(format!(
+            "Can't {} {} {} {}",
+            verb,
+            pretty_print(&x),
+            conjunction,
+            pretty_print(&y)
+        ))),
+    }
+}
+
+fn int_operands(verb: &str, conjunction: &str, a: Value, b: Value) -> Result<IntOperands, EvaluationError> {
+    match (a, b) {
+        (Value::Int(x), Value::Int(y)) => Ok(IntOperands::Ints(x, y)),
+        (Value::Int(x), Value::BigInt(y)) => Ok(IntOperands::BigInts(BigInt::from(x), y)),
+        (Value::BigInt(x), Value::Int(y)) => Ok(IntOperands::BigInts(x, BigInt::from(y))),
+        (Value::BigInt(x), Value::BigInt(y)) => Ok(IntOperands::BigInts(x, y)),
+        (x, y) => Err(EvaluationError::InvalidOperation(format!(
+            "Can't {} {} {} {}",
+            verb,
+            pretty_print(&x),
+            conjunction,
+            pretty_print(&y)
+        ))),
+    }
+}
+
+fn check_divisor(operands: &Operands) -> Result<(), EvaluationError> {
+    let is_zero = match operands {
+        Operands::Ints(_, y) => *y == 0,
+        Operands::BigInts(_, y) => y.is_zero(),
+        Operands::Floats(_, y) => *y == 0.0,
+    };
+    if is_zero {
+        Err(EvaluationError::DivisionByZero)
+    } else {
+        Ok(())
+    }
+}
+
+pub fn add(a: Value, b: Value) -> Result<Value, EvaluationError> {
+    Ok(match operands("add", "and", a, b)? {
+        Operands::Ints(x, y) => match x.checked_add(y) {
+            Some(v) => Value::Int(v),
+            None => normalize(BigInt::from(x) + y),
+        },
+        Operands::BigInts(x, y) => normalize(x + y),
+        Operands::Floats(x, y) => Value::Float(x + y),
+    })
+}
+
+pub fn sub(a: Value, b: Value) -> Result<Value, EvaluationError> {
+    Ok(match operands("subtract", "and", a, b)? {
+        Operands::Ints(x, y) => match x.checked_sub(y) {
+            Some(v) => Value::Int(v),
+            None => normalize(BigInt::from(x) - y),
+        },
+        Operands::BigInts(x, y) => normalize(x - y),
+        Operands::Floats(x, y) => Value::Float(x - y),
+    })
+}
+
+pub fn mul(a: Value, b: Value) -> Result<Value, EvaluationError> {
+    Ok(match operands("multiply", "and", a, b)? {
+        Operands::Ints(x, y) => match x.checked_mul(y) {
+            Some(v) => Value::Int(v),
+            None => normalize(BigInt::from(x) * y),
+        },
+        Operands::BigInts(x, y) => normalize(x * y),
+        Operands::Floats(x, y) => Value::Float(x * y),
+    })
+}
+
+/// Integer division truncates towards zero
+pub fn div(a: Value, b: Value) -> Result<Value, EvaluationError> {
+    let operands = operands("divide", "and", a, b)?;
+    check_divisor(&operands)?;
+    Ok(match operands {
+        Operands::Ints(x, y) => match x.checked_div(y) {
+            Some(v) => Value::Int(v),
+            None => normalize(BigInt::from(x) / y),
+        },
+        Operands::BigInts(x, y) => normalize(x / y),
+        Operands::Floats(x, y) => Value::Float(x / y),
+    })
+}
+
+// floor division and modulo round towards negative infinity, so that
+// `a == (a // b) * b + a % b` holds whatever the signs of the operands
+fn floor_div_big(x: BigInt, y: BigInt) -> BigInt {
+    let quotient = &x / &y;
+    if !(&x % &y).is_zero() && x.is_negative() != y.is_negative() {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
+
+fn floor_mod_big(x: BigInt, y: BigInt) -> BigInt {
+    let remainder = x % &y;
+    if !remainder.is_zero() && remainder.is_negative() != y.is_negative() {
+        remainder + y
+    } else {
+        remainder
+    }
+}
+
+fn floor_mod_float(x: f64, y: f64) -> f64 {
+    let remainder = x % y;
+    if remainder != 0.0 && (remainder < 0.0) != (y < 0.0) {
+        remainder + y
+    } else {
+        remainder
+    }
+}
+
+pub fn floor_div(a: Value, b: Value) -> Result<Value, EvaluationError> {
+    let operands = operands("divide", "and", a, b)?;
+    check_divisor(&operands)?;
+    Ok(match operands {
+        Operands::Ints(x, y) => match x.checked_div(y) {
+            Some(quotient) if x % y != 0 && (x < 0) != (y < 0) => Value::Int(quotient - 1),
+            Some(quotient) => Value::Int(quotient),
+            None => normalize(floor_div_big(BigInt::from(x), BigInt::from(y))),
+        },
+        Operands::BigInts(x, y) => normalize(floor_div_big(x, y)),
+        Operands::Floats(x, y) => Value::Float((x / y).floor()),
+    })
+}
+
+pub fn modulo(a: Value, b: Value) -> Result<Value, EvaluationError> {
+    let operands = operands("take the modulo of", "and", a, b)?;
+    check_divisor(&operands)?;
+    Ok(match operands {
+        Operands::Ints(x, y) => {
+            let remainder = x.wrapping_rem(y);
+            if remainder != 0 && (remainder < 0) != (y < 0) {
+                Value::Int(remainder + y)
+            } else {
+                Value::Int(remainder)
+            }
+        }
+        Operands::BigInts(x, y) => normalize(floor_mod_big(x, y)),
+        Operands::Floats(x, y) => Value::Float(floor_mod_float(x, y)),
+    })
+}
+
+fn pow_big(x: BigInt, y: BigInt) -> Result<Value, EvaluationError> {
+    if y.is_negative() {
+        return Err(EvaluationError::NegativeExponent);
+    }
+    // 0, 1 and -1 stay as small as they are whatever the exponent, so only
+    // whether it's zero or even matters
+    if x.bits() <= 1 {
+        let exponent = if y.is_zero() {
+            0
+        } else if (&y % 2u32).is_zero() {
+            2
+        } else {
+            1
+        };
+        return Ok(normalize(x.pow(exponent)));
+    }
+    match y.to_u64() {
+        Some(exponent) if x.bits().saturating_mul(exponent) <= MAX_BITS => {
+            Ok(normalize(x.pow(exponent as u32)))
+        }
+        _ => Err(EvaluationError::IntegerOverflow),
+    }
+}
+
+pub fn pow(a: Value, b: Value) -> Result<Value, EvaluationError> {
+    match operands("raise", "to the power of", a, b)? {
+        Operands::Ints(x, y) => match u32::try_from(y).ok().and_then(|e| x.checked_pow(e)) {
+            Some(v) => Ok(Value::Int(v)),
+            None => pow_big(BigInt::from(x), BigInt::from(y)),
+        },
+        Operands::BigInts(x, y) => pow_big(x, y),
+        Operands::Floats(x, y) => Ok(Value::Float(x.powf(y))),
+    }
+}
+
+/// Orders two numbers, comparing ints and floats as floats
+pub fn compare(a: &Value, b: &Value) -> Result<Ordering, EvaluationError> {
+    let ordering = match operands("compare", "and", a.clone(), b.clone())? {
+        Operands::Ints(x, y) => Some(x.cmp(&y)),
+        Operands::BigInts(x, y) => Some(x.cmp(&y)),
+        Operands::Floats(x, y) => x.partial_cmp(&y),
+    };
+    ordering.ok_or_else(|| {
+        EvaluationError::InvalidOperation(format!(
+            "Can't compare {} and {}",
+            pretty_print(a),
+            pretty_print(b)
+        ))
+    })
+}
+
+pub fn negate(a: Value) -> Result<Value, EvaluationError> {
+    match a {
+        Value::Int(x) => Ok(match x.checked_neg() {
+            Some(v) => Value::Int(v),
+            None => normalize(-BigInt::from(x)),
+        }),
+        Value::BigInt(x) => Ok(normalize(-x)),
+        Value::Float(x) => Ok(Value::Float(-x)),
+        x => Err(EvaluationError::InvalidOperation(format!(
+            "Can't negate {}",
+            pretty_print(&x)
+        ))),
+    }
+}
+
+pub fn bit_and(a: Value, b: Value) -> Result<Value, EvaluationError> {
+    match int_operands("take the bitwise and of", "and", a, b)? {
+        IntOperands::Ints(x, y) => Ok(Value::Int(x & y)),
+        IntOperands::BigInts(x, y) => Ok(normalize(x & y)),
+    }
+}
+
+pub fn bit_or(a: Value, b: Value) -> Result<Value, EvaluationError> {
+    match int_operands("take the bitwise or of", "and", a, b)? {
+        IntOperands::Ints(x, y) => Ok(Value::Int(x | y)),
+        IntOperands::BigInts(x, y) => Ok(normalize(x | y)),
+    }
+}
+
+pub fn bit_xor(a: Value, b: Value) -> Result<Value, EvaluationError> {
+    match int_operands("take the bitwise xor of", "and", a, b)? {
+        IntOperands::Ints(x, y) => Ok(Value::Int(x ^ y)),
+        IntOperands::BigInts(x, y) => Ok(normalize(x ^ y)),
+    }
+}
+
+fn shift_count(y: BigInt) -> Result<usize, EvaluationError> {
+    if y.is_negative() {
+        return Err(EvaluationError::NegativeShiftCount);
+    }
+    y.to_usize().ok_or(EvaluationError::IntegerOverflow)
+}
+
+/// Shifting left never loses bits: ints that overflow are promoted to big ints
+pub fn shift_left(a: Value, b: Value) -> Result<Value, EvaluationError> {
+    let (x, y) = match int_operands("shift", "by", a, b)? {
+        IntOperands::Ints(x, y) => (BigInt::from(x), BigInt::from(y)),
+        IntOperands::BigInts(x, y) => (x, y),
+    };
+    let count = shift_count(y)?;
+    // zero stays zero however far it's shifted
+    if x.is_zero() {
+        return Ok(Value::Int(0));
+    }
+    if x.bits().saturating_add(count as u64) > MAX_BITS {
+        return Err(EvaluationError::IntegerOverflow);
+    }
+    Ok(normalize(x << count))
+}
+
+/// Shifting right is arithmetic, rounding towards negative infinity
+pub fn shift_right(a: Value, b: Value) -> Result<Value, EvaluationError> {
+    match int_operands("shift", "by", a, b)? {
+        IntOperands::Ints(_, y) if y < 0 => Err(EvaluationError::NegativeShiftCount),
+        IntOperands::Ints(x, y) => Ok(Value::Int(x >> y.min(63))),
+        IntOperands::BigInts(x, y) => {
+            let count = shift_count(y)?;
+            Ok(normalize(x >> count))
+        }
+    }
+}
+
+pub fn bit_not(a: Value) -> Result<Value, EvaluationError> {
+    match a {
+        Value::Int(x) => Ok(Value::Int(!x)),
+        Value::BigInt(x) => Ok(normalize(!x)),
+        x => Err(EvaluationError::InvalidOperation(format!(
+            "Can't take the bitwise not of {}",
+            pretty_print(&x)
+        ))),
+    }
+}
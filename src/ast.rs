@@ -1,9 +1,26 @@
+use num_bigint::BigInt;
+use num_traits::Num;
+
 #[derive(Debug)]
 pub enum Operator {
     Add,
     Sub,
     Mul,
     Div,
+    Mod,
+    FloorDiv,
+    Pow,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
+}
+
+#[derive(Debug)]
+pub enum UnaryOperator {
+    Neg,
+    BitNot,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -25,14 +42,48 @@ pub enum Expr {
     LetAssignment(Identifier, Box<Expr>),
     ConstAssignment(Identifier, Box<Expr>),
     BinaryOp(Box<Expr>, Operator, Box<Expr>),
+    UnaryOp(UnaryOperator, Box<Expr>),
     Function(Vec<Identifier>, Vec<Expr>),
+    Block(Vec<Expr>),
     CallFunction(Identifier, Vec<Expr>),
+    Documented(String, Box<Expr>),
+    // the start and end, and whether the end is included
+    Range(Box<Expr>, Box<Expr>, bool),
+    // the loop variable, what it iterates over, and the body
+    For(Identifier, Box<Expr>, Vec<Expr>),
+    Yield(Box<Expr>),
+    Break,
+    Continue,
+    Throw(Box<Expr>),
+    // the body, then the name of the error and the block that catches it, and
+    // the block that always runs afterwards; at least one of them is given
+    Try(Vec<Expr>, Option<(Identifier, Vec<Expr>)>, Option<Vec<Expr>>),
 }
 
 #[derive(Debug)]
 pub enum Value {
-    Int(i32),
+    Int(i64),
+    BigInt(BigInt),
     Float(f64),
     Bool(bool),
     String(String),
+    Nil,
+}
+
+impl Value {
+    /// Integer literals too large for 64 bits become big ints rather than failing
+    pub fn from_int_literal(literal: &str, negative: bool) -> Value {
+        let (digits, radix) = match literal.get(..2) {
+            Some("0x") | Some("0X") => (&literal[2..], 16),
+            Some("0o") | Some("0O") => (&literal[2..], 8),
+            Some("0b") | Some("0B") => (&literal[2..], 2),
+            _ => (literal, 10),
+        };
+        let sign = if negative { "-" } else { "" };
+        let digits = format!("{}{}", sign, digits.replace("_", ""));
+        match i64::from_str_radix(&digits, radix) {
+            Ok(v) => Value::Int(v),
+            Err(_) => Value::BigInt(BigInt::from_str_radix(&digits, radix).unwrap()),
+        }
+    }
 }
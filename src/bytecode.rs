@@ -1,28 +1,318 @@
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::rc::Rc;
 
-pub type Heap = Vec<Value>;
-pub type SymbolTable = HashMap<String, (usize, i8)>;
+use num_bigint::BigInt;
+
+use vm::{EvaluationError, Generator, VM};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Value {
-    Int(i32),
+    Int(i64),
+    BigInt(BigInt),
     Float(f64),
     Bool(bool),
-    Pointer(u8),
-    Bytes(Vec<u8>),
-    Function(Vec<Instruction>, Heap, SymbolTable),
+    String(Rc<str>),
+    List(Rc<Vec<Value>>),
+    // keys are kept sorted, so that maps print the same way every time
+    Map(Rc<BTreeMap<String, Value>>),
+    Function(Closure),
+    Builtin(Builtin),
+    NativeFunction(NativeFunction),
+    Range(Range),
+    // what a `for` loop is going through, which only ever lives on the stack
+    Iterator(Iter),
+    Generator(Rc<Generator>),
+    Nil,
+}
+
+/// The most items a list that native code builds may hold, so that a huge
+/// range or a generator that never finishes gives an error rather than
+/// exhausting memory
+pub const MAX_LIST_LEN: usize = 1 << 24;
+
+/// How many lists and maps deep a value that native code builds may be, since
+/// dropping, printing and comparing values all recurse into them
+pub const MAX_NESTING: usize = 512;
+
+/// How many lists and maps deep a value is. Each list and map is only looked
+/// into once, recording its depth in `seen`, so that values built out of
+/// copies of themselves don't take exponentially long to measure
+pub fn nesting(value: &Value, seen: &mut HashMap<usize, usize>) -> usize {
+    let key = match value {
+        Value::List(items) => Rc::as_ptr(items) as usize,
+        Value::Map(entries) => Rc::as_ptr(entries) as usize,
+        _ => return 0,
+    };
+    if let Some(depth) = seen.get(&key) {
+        return *depth;
+    }
+    let deepest = match value {
+        Value::List(items) => items.iter().map(|item| nesting(item, seen)).max(),
+        Value::Map(entries) => entries.values().map(|value| nesting(value, seen)).max(),
+        _ => unreachable!(),
+    };
+    let depth = 1 + deepest.unwrap_or(0);
+    seen.insert(key, depth);
+    depth
+}
+
+/// The ints from `start` to `end`, which includes `end` if `inclusive`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Range {
+    pub start: i64,
+    pub end: i64,
+    pub inclusive: bool,
+}
+
+impl Range {
+    // the bounds as a half open range, which can't overflow when wider
+    pub fn bounds(&self) -> (i128, i128) {
+        let end = i128::from(self.end) + if self.inclusive { 1 } else { 0 };
+        (i128::from(self.start), end)
+    }
+
+    /// The number of ints in the range
+    pub fn count(&self) -> i128 {
+        let (start, end) = self.bounds();
+        (end - start).max(0)
+    }
+}
+
+/// The position within a value that a loop has reached
+#[derive(Debug, Clone, PartialEq)]
+pub enum Iter {
+    List(Rc<Vec<Value>>, usize),
+    // the byte position of the next char
+    Chars(Rc<str>, usize),
+    // the next int and the end, which isn't included
+    Range(i128, i128),
+}
+
+impl Iter {
+    /// The next item, or `None` once there are no more
+    pub fn advance(&mut self) -> Option<Value> {
+        match self {
+            Iter::List(items, index) => {
+                let item = items.get(*index).cloned();
+                *index += 1;
+                item
+            }
+            Iter::Chars(s, position) => {
+                let c = s[*position..].chars().next()?;
+                *position += c.len_utf8();
+                Some(Value::String(Rc::from(c.to_string())))
+            }
+            Iter::Range(next, end) => {
+                if next >= end {
+                    return None;
+                }
+                *next += 1;
+                Some(Value::Int((*next - 1) as i64))
+            }
+        }
+    }
+}
+
+/// Functions provided by the runtime, which are predefined globals
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Builtin {
+    Print,
+    Println,
+}
+
+/// Compiled code, along with the constants its instructions refer to by index
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Chunk {
+    pub code: Vec<Instruction>,
+    pub constants: Vec<Value>,
+    // the parts of the code that a `try` covers, innermost first
+    pub handlers: Vec<Handler>,
+    // whether this is the body of a function that yields, which makes calling
+    // the function return a generator rather than run the body
+    pub generator: bool,
+}
+
+/// A range of a chunk's code that a `try` covers, and where to go when running
+/// it fails
+#[derive(Debug, Clone, PartialEq)]
+pub struct Handler {
+    // from `start` up to, but not including, `end`
+    pub start: usize,
+    pub end: usize,
+    pub target: usize,
+    // how many values the frame had on the data stack when the `try` started
+    pub depth: usize,
+    // whether the target is given the error on top of the stack to catch it,
+    // or is a `finally` block that throws it again once it has run
+    pub catches: bool,
+}
+
+/// A variable that closures have captured, which they share with the frame
+/// that declared it, so that each sees the others' assignments
+pub type Cell = Rc<RefCell<Value>>;
+
+/// A function value, along with the variables it captured when it was made
+#[derive(Debug, Clone, PartialEq)]
+pub struct Closure {
+    pub arity: usize,
+    pub chunk: Rc<Chunk>,
+    pub captured: Vec<Cell>,
+}
+
+// natives are given the VM so that they can call the functions they're passed
+pub type NativeFn = dyn Fn(&mut VM, &[Value]) -> Result<Value, EvaluationError>;
+
+/// A Rust function registered by the host, which is given its arguments in order
+#[derive(Clone)]
+pub struct NativeFunction {
+    pub name: Rc<str>,
+    // `None` for functions that take any number of arguments
+    pub arity: Option<usize>,
+    pub function: Rc<NativeFn>,
+}
+
+impl fmt::Debug for NativeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.arity {
+            Some(arity) => write!(f, "NativeFunction({}/{})", self.name, arity),
+            None => write!(f, "NativeFunction({}/*)", self.name),
+        }
+    }
+}
+
+// closures can't be compared, so native functions are only equal to themselves
+impl PartialEq for NativeFunction {
+    fn eq(&self, other: &NativeFunction) -> bool {
+        Rc::ptr_eq(&self.function, &other.function)
+    }
+}
+
+/// Where a function being made finds a variable it captures
+#[derive(Debug, Clone, PartialEq)]
+pub enum Capture {
+    Local(u16),
+    Captured(u16),
+}
+
+/// Top-level variables, which are resolved to an index into this table
+/// at compile time, and kept across evaluations
+#[derive(Debug, Default)]
+pub struct Globals {
+    indices: HashMap<String, u16>,
+    pub names: Vec<String>,
+    pub values: Vec<Option<Value>>,
+}
+
+impl Globals {
+    pub fn new() -> Globals {
+        Globals::default()
+    }
+
+    pub fn resolve(&mut self, name: &str) -> u16 {
+        if let Some(&index) = self.indices.get(name) {
+            return index;
+        }
+        let index = self.names.len() as u16;
+        self.indices.insert(name.to_string(), index);
+        self.names.push(name.to_string());
+        self.values.push(None);
+        index
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Instruction {
-    Push(Value),
-    AssignAndPush(Vec<u8>),
+    Constant(u16),
     Add,
     Sub,
     Mul,
     Div,
-    Lookup(String),
-    SetVar(String),
-    MakeFunction(Vec<Instruction>),
-    Call,
+    Mod,
+    FloorDiv,
+    Pow,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
+    Negate,
+    BitNot,
+    Pop,
+    GetLocal(u16),
+    // declares a new variable in the slot, replacing whatever was there
+    SetLocal(u16),
+    // assigns to the variable in the slot, which closures may share
+    AssignLocal(u16),
+    GetCaptured(u16),
+    SetCaptured(u16),
+    GetGlobal(u16),
+    SetGlobal(u16),
+    MakeFunction(usize, Rc<Chunk>, Vec<Capture>),
+    Call(usize),
+    // whether the end is included
+    MakeRange(bool),
+    // suspends the generator being run, with the value on top of the stack as its next item
+    Yield,
+    // jumps are relative to the jump instruction
+    Jump(isize),
+    // replaces the value on top of the stack with an iterator over it, and starts a loop
+    GetIter,
+    // pushes the iterator's next item, or jumps to the end of the loop if there's none
+    ForIter(isize),
+    // both drop the values pushed since the loop started, then jump
+    Break(isize),
+    Continue(isize),
+    // pops the iterator once its loop has finished
+    EndLoop,
+    // throws the value on top of the stack
+    Throw,
+    // throws the error that its `finally` block was run for again
+    EndFinally,
+}
+
+impl Instruction {
+    /// How many more values there are on the data stack once the instruction
+    /// has run, when it carries on to the next one
+    pub fn stack_effect(&self) -> isize {
+        match *self {
+            Instruction::Constant(_)
+            | Instruction::GetLocal(_)
+            | Instruction::GetCaptured(_)
+            | Instruction::GetGlobal(_)
+            | Instruction::MakeFunction(_, _, _)
+            | Instruction::ForIter(_) => 1,
+            Instruction::Add
+            | Instruction::Sub
+            | Instruction::Mul
+            | Instruction::Div
+            | Instruction::Mod
+            | Instruction::FloorDiv
+            | Instruction::Pow
+            | Instruction::BitAnd
+            | Instruction::BitOr
+            | Instruction::BitXor
+            | Instruction::ShiftLeft
+            | Instruction::ShiftRight
+            | Instruction::Pop
+            | Instruction::SetLocal(_)
+            | Instruction::AssignLocal(_)
+            | Instruction::SetCaptured(_)
+            | Instruction::SetGlobal(_)
+            | Instruction::MakeRange(_)
+            | Instruction::Yield
+            | Instruction::EndLoop
+            | Instruction::Throw => -1,
+            // the function and its arguments are replaced by its result
+            Instruction::Call(argc) => -(argc as isize),
+            Instruction::Negate
+            | Instruction::BitNot
+            | Instruction::Jump(_)
+            | Instruction::GetIter
+            | Instruction::Break(_)
+            | Instruction::Continue(_)
+            | Instruction::EndFinally => 0,
+        }
+    }
 }
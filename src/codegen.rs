@@ -1,78 +1,564 @@
-use ast::{Expr, Operator, Value};
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use ast::{Expr, Identifier, Operator, UnaryOperator, Value};
 use bytecode;
-use bytecode::Instruction;
+use bytecode::{Capture, Chunk, Globals, Handler, Instruction};
 
-fn generate_from_node(node: &Expr) -> Vec<Instruction> {
-    let mut code: Vec<Instruction> = Vec::new();
-    match node {
-        Expr::Literal(ref value) => match value {
-            Value::Int(ref v) => code.push(Instruction::Push(bytecode::Value::Int(*v))),
-            Value::Float(ref v) => code.push(Instruction::Push(bytecode::Value::Float(*v))),
-            Value::Bool(ref v) => code.push(Instruction::Push(bytecode::Value::Bool(*v))),
+fn binary_instruction(op: &Operator) -> Instruction {
+    match op {
+        Operator::Add => Instruction::Add,
+        Operator::Sub => Instruction::Sub,
+        Operator::Mul => Instruction::Mul,
+        Operator::Div => Instruction::Div,
+        Operator::Mod => Instruction::Mod,
+        Operator::FloorDiv => Instruction::FloorDiv,
+        Operator::Pow => Instruction::Pow,
+        Operator::BitAnd => Instruction::BitAnd,
+        Operator::BitOr => Instruction::BitOr,
+        Operator::BitXor => Instruction::BitXor,
+        Operator::ShiftLeft => Instruction::ShiftLeft,
+        Operator::ShiftRight => Instruction::ShiftRight,
+    }
+}
+
+enum Variable {
+    Local(u16),
+    Captured(u16),
+    Global(u16),
+}
+
+// the code covered by a `try` being generated, which has a gap wherever a
+// break or continue leaves it
+struct Protected<'b> {
+    ranges: Vec<(usize, usize)>,
+    start: usize,
+    finally: Option<&'b [Expr]>,
+}
+
+impl<'b> Protected<'b> {
+    fn close(&mut self, end: usize) {
+        if self.start < end {
+            self.ranges.push((self.start, end));
+        }
+        self.start = end;
+    }
+}
+
+// what a break or continue being generated is inside of
+enum Nesting<'b> {
+    // the positions of the loop's breaks and continues, which are filled in
+    // once its end is known
+    Loop(Vec<usize>, Vec<usize>),
+    Try(Protected<'b>),
+}
+
+// the variables of a function being generated: its block scopes, innermost
+// last, map names to slots, and slots are reused once their block ends
+#[derive(Default)]
+struct FunctionScope<'b> {
+    scopes: Vec<HashMap<String, u16>>,
+    next_slot: u16,
+    captures: Vec<(String, Capture)>,
+    constants: Vec<bytecode::Value>,
+    code: Vec<Instruction>,
+    handlers: Vec<Handler>,
+    // how many values the code so far leaves on the data stack
+    depth: isize,
+    nesting: Vec<Nesting<'b>>,
+}
 
-            // string is stored in the heap
-            Value::String(ref v) => {
-                let as_bytes = v.as_bytes();
-                code.push(Instruction::AssignAndPush(as_bytes.to_vec()));
+impl<'b> FunctionScope<'b> {
+    fn local(&self, name: &str) -> Option<u16> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name).cloned())
+    }
+
+    fn captured(&self, name: &str) -> Option<u16> {
+        self.captures
+            .iter()
+            .position(|(captured, _)| captured == name)
+            .map(|index| index as u16)
+    }
+
+    // literals that are equal are only stored once in a chunk's constants
+    fn constant(&mut self, value: bytecode::Value) -> u16 {
+        match self.constants.iter().position(|c| *c == value) {
+            Some(index) => index as u16,
+            None => {
+                self.constants.push(value);
+                self.constants.len() as u16 - 1
             }
-        },
-        Expr::BinaryOp(ref a, Operator::Add, ref b) => {
-            code.extend(generate_from_node(a));
-            code.extend(generate_from_node(b));
-            code.push(Instruction::Add)
         }
-        Expr::BinaryOp(ref a, Operator::Sub, ref b) => {
-            code.extend(generate_from_node(a));
-            code.extend(generate_from_node(b));
-            code.push(Instruction::Sub)
+    }
+
+    fn declare(&mut self, name: &str) -> u16 {
+        let slot = self.next_slot;
+        self.next_slot += 1;
+        self.scopes
+            .last_mut()
+            .unwrap()
+            .insert(name.to_string(), slot);
+        slot
+    }
+}
+
+/// Resolves variables as it generates code: declarations at the top level of
+/// a program are globals, and everything else is a numbered slot in the
+/// frame of the function that declares it. Functions capture the enclosing
+/// functions' variables when they are made, sharing them rather than copying
+/// them, so assignments on either side are seen by both.
+struct Generator<'a, 'b> {
+    globals: &'a mut Globals,
+    // the top level of the program, then each function being generated within it
+    functions: Vec<FunctionScope<'b>>,
+    // every chunk shares a single copy of each distinct string literal
+    strings: HashSet<Rc<str>>,
+}
+
+impl<'a, 'b> Generator<'a, 'b> {
+    fn new(globals: &'a mut Globals) -> Generator<'a, 'b> {
+        Generator {
+            globals,
+            functions: vec![FunctionScope::default()],
+            strings: HashSet::new(),
         }
-        Expr::BinaryOp(ref a, Operator::Div, ref b) => {
-            code.extend(generate_from_node(a));
-            code.extend(generate_from_node(b));
-            code.push(Instruction::Div)
+    }
+
+    fn function(&mut self) -> &mut FunctionScope<'b> {
+        self.functions.last_mut().unwrap()
+    }
+
+    fn position(&self) -> usize {
+        self.functions.last().unwrap().code.len()
+    }
+
+    // adds an instruction to the function being generated, returning its position
+    fn emit(&mut self, instruction: Instruction) -> usize {
+        let function = self.function();
+        function.depth += instruction.stack_effect();
+        function.code.push(instruction);
+        function.code.len() - 1
+    }
+
+    fn patch(&mut self, position: usize, instruction: Instruction) {
+        self.function().code[position] = instruction;
+    }
+
+    fn enter_scope(&mut self) -> u16 {
+        let function = self.function();
+        function.scopes.push(HashMap::new());
+        function.next_slot
+    }
+
+    fn exit_scope(&mut self, next_slot: u16) {
+        let function = self.function();
+        function.scopes.pop();
+        function.next_slot = next_slot;
+    }
+
+    fn intern(&mut self, string: &str) -> Rc<str> {
+        match self.strings.get(string) {
+            Some(interned) => Rc::clone(interned),
+            None => {
+                let interned: Rc<str> = Rc::from(string);
+                self.strings.insert(Rc::clone(&interned));
+                interned
+            }
         }
-        Expr::BinaryOp(ref a, Operator::Mul, ref b) => {
-            code.extend(generate_from_node(a));
-            code.extend(generate_from_node(b));
-            code.push(Instruction::Mul)
+    }
+
+    fn constant(&mut self, value: bytecode::Value) -> Instruction {
+        Instruction::Constant(self.functions.last_mut().unwrap().constant(value))
+    }
+
+    fn resolve(&mut self, name: &str) -> Variable {
+        let depth = self.functions.len() - 1;
+        match self.resolve_in(depth, name) {
+            Some(Capture::Local(slot)) => Variable::Local(slot),
+            Some(Capture::Captured(index)) => Variable::Captured(index),
+            None => Variable::Global(self.globals.resolve(name)),
         }
-        Expr::LetAssignment(ref ident, ref expr) => {
-            code.extend(generate_from_node(expr));
-            code.push(Instruction::SetVar(ident.value.clone()))
+    }
+
+    // finds a variable in the function at `depth`, capturing it from the
+    // enclosing functions if need be; `None` means it's a global
+    fn resolve_in(&mut self, depth: usize, name: &str) -> Option<Capture> {
+        if let Some(slot) = self.functions[depth].local(name) {
+            return Some(Capture::Local(slot));
         }
-        Expr::ConstAssignment(ref ident, ref expr) => {
-            code.extend(generate_from_node(expr));
-            code.push(Instruction::SetVar(ident.value.clone()))
+        if let Some(index) = self.functions[depth].captured(name) {
+            return Some(Capture::Captured(index));
         }
-        Expr::Assignment(ref ident, ref expr) => {
-            code.extend(generate_from_node(expr));
-            code.push(Instruction::SetVar(ident.value.clone()))
+        if depth == 0 {
+            return None;
         }
-        Expr::Identifier(ref ident) => code.push(Instruction::Lookup(ident.value.clone())),
-        Expr::Function(ref params, ref body) => {
-            let mut function_stack: Vec<Instruction> = Vec::new();
-            for param in params {
-                function_stack.push(Instruction::SetVar(param.value.clone()))
+        let source = self.resolve_in(depth - 1, name)?;
+        let function = &mut self.functions[depth];
+        function.captures.push((name.to_string(), source));
+        Some(Capture::Captured(function.captures.len() as u16 - 1))
+    }
+
+    // declarations at the top level of the program, outside of any block, are globals
+    fn is_local_declaration(&self) -> bool {
+        self.functions.len() > 1 || !self.functions[0].scopes.is_empty()
+    }
+
+    fn declare(&mut self, ident: &Identifier) -> Instruction {
+        if self.is_local_declaration() {
+            Instruction::SetLocal(self.function().declare(&ident.value))
+        } else {
+            Instruction::SetGlobal(self.globals.resolve(&ident.value))
+        }
+    }
+
+    fn get(&mut self, ident: &Identifier) -> Instruction {
+        match self.resolve(&ident.value) {
+            Variable::Local(slot) => Instruction::GetLocal(slot),
+            Variable::Captured(index) => Instruction::GetCaptured(index),
+            Variable::Global(index) => Instruction::GetGlobal(index),
+        }
+    }
+
+    fn set(&mut self, ident: &Identifier) -> Instruction {
+        match self.resolve(&ident.value) {
+            Variable::Local(slot) => Instruction::AssignLocal(slot),
+            Variable::Captured(index) => Instruction::SetCaptured(index),
+            Variable::Global(index) => Instruction::SetGlobal(index),
+        }
+    }
+
+    fn generate_from_node(&mut self, node: &'b Expr) {
+        match node {
+            Expr::Literal(ref value) => {
+                let value = match value {
+                    Value::Int(ref v) => bytecode::Value::Int(*v),
+                    Value::BigInt(ref v) => bytecode::Value::BigInt(v.clone()),
+                    Value::Float(ref v) => bytecode::Value::Float(*v),
+                    Value::Bool(ref v) => bytecode::Value::Bool(*v),
+                    Value::String(ref v) => bytecode::Value::String(self.intern(v)),
+                    Value::Nil => bytecode::Value::Nil,
+                };
+                let instruction = self.constant(value);
+                self.emit(instruction);
+            }
+            Expr::BinaryOp(ref a, ref op, ref b) => {
+                self.generate_from_node(a);
+                self.generate_from_node(b);
+                self.emit(binary_instruction(op));
+            }
+            Expr::UnaryOp(UnaryOperator::Neg, ref a) => {
+                self.generate_from_node(a);
+                self.emit(Instruction::Negate);
             }
-            for node in body {
-                function_stack.append(&mut generate_from_node(node))
+            Expr::UnaryOp(UnaryOperator::BitNot, ref a) => {
+                self.generate_from_node(a);
+                self.emit(Instruction::BitNot);
             }
+            // a local function is declared before it's made, starting out as nil,
+            // so that it can capture itself to call itself
+            Expr::LetAssignment(ref ident, ref expr) | Expr::ConstAssignment(ref ident, ref expr)
+                if self.is_local_declaration() && matches!(**expr, Expr::Function(_, _)) =>
+            {
+                let instruction = self.constant(bytecode::Value::Nil);
+                self.emit(instruction);
+                let slot = self.function().declare(&ident.value);
+                self.emit(Instruction::SetLocal(slot));
+                self.generate_from_node(expr);
+                self.emit(Instruction::AssignLocal(slot));
+            }
+            // otherwise the right hand side is generated before the new variable
+            // is declared, so it still refers to any variable the declaration shadows
+            Expr::LetAssignment(ref ident, ref expr) | Expr::ConstAssignment(ref ident, ref expr) => {
+                self.generate_from_node(expr);
+                let instruction = self.declare(ident);
+                self.emit(instruction);
+            }
+            Expr::Assignment(ref ident, ref expr) => {
+                self.generate_from_node(expr);
+                let instruction = self.set(ident);
+                self.emit(instruction);
+            }
+            Expr::Identifier(ref ident) => {
+                let instruction = self.get(ident);
+                self.emit(instruction);
+            }
+            Expr::Function(ref params, ref body) => {
+                // arguments become the first slots of the function's frame
+                let mut function = FunctionScope::default();
+                function.scopes.push(HashMap::new());
+                for param in params {
+                    function.declare(&param.value);
+                }
+
+                self.functions.push(function);
+                self.generate_sequence(body);
+                let function = self.functions.pop().unwrap();
+
+                // nested functions are in chunks of their own, so any yield
+                // here belongs to this function
+                let generator = function.code.contains(&Instruction::Yield);
+                let chunk = Chunk {
+                    code: function.code,
+                    constants: function.constants,
+                    handlers: function.handlers,
+                    generator,
+                };
+                let captures = function
+                    .captures
+                    .into_iter()
+                    .map(|(_, capture)| capture)
+                    .collect();
+                self.emit(Instruction::MakeFunction(params.len(), Rc::new(chunk), captures));
+            }
+
+            Expr::Block(ref body) => {
+                let next_slot = self.enter_scope();
+                self.generate_sequence(body);
+                self.exit_scope(next_slot);
+            }
+
+            Expr::Documented(_, ref expr) => self.generate_from_node(expr),
+
+            Expr::CallFunction(ref ident, ref args) => {
+                for arg in args {
+                    self.generate_from_node(arg);
+                }
+                let instruction = self.get(ident);
+                self.emit(instruction);
+                self.emit(Instruction::Call(args.len()));
+            }
+
+            Expr::Range(ref start, ref end, inclusive) => {
+                self.generate_from_node(start);
+                self.generate_from_node(end);
+                self.emit(Instruction::MakeRange(*inclusive));
+            }
+
+            Expr::For(ref ident, ref iterable, ref body) => {
+                self.generate_from_node(iterable);
+                self.emit(Instruction::GetIter);
+                self.generate_loop(ident, body);
+                self.emit(Instruction::EndLoop);
+            }
+            Expr::Yield(ref expr) => {
+                self.generate_from_node(expr);
+                self.emit(Instruction::Yield);
+            }
+            // the offsets are filled in by the enclosing loop
+            Expr::Break => self.generate_jump_out(Instruction::Break(0)),
+            Expr::Continue => self.generate_jump_out(Instruction::Continue(0)),
+            Expr::Throw(ref expr) => {
+                self.generate_from_node(expr);
+                self.emit(Instruction::Throw);
+            }
+            Expr::Try(ref body, ref catch, ref finally) => {
+                self.generate_try(body, catch.as_ref(), finally.as_ref().map(Vec::as_slice))
+            }
+        }
+    }
+
+    // the part of a loop that runs for each item: the loop variable and the
+    // body share a scope, whose slots are reused once the loop has finished
+    fn generate_loop(&mut self, ident: &Identifier, body: &'b [Expr]) {
+        let next_slot = self.enter_scope();
+        let slot = self.function().declare(&ident.value);
+
+        let start = self.emit(Instruction::ForIter(0));
+        self.emit(Instruction::SetLocal(slot));
+        self.function().nesting.push(Nesting::Loop(Vec::new(), Vec::new()));
+        self.generate_statements(body);
+        let (breaks, continues) = match self.function().nesting.pop() {
+            Some(Nesting::Loop(breaks, continues)) => (breaks, continues),
+            _ => unreachable!(),
+        };
+        self.exit_scope(next_slot);
+
+        // the jump back is the last instruction of the loop, which leaves the
+        // loop's `EndLoop` just after the end
+        let jump = self.position();
+        self.emit(Instruction::Jump(start as isize - jump as isize));
+        let end = self.position();
+        self.patch(start, Instruction::ForIter((end - start) as isize));
+        for position in breaks {
+            self.patch(position, Instruction::Break((end - position) as isize));
+        }
+        for position in continues {
+            self.patch(position, Instruction::Continue(start as isize - position as isize));
+        }
+    }
+
+    // a break or continue runs the `finally` blocks of the tries it leaves on
+    // its way to the end of its loop; each try doesn't cover the code that
+    // leaves it, so that an error there goes to the enclosing tries instead
+    fn generate_jump_out(&mut self, instruction: Instruction) {
+        let mut index = self.function().nesting.len();
+        while index > 0 {
+            index -= 1;
+            let position = self.position();
+            let finally = match self.function().nesting[index] {
+                Nesting::Loop(_, _) => break,
+                Nesting::Try(ref mut protected) => {
+                    protected.close(position);
+                    protected.finally
+                }
+            };
+            if let Some(finally) = finally {
+                let inner = self.function().nesting.split_off(index);
+                self.generate_finally(finally);
+                self.function().nesting.extend(inner);
+            }
+        }
+
+        let position = self.emit(instruction.clone());
+        // anything after it until the end of its block is covered as usual
+        for nesting in self.function().nesting.iter_mut().rev() {
+            match nesting {
+                Nesting::Loop(breaks, continues) => {
+                    match instruction {
+                        Instruction::Break(_) => breaks.push(position),
+                        _ => continues.push(position),
+                    }
+                    break;
+                }
+                Nesting::Try(protected) => protected.start = position + 1,
+            }
+        }
+    }
+
+    // the body of a try jumps over its handlers once it has finished; if it
+    // fails, the data stack is truncated to its depth at the start of the try
+    // and the catch block is given the error, and the finally block runs
+    // whichever way the body and the catch block finish
+    fn generate_try(
+        &mut self,
+        body: &'b [Expr],
+        catch: Option<&'b (Identifier, Vec<Expr>)>,
+        finally: Option<&'b [Expr]>,
+    ) {
+        let depth = self.function().depth;
+        let mut exits = Vec::new();
+
+        let mut uncaught = self.generate_protected(body, finally);
+        if let Some(finally) = finally {
+            self.generate_finally(finally);
+        }
+        exits.push(self.emit(Instruction::Jump(0)));
+
+        if let Some((ref ident, ref handler)) = catch {
+            let target = self.position();
+            self.add_handlers(&uncaught, target, depth, true);
+
+            // the error is on top of the stack, and becomes the catch
+            // block's variable
+            self.function().depth = depth + 1;
+            let next_slot = self.enter_scope();
+            let slot = self.function().declare(&ident.value);
+            self.emit(Instruction::SetLocal(slot));
+            uncaught = self.generate_protected(handler, finally);
+            self.exit_scope(next_slot);
 
-            code.push(Instruction::MakeFunction(function_stack));
+            if let Some(finally) = finally {
+                self.generate_finally(finally);
+                exits.push(self.emit(Instruction::Jump(0)));
+            }
+        }
+
+        if let Some(finally) = finally {
+            let target = self.position();
+            self.add_handlers(&uncaught, target, depth, false);
+            self.function().depth = depth;
+            self.generate_finally(finally);
+            self.emit(Instruction::EndFinally);
+        }
+
+        let end = self.position();
+        for position in exits {
+            self.patch(position, Instruction::Jump((end - position) as isize));
         }
+        self.function().depth = depth;
+    }
 
-        Expr::CallFunction(ref ident, ref _args) => {
-            code.push(Instruction::Lookup(ident.value.clone()));
-            code.push(Instruction::Call)
+    // generates statements covered by a try, returning the ranges of code
+    // that are covered
+    fn generate_protected(&mut self, body: &'b [Expr], finally: Option<&'b [Expr]>) -> Vec<(usize, usize)> {
+        let start = self.position();
+        self.function().nesting.push(Nesting::Try(Protected {
+            ranges: Vec::new(),
+            start,
+            finally,
+        }));
+        let next_slot = self.enter_scope();
+        self.generate_statements(body);
+        self.exit_scope(next_slot);
+        let end = self.position();
+        match self.function().nesting.pop() {
+            Some(Nesting::Try(mut protected)) => {
+                protected.close(end);
+                protected.ranges
+            }
+            _ => unreachable!(),
         }
     }
-    code
+
+    // each copy of a finally block declares its variables in a scope of its
+    // own, as any other block does
+    fn generate_finally(&mut self, finally: &'b [Expr]) {
+        let next_slot = self.enter_scope();
+        self.generate_statements(finally);
+        self.exit_scope(next_slot);
+    }
+
+    fn add_handlers(&mut self, ranges: &[(usize, usize)], target: usize, depth: isize, catches: bool) {
+        let function = self.function();
+        for &(start, end) in ranges {
+            function.handlers.push(Handler {
+                start,
+                end,
+                target,
+                depth: depth as usize,
+                catches,
+            });
+        }
+    }
+
+    // statements whose value, if they have one, is dropped
+    fn generate_statements(&mut self, body: &'b [Expr]) {
+        self.generate_sequence(body);
+        if body.last().is_some_and(pushes_value) {
+            self.emit(Instruction::Pop);
+        }
+    }
+
+    // only the last statement of a sequence leaves its value on the stack
+    fn generate_sequence(&mut self, nodes: &'b [Expr]) {
+        for (i, node) in nodes.iter().enumerate() {
+            self.generate_from_node(node);
+            if i + 1 < nodes.len() && pushes_value(node) {
+                self.emit(Instruction::Pop);
+            }
+        }
+    }
+}
+
+fn pushes_value(node: &Expr) -> bool {
+    match node {
+        Expr::LetAssignment(_, _) | Expr::ConstAssignment(_, _) | Expr::Assignment(_, _) => false,
+        Expr::For(_, _, _) | Expr::Yield(_) | Expr::Break | Expr::Continue => false,
+        Expr::Throw(_) | Expr::Try(_, _, _) => false,
+        Expr::Documented(_, ref expr) => pushes_value(expr),
+        Expr::Block(ref body) => body.last().is_some_and(pushes_value),
+        _ => true,
+    }
 }
 
-pub fn generate(nodes: Vec<Expr>) -> Vec<Instruction> {
-    let mut code: Vec<Instruction> = Vec::new();
-    for node in nodes {
-        code.extend(generate_from_node(&node))
+pub fn generate(nodes: Vec<Expr>, globals: &mut Globals) -> Chunk {
+    let mut generator = Generator::new(globals);
+    generator.generate_sequence(&nodes);
+    let function = generator.functions.pop().unwrap();
+    Chunk {
+        code: function.code,
+        constants: function.constants,
+        handlers: function.handlers,
+        generator: false,
     }
-    code
 }
@@ -0,0 +1,307 @@
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::BuildHasher;
+use std::rc::Rc;
+
+use num_traits::ToPrimitive;
+
+use bytecode::{NativeFn, Value, MAX_LIST_LEN};
+use pretty_print::pretty_print;
+use vm::{EvaluationError, VM};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConversionError {
+    // the type that was expected, and the type of the value found instead
+    UnexpectedType(&'static str, &'static str),
+    // the type that was expected, and the value that doesn't fit in it
+    OutOfRange(&'static str, String),
+    // the length of tuple that was expected, and the length of the list found
+    WrongLength(usize, usize),
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConversionError::UnexpectedType(expected, found) => {
+                write!(f, "expected {}, found {}", expected, found)
+            }
+            ConversionError::OutOfRange(expected, value) => {
+                write!(f, "{} doesn't fit in {}", value, expected)
+            }
+            ConversionError::WrongLength(expected, found) => {
+                write!(f, "expected a list of length {}, found length {}", expected, found)
+            }
+        }
+    }
+}
+
+/// The name of a value's type, as scripts see it
+pub fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Int(_) | Value::BigInt(_) => "int",
+        Value::Float(_) => "float",
+        Value::Bool(_) => "bool",
+        Value::String(_) => "string",
+        Value::List(_) => "list",
+        Value::Map(_) => "map",
+        Value::Function(_) | Value::Builtin(_) | Value::NativeFunction(_) => "function",
+        Value::Range(_) => "range",
+        Value::Iterator(_) => "iterator",
+        Value::Generator(_) => "generator",
+        Value::Nil => "nil",
+    }
+}
+
+/// Rust types that can be made from a script's values
+pub trait FromValue: Sized {
+    fn from_value(value: &Value) -> Result<Self, ConversionError>;
+}
+
+/// Rust types that can be given to scripts as values
+pub trait IntoValue {
+    fn into_value(self) -> Value;
+}
+
+fn unexpected<T>(expected: &'static str, value: &Value) -> Result<T, ConversionError> {
+    Err(ConversionError::UnexpectedType(expected, type_name(value)))
+}
+
+impl FromValue for Value {
+    fn from_value(value: &Value) -> Result<Value, ConversionError> {
+        Ok(value.clone())
+    }
+}
+
+impl FromValue for i64 {
+    fn from_value(value: &Value) -> Result<i64, ConversionError> {
+        match value {
+            Value::Int(x) => Ok(*x),
+            Value::BigInt(x) => Err(ConversionError::OutOfRange("i64", x.to_string())),
+            value => unexpected("int", value),
+        }
+    }
+}
+
+impl FromValue for i32 {
+    fn from_value(value: &Value) -> Result<i32, ConversionError> {
+        let x = i64::from_value(value).map_err(|e| match e {
+            ConversionError::OutOfRange(_, x) => ConversionError::OutOfRange("i32", x),
+            e => e,
+        })?;
+        i32::try_from(x).map_err(|_| ConversionError::OutOfRange("i32", x.to_string()))
+    }
+}
+
+// ints are accepted where floats are expected, as they are by arithmetic
+impl FromValue for f64 {
+    fn from_value(value: &Value) -> Result<f64, ConversionError> {
+        match value {
+            Value::Float(x) => Ok(*x),
+            Value::Int(x) => Ok(*x as f64),
+            Value::BigInt(x) => x
+                .to_f64()
+                .filter(|x| x.is_finite())
+                .ok_or_else(|| ConversionError::OutOfRange("f64", x.to_string())),
+            value => unexpected("float", value),
+        }
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(value: &Value) -> Result<bool, ConversionError> {
+        match value {
+            Value::Bool(x) => Ok(*x),
+            value => unexpected("bool", value),
+        }
+    }
+}
+
+impl FromValue for String {
+    fn from_value(value: &Value) -> Result<String, ConversionError> {
+        match value {
+            Value::String(s) => Ok(s.to_string()),
+            value => unexpected("string", value),
+        }
+    }
+}
+
+impl<T: FromValue> FromValue for Vec<T> {
+    fn from_value(value: &Value) -> Result<Vec<T>, ConversionError> {
+        match value {
+            Value::List(items) => items.iter().map(T::from_value).collect(),
+            // ranges can be used wherever a list of their ints could, as long
+            // as the list wouldn't be too long to make
+            Value::Range(range) if range.count() > MAX_LIST_LEN as i128 => {
+                Err(ConversionError::OutOfRange("list", pretty_print(value)))
+            }
+            Value::Range(range) => {
+                let (start, end) = range.bounds();
+                (start..end).map(|i| T::from_value(&Value::Int(i as i64))).collect()
+            }
+            value => unexpected("list", value),
+        }
+    }
+}
+
+impl<T: FromValue, S: BuildHasher + Default> FromValue for HashMap<String, T, S> {
+    fn from_value(value: &Value) -> Result<HashMap<String, T, S>, ConversionError> {
+        match value {
+            Value::Map(entries) => entries
+                .iter()
+                .map(|(k, v)| Ok((k.clone(), T::from_value(v)?)))
+                .collect(),
+            value => unexpected("map", value),
+        }
+    }
+}
+
+// nil is `None`, and anything else is converted as `T`
+impl<T: FromValue> FromValue for Option<T> {
+    fn from_value(value: &Value) -> Result<Option<T>, ConversionError> {
+        match value {
+            Value::Nil => Ok(None),
+            value => T::from_value(value).map(Some),
+        }
+    }
+}
+
+impl IntoValue for Value {
+    fn into_value(self) -> Value {
+        self
+    }
+}
+
+impl IntoValue for () {
+    fn into_value(self) -> Value {
+        Value::Nil
+    }
+}
+
+impl IntoValue for i64 {
+    fn into_value(self) -> Value {
+        Value::Int(self)
+    }
+}
+
+impl IntoValue for i32 {
+    fn into_value(self) -> Value {
+        Value::Int(i64::from(self))
+    }
+}
+
+impl IntoValue for f64 {
+    fn into_value(self) -> Value {
+        Value::Float(self)
+    }
+}
+
+impl IntoValue for bool {
+    fn into_value(self) -> Value {
+        Value::Bool(self)
+    }
+}
+
+impl IntoValue for String {
+    fn into_value(self) -> Value {
+        Value::String(Rc::from(self))
+    }
+}
+
+impl IntoValue for &str {
+    fn into_value(self) -> Value {
+        Value::String(Rc::from(self))
+    }
+}
+
+impl<T: IntoValue> IntoValue for Vec<T> {
+    fn into_value(self) -> Value {
+        Value::List(Rc::new(self.into_iter().map(IntoValue::into_value).collect()))
+    }
+}
+
+impl<T: IntoValue, S: BuildHasher> IntoValue for HashMap<String, T, S> {
+    fn into_value(self) -> Value {
+        let entries: BTreeMap<String, Value> = self.into_iter().map(|(k, v)| (k, v.into_value())).collect();
+        Value::Map(Rc::new(entries))
+    }
+}
+
+impl<T: IntoValue> IntoValue for Option<T> {
+    fn into_value(self) -> Value {
+        match self {
+            Some(value) => value.into_value(),
+            None => Value::Nil,
+        }
+    }
+}
+
+// tuples are lists of a fixed length
+macro_rules! tuple_conversions {
+    ($length:expr; $($element:ident: $T:ident),+) => {
+        impl<$($T: FromValue),+> FromValue for ($($T,)+) {
+            fn from_value(value: &Value) -> Result<($($T,)+), ConversionError> {
+                match value {
+                    Value::List(items) => match items.as_slice() {
+                        [$($element),+] => Ok(($($T::from_value($element)?,)+)),
+                        items => Err(ConversionError::WrongLength($length, items.len())),
+                    },
+                    value => unexpected("list", value),
+                }
+            }
+        }
+
+        impl<$($T: IntoValue),+> IntoValue for ($($T,)+) {
+            fn into_value(self) -> Value {
+                let ($($element,)+) = self;
+                Value::List(Rc::new(vec![$($element.into_value()),+]))
+            }
+        }
+    };
+}
+
+tuple_conversions!(1; a: A);
+tuple_conversions!(2; a: A, b: B);
+tuple_conversions!(3; a: A, b: B, c: C);
+tuple_conversions!(4; a: A, b: B, c: C, d: D);
+
+/// Rust functions that can be registered with a runtime without handling
+/// values themselves: their arguments are converted with `FromValue`, and
+/// their results with `IntoValue`. An error a function returns becomes an
+/// `EvaluationError::NativeError` holding its message.
+pub trait IntoNativeFunction<Args> {
+    const ARITY: usize;
+
+    fn into_native(self) -> Rc<NativeFn>;
+}
+
+macro_rules! native_function {
+    ($arity:expr; $($arg:ident: $T:ident),*) => {
+        impl<Func, $($T,)* Output, Error> IntoNativeFunction<($($T,)*)> for Func
+        where
+            Func: Fn($($T),*) -> Result<Output, Error> + 'static,
+            $($T: FromValue,)*
+            Output: IntoValue,
+            Error: fmt::Display,
+        {
+            const ARITY: usize = $arity;
+
+            fn into_native(self) -> Rc<NativeFn> {
+                Rc::new(move |_: &mut VM, args: &[Value]| match args {
+                    [$($arg),*] => self($($T::from_value($arg)?),*)
+                        .map(IntoValue::into_value)
+                        .map_err(|e| EvaluationError::NativeError(e.to_string())),
+                    args => Err(EvaluationError::IncorrectArity($arity, args.len())),
+                })
+            }
+        }
+    };
+}
+
+native_function!(0;);
+native_function!(1; a: A);
+native_function!(2; a: A, b: B);
+native_function!(3; a: A, b: B, c: C);
+native_function!(4; a: A, b: B, c: C, d: D);
+native_function!(5; a: A, b: B, c: C, d: D, e: E);
+native_function!(6; a: A, b: B, c: C, d: D, e: E, f: F);
@@ -0,0 +1,332 @@
+use std::str::FromStr;
+
+pub type Spanned<Tok, Loc, Error> = Result<(Loc, Tok, Loc), Error>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Tok<'input> {
+    Identifier(&'input str),
+    Int(&'input str),
+    Float(f64),
+    String(&'input str),
+    DocComment(&'input str),
+    Let,
+    Const,
+    True,
+    False,
+    Nil,
+    For,
+    In,
+    Break,
+    Continue,
+    Yield,
+    Throw,
+    Try,
+    Catch,
+    Finally,
+    LParen,
+    // an opening paren directly after an identifier, which starts a call's
+    // arguments rather than a parenthesized argument
+    CallParen,
+    RParen,
+    // a closing paren followed by `=>`, which is what tells a function's
+    // parameter list apart from a parenthesized expression
+    RParenArrow,
+    LBrace,
+    RBrace,
+    Semicolon,
+    Equals,
+    Plus,
+    Minus,
+    Star,
+    StarStar,
+    Slash,
+    TildeSlash,
+    Percent,
+    Amp,
+    Pipe,
+    Caret,
+    Tilde,
+    ShiftLeft,
+    ShiftRight,
+    DotDot,
+    DotDotEquals,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LexicalError {
+    pub location: usize,
+    pub message: &'static str,
+}
+
+// longer symbols must come before any symbol that is a prefix of them
+const SYMBOLS: &[(&str, Tok<'static>)] = &[
+    ("..=", Tok::DotDotEquals),
+    ("**", Tok::StarStar),
+    ("~/", Tok::TildeSlash),
+    ("<<", Tok::ShiftLeft),
+    (">>", Tok::ShiftRight),
+    ("..", Tok::DotDot),
+    ("(", Tok::LParen),
+    ("{", Tok::LBrace),
+    ("}", Tok::RBrace),
+    (";", Tok::Semicolon),
+    ("=", Tok::Equals),
+    ("+", Tok::Plus),
+    ("-", Tok::Minus),
+    ("*", Tok::Star),
+    ("/", Tok::Slash),
+    ("%", Tok::Percent),
+    ("&", Tok::Amp),
+    ("|", Tok::Pipe),
+    ("^", Tok::Caret),
+    ("~", Tok::Tilde),
+];
+
+pub struct Lexer<'input> {
+    input: &'input str,
+    position: usize,
+    identifier_end: Option<usize>,
+}
+
+impl<'input> Lexer<'input> {
+    pub fn new(input: &'input str) -> Lexer<'input> {
+        Lexer {
+            input,
+            position: 0,
+            identifier_end: None,
+        }
+    }
+
+    fn rest(&self) -> &'input str {
+        &self.input[self.position..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.rest().chars().nth(n)
+    }
+
+    fn advance_while<F: Fn(char) -> bool>(&mut self, predicate: F) {
+        while let Some(c) = self.peek() {
+            if !predicate(c) {
+                break;
+            }
+            self.position += c.len_utf8();
+        }
+    }
+
+    fn error(&self, location: usize, message: &'static str) -> Option<<Self as Iterator>::Item> {
+        Some(Err(LexicalError { location, message }))
+    }
+
+    // `/* */` comments nest, so that commenting out code that already contains
+    // a block comment works as expected
+    fn skip_block_comment(&mut self) -> Result<(), LexicalError> {
+        let start = self.position;
+        let mut depth = 0;
+        loop {
+            if self.rest().starts_with("/*") {
+                depth += 1;
+                self.position += 2;
+            } else if self.rest().starts_with("*/") {
+                depth -= 1;
+                self.position += 2;
+                if depth == 0 {
+                    return Ok(());
+                }
+            } else if let Some(c) = self.peek() {
+                self.position += c.len_utf8();
+            } else {
+                return Err(LexicalError {
+                    location: start,
+                    message: "unterminated block comment",
+                });
+            }
+        }
+    }
+
+    // consumes digits of the given radix, allowing single underscores between
+    // them, and returns whether that was well formed
+    fn digits(&mut self, radix: u32) -> bool {
+        let start = self.position;
+        loop {
+            self.advance_while(|c| c.is_digit(radix));
+            match (self.peek(), self.peek_nth(1)) {
+                (Some('_'), Some(c)) if c.is_digit(radix) && self.position > start => {
+                    self.position += 1
+                }
+                _ => return self.position > start,
+            }
+        }
+    }
+
+    fn exponent(&mut self) -> bool {
+        match (self.peek(), self.peek_nth(1), self.peek_nth(2)) {
+            (Some('e'), Some(c), _) | (Some('E'), Some(c), _) if c.is_ascii_digit() => {
+                self.position += 1;
+            }
+            (Some('e'), Some('+'), Some(c))
+            | (Some('e'), Some('-'), Some(c))
+            | (Some('E'), Some('+'), Some(c))
+            | (Some('E'), Some('-'), Some(c))
+                if c.is_ascii_digit() =>
+            {
+                self.position += 2;
+            }
+            _ => return false,
+        }
+        self.advance_while(|c| c.is_ascii_digit());
+        true
+    }
+
+    fn number(&mut self, start: usize) -> Option<<Self as Iterator>::Item> {
+        let radix = match (self.peek(), self.peek_nth(1)) {
+            (Some('0'), Some('x')) | (Some('0'), Some('X')) => 16,
+            (Some('0'), Some('o')) | (Some('0'), Some('O')) => 8,
+            (Some('0'), Some('b')) | (Some('0'), Some('B')) => 2,
+            _ => 10,
+        };
+
+        let mut is_float = false;
+        let well_formed = if radix != 10 {
+            self.position += 2;
+            self.digits(radix)
+        } else {
+            let integral = self.peek() == Some('.') || self.digits(10);
+            // a dot followed by another dot is left alone, for ranges
+            if self.peek() == Some('.') && self.peek_nth(1) != Some('.') {
+                is_float = true;
+                self.position += 1;
+                if self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    self.digits(10);
+                }
+            }
+            if self.exponent() {
+                is_float = true;
+            }
+            integral
+        };
+
+        if !well_formed || self.peek().is_some_and(|c| c.is_alphanumeric() || c == '_') {
+            self.advance_while(|c| c.is_alphanumeric() || c == '_');
+            return self.error(start, "invalid numeric literal");
+        }
+
+        let text = &self.input[start..self.position];
+        if is_float {
+            let value = f64::from_str(&text.replace("_", "")).unwrap();
+            if !value.is_finite() {
+                return self.error(start, "float literal is out of range");
+            }
+            Some(Ok((start, Tok::Float(value), self.position)))
+        } else {
+            Some(Ok((start, Tok::Int(text), self.position)))
+        }
+    }
+
+    fn identifier(&mut self, start: usize) -> Option<<Self as Iterator>::Item> {
+        self.advance_while(|c| c.is_ascii_alphanumeric() || c == '_');
+        let tok = match &self.input[start..self.position] {
+            "let" => Tok::Let,
+            "const" => Tok::Const,
+            "true" => Tok::True,
+            "false" => Tok::False,
+            "nil" => Tok::Nil,
+            "for" => Tok::For,
+            "in" => Tok::In,
+            "break" => Tok::Break,
+            "continue" => Tok::Continue,
+            "yield" => Tok::Yield,
+            "throw" => Tok::Throw,
+            "try" => Tok::Try,
+            "catch" => Tok::Catch,
+            "finally" => Tok::Finally,
+            ident => {
+                self.identifier_end = Some(self.position);
+                Tok::Identifier(ident)
+            }
+        };
+        Some(Ok((start, tok, self.position)))
+    }
+
+    fn string(&mut self, start: usize) -> Option<<Self as Iterator>::Item> {
+        self.position += 1;
+        self.advance_while(|c| c != '"');
+        if self.peek().is_none() {
+            return self.error(start, "unterminated string");
+        }
+        self.position += 1;
+        Some(Ok((start, Tok::String(&self.input[start..self.position]), self.position)))
+    }
+
+    fn close_paren(&mut self, start: usize) -> Option<<Self as Iterator>::Item> {
+        self.position += 1;
+        let after_paren = self.position;
+        self.advance_while(char::is_whitespace);
+        if self.rest().starts_with("=>") {
+            self.position += 2;
+            Some(Ok((start, Tok::RParenArrow, self.position)))
+        } else {
+            self.position = after_paren;
+            Some(Ok((start, Tok::RParen, self.position)))
+        }
+    }
+}
+
+impl<'input> Iterator for Lexer<'input> {
+    type Item = Spanned<Tok<'input>, usize, LexicalError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.advance_while(char::is_whitespace);
+            let start = self.position;
+            let c = self.peek()?;
+
+            // `///` starts a doc comment, but `////` is an ordinary comment
+            if self.rest().starts_with("///") && !self.rest().starts_with("////") {
+                self.advance_while(|c| c != '\n');
+                let text = self.input[start + 3..self.position].trim();
+                return Some(Ok((start, Tok::DocComment(text), self.position)));
+            }
+            if self.rest().starts_with("//") {
+                self.advance_while(|c| c != '\n');
+                continue;
+            }
+            if self.rest().starts_with("/*") {
+                if let Err(e) = self.skip_block_comment() {
+                    return Some(Err(e));
+                }
+                continue;
+            }
+
+            if c.is_ascii_digit() || (c == '.' && self.peek_nth(1).is_some_and(|c| c.is_ascii_digit())) {
+                return self.number(start);
+            }
+            if c.is_ascii_alphabetic() || c == '_' {
+                return self.identifier(start);
+            }
+            if c == '"' {
+                return self.string(start);
+            }
+            if c == ')' {
+                return self.close_paren(start);
+            }
+            if c == '(' && self.identifier_end == Some(start) {
+                self.position += 1;
+                return Some(Ok((start, Tok::CallParen, self.position)));
+            }
+            for (symbol, tok) in SYMBOLS {
+                if self.rest().starts_with(symbol) {
+                    self.position += symbol.len();
+                    return Some(Ok((start, tok.clone(), self.position)));
+                }
+            }
+
+            self.position += c.len_utf8();
+            return self.error(start, "unexpected character");
+        }
+    }
+}
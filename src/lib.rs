@@ -0,0 +1,18 @@
+extern crate lalrpop_util;
+extern crate num_bigint;
+extern crate num_traits;
+extern crate regex;
+
+pub mod arithmetic;
+pub mod ast;
+pub mod bytecode;
+pub mod codegen;
+pub mod convert;
+pub mod lexer;
+#[allow(clippy::all, deprecated, unused_parens)]
+pub mod parser;
+pub mod pretty_print;
+pub mod runtime;
+pub mod semantic_analysis;
+pub mod stdlib;
+pub mod vm;
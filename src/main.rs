@@ -1,20 +1,16 @@
-pub mod ast;
-pub mod bytecode;
-pub mod codegen;
-pub mod parser;
-pub mod pretty_print;
-pub mod runtime;
-pub mod semantic_analysis;
-pub mod vm;
+extern crate bytelang;
 
+use std::env;
 use std::io::{self, Write};
 
-use pretty_print::pretty_print;
-use runtime::{EvaluationResult, Runtime};
-use vm::ReturnValue;
+use bytelang::pretty_print::pretty_print;
+use bytelang::runtime::{EvaluationResult, Runtime};
+use bytelang::vm::ReturnValue;
 
 fn main() {
     let mut runtime = Runtime::new();
+    // `--trace` prints the VM's state after every instruction
+    runtime.set_trace(env::args().any(|arg| arg == "--trace"));
     loop {
         print!(">>> ");
         let _ = io::stdout().flush();
@@ -24,6 +20,9 @@ fn main() {
         match stdin.read_line(&mut line) {
             Ok(_) => {
                 let result = runtime.evaluate(&line);
+                if !runtime.warnings().is_empty() {
+                    println!("warning: {:?}", runtime.warnings())
+                }
                 match result {
                     EvaluationResult::ParseError(e) => println!("{:?}", e),
                     EvaluationResult::SemanticAnalysisError(e) => println!("{:?}", e),
@@ -1,7 +1,8 @@
 // auto-generated: "lalrpop 0.15.2"
-// sha256: 76dc68435d2af6efadfeccaeaa2ebaba1bb7f4ccafb030693deb819af95433
+// sha256: da982edd7fb9cfc8d4b058b8592ef115cdf930d63b3ec5a907928f2151459b4
 use std::str::FromStr;
-use ast::{Expr, Operator, Value, Identifier};
+use ast::{Expr, Operator, UnaryOperator, Value, Identifier};
+use lexer::{LexicalError, Tok};
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
 
@@ -10,225 +11,419 @@ mod __parse__Expr {
     #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
 
     use std::str::FromStr;
-    use ast::{Expr, Operator, Value, Identifier};
+    use ast::{Expr, Operator, UnaryOperator, Value, Identifier};
+    use lexer::{LexicalError, Tok};
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
-    use super::__intern_token::Token;
+    use super::__ToTriple;
     #[allow(dead_code)]
     pub enum __Symbol<'input>
      {
-        Variant0(&'input str),
-        Variant1(Box<Expr>),
-        Variant2(Operator),
-        Variant3(Expr),
-        Variant4(::std::vec::Vec<Expr>),
-        Variant5(bool),
-        Variant6(f64),
-        Variant7(Identifier),
-        Variant8(::std::vec::Vec<Identifier>),
-        Variant9(i32),
-    }
-    const __ACTION: &'static [i8] = &[
+        Variant0(Tok<'input>),
+        Variant1(&'input str),
+        Variant2(f64),
+        Variant3(Box<Expr>),
+        Variant4(::std::vec::Vec<Box<Expr>>),
+        Variant5(Expr),
+        Variant6(::std::vec::Vec<Expr>),
+        Variant7(Operator),
+        Variant8(Vec<Expr>),
+        Variant9((Identifier, Vec<Expr>)),
+        Variant10(String),
+        Variant11(::std::vec::Vec<String>),
+        Variant12(::std::option::Option<Box<Expr>>),
+        Variant13(bool),
+        Variant14(Identifier),
+        Variant15(::std::vec::Vec<Identifier>),
+        Variant16(Value),
+    }
+    const __ACTION: &'static [i16] = &[
         // State 0
-        12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 14, 15, 16, 17, 18,
+        0, 0, 28, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
         // State 1
-        0, 0, 0, 0, 20, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -97, 0, 0, 0, 0, 0, -97, -97, 0, -97, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, -97, -97, 0, 0,
         // State 2
-        0, -4, 0, 23, -4, -4, 24, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -66, 0, 0, 0, 0, 0, 39, 40, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, -66, 0, 0,
         // State 3
-        0, -46, 0, -46, -46, -46, -46, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -95, 0, 0, 0, 0, 0, -95, -95, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, -95, -95, 0, 0,
         // State 4
-        0, -43, 0, -43, -43, -43, -43, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        25, -28, 0, -28, -28, -28, -28, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -99, 0, -99, 0, 0, 0, -99, -99, -99, -99, 0, -99, -99, 0, -99, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, -99, -99, 0, 0,
         // State 6
-        0, -47, 0, -47, -47, -47, -47, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -87, -87, -87, -87, 0, -87, -87, -87, -87, -87, -87, -87, -87, -87, 0, -87, -87, 0, 0, 0, 0, 0, 0, -87, 0, -87, 0, -87, 0, -87, 0, -87, -87, 0, -87, 0, 0, -87, -87, -87, 0, -87,
         // State 7
-        0, -44, 0, -44, -44, -44, -44, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -85, -85, -85, -85, 0, -85, -85, -85, -85, -85, -85, -85, -85, -85, 0, -85, -85, 0, 0, 0, 0, 0, 0, -85, 0, -85, 0, -85, 0, -85, 0, -85, -85, 0, -85, 0, 0, -85, -85, -85, 0, -85,
         // State 8
-        0, -5, 0, 0, -5, -5, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, -25, 0, 0,
         // State 9
-        0, -18, 0, -18, -18, -18, -18, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -47, -47, -47, -47, 0, -47, -47, -47, -47, -47, -47, -47, -47, -47, 0, -47, -47, 0, 41, 0, 0, 0, 0, -47, 0, -47, 0, -47, 0, -47, 0, -47, -47, 0, -47, 0, 0, -47, -47, -47, 0, -47,
         // State 10
-        0, -45, 0, -45, -45, -45, -45, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -90, -90, -90, -90, 0, -90, -90, -90, -90, -90, -90, -90, -90, -90, 0, -90, -90, 0, 0, 0, 0, 0, 0, -90, 0, -90, 0, -90, 0, -90, 0, -90, -90, 0, -90, 0, 0, -90, -90, -90, 0, -90,
         // State 11
-        12, 0, 29, 0, 0, 0, 0, 0, 0, 0, 13, 0, 14, 15, 16, 17, 18,
+        -58, -58, -58, -58, 0, -58, -58, -58, -58, -58, -58, -58, -58, -58, 0, -58, -58, 0, 0, 0, 0, 0, 0, -58, 0, -58, 0, -58, 0, -58, 0, -58, -58, 0, -58, 0, 0, -58, -58, -58, 0, -58,
         // State 12
-        0, -21, 0, -21, -21, -21, -21, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -110, -110, 0, -110, 0, -110, 0, -110, -110, -110, -110, -110, -110, -110, 0, -110, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, -110, -110, 0, -110,
         // State 13
-        0, -49, 0, -49, -49, -49, -49, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -57, -57, 0, -57, 0, -57, 42, -57, -57, -57, -57, -57, -57, -57, 0, -57, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, -57, -57, 0, -57,
         // State 14
-        0, -36, 0, 0, -36, -36, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, -24, 0, 0,
         // State 15
-        0, -34, 0, -34, -34, -34, -34, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -93, 0, -93, 0, 0, 0, 0, 0, -93, -93, 0, -93, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, -93, -93, 0, 0,
         // State 16
-        -23, -23, -23, -23, -23, -23, -23, -23, -23, 0, 0, 0, 0, 0, 0, -23, 0,
+        -89, -89, -89, -89, 0, -89, -89, -89, -89, -89, -89, -89, -89, -89, 0, -89, -89, 0, 0, 0, 0, 0, 0, -89, 0, -89, 0, -89, 0, -89, 0, -89, -89, 0, -89, 0, 0, -89, -89, -89, 0, -89,
         // State 17
-        0, -22, 0, -22, -22, -22, -22, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -103, 0, -103, 0, 0, 0, 0, 0, -103, -103, 0, -103, -103, 0, -103, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, -103, -103, 0, 0,
         // State 18
-        12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 14, 0, 16, 17, 18,
+        -59, -59, -59, -59, 0, -59, -59, -59, -59, -59, -59, -59, -59, -59, 0, -59, -59, 0, 0, 0, 0, 0, 0, -59, 0, -59, 0, -59, 0, -59, 0, -59, -59, 0, -59, 0, 0, -59, -59, -59, 0, -59,
         // State 19
-        -6, 0, 0, 0, 0, 0, 0, 0, 0, 0, -6, 0, -6, 0, -6, -6, -6,
+        0, 44, 0, -12, 0, 0, 0, 0, 0, -12, -12, 0, -12, 0, 0, 0, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -12, -12, -12, 0, 0,
         // State 20
-        -7, 0, 0, 0, 0, 0, 0, 0, 0, 0, -7, 0, -7, 0, -7, -7, -7,
+        0, 0, 0, -14, 0, 0, 0, 0, 0, -14, -14, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 46, -14, 0, 0,
         // State 21
-        12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 14, 0, 16, 17, 18,
+        0, 0, 0, -16, 0, 0, 0, 0, 0, -16, -16, 0, -16, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16, -16, -16, 0, 0,
         // State 22
-        -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, -19, 0, -19, -19, -19,
+        0, -81, 0, -81, 0, 0, 0, 50, 51, -81, -81, 0, -81, -81, 0, -81, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, -81, -81, 0, 0,
         // State 23
-        -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, -20, 0, -20, -20, -20,
+        53, -32, 0, -32, 0, 54, 0, -32, -32, -32, -32, 55, -32, -32, 0, -32, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, -32, -32, 0, 56,
         // State 24
-        0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0,
+        0, -67, 0, -67, 0, 0, 0, 0, 0, -67, -67, 0, -67, 58, 0, 59, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67, -67, -67, 0, 0,
         // State 25
-        0, 36, 0, 0, 20, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -86, -86, -86, -86, 0, -86, -86, -86, -86, -86, -86, -86, -86, -86, 0, -86, -86, 0, 0, 0, 0, 0, 0, -86, 0, -86, 0, -86, 0, -86, 0, -86, -86, 0, -86, 0, 0, -86, -86, -86, 0, -86,
         // State 26
-        25, -28, -26, -28, -28, -28, -28, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0,
+        -101, -101, 0, -101, 0, -101, 0, -101, -101, -101, -101, -101, -101, -101, 0, -101, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, -101, -101, 0, -101,
         // State 27
-        0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0,
+        0, 0, 28, 0, 63, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
         // State 28
-        12, -39, 0, -39, -39, -39, -39, -39, 0, 47, 13, 48, 14, 15, 16, 17, 18,
+        0, 0, 68, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 70, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 71, 0,
         // State 29
-        0, -3, 0, 23, -3, -3, 24, -3, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -37, -37, -37, -37, 0, -37, -37, -37, -37, -37, -37, -37, -37, -37, 0, -37, -37, 0, 0, 0, 0, 0, 0, -37, 0, -37, 0, -37, 0, -37, 0, -37, -37, 0, -37, 0, 0, -37, -37, -37, 0, -37,
         // State 30
-        0, -17, 0, -17, -17, -17, -17, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -38, -38, -38, -38, 0, -38, -38, -38, -38, -38, -38, -38, -38, -38, 0, -38, -38, 0, 0, 0, 0, 0, 0, -38, 0, -38, 0, -38, 0, -38, 0, -38, -38, 0, -38, 0, 0, -38, -38, -38, 0, -38,
         // State 31
-        0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0,
+        -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, 0, -42, 0, 0, 0, 0, -42, 0, -42, 0, -42, -42, -42, 0, -42, -42, 0, -42, 0, 0, -42, -42, -42, 0, -42,
         // State 32
-        0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0,
+        -48, -48, -48, -48, 0, -48, -48, -48, -48, -48, -48, -48, -48, -48, 0, -48, -48, 0, 0, 0, 0, 0, 0, -48, 0, -48, 0, -48, 0, -48, 0, -48, -48, 0, -48, 0, 0, -48, -48, -48, 0, -48,
         // State 33
-        0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0,
+        -88, -88, -88, -88, 0, -88, -88, -88, -88, -88, -88, -88, -88, -88, 0, -88, -88, 0, 0, 0, 0, 0, 0, -88, 0, -88, 0, -88, 0, -88, 0, -88, -88, 0, -88, 0, 0, -88, -88, -88, 0, -88,
         // State 34
-        0, -37, 0, -37, -37, -37, -37, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -80, -80, -80, -80, 0, -80, -80, -80, -80, -80, -80, -80, -80, -80, 0, -80, -80, 0, 0, 0, 0, 0, 0, -80, 0, -80, 0, -80, 0, -80, 0, -80, -80, 0, -80, 0, 0, -80, -80, -80, 0, -80,
         // State 35
-        0, -48, 0, -48, -48, -48, -48, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -104, -104, -104, -104, 0, -104, -104, -104, -104, -104, -104, -104, -104, -104, 0, -104, -104, 0, 0, 0, 0, 0, 0, -104, 0, -104, 0, -104, 0, -104, 0, -104, -104, 0, -104, 0, 0, -104, -104, -104, 0, -104,
         // State 36
-        0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0,
+        0, 0, 28, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 85, 0, 0, 86, 87, 88, 30, 0, 31, 89, 32, 0, 33, 90, 34, 35, 91, 36, 92, 93, 37, 0, -61, 38, 0,
         // State 37
-        12, -40, 0, -40, -40, -40, -40, -40, 0, 47, 13, 48, 14, 15, 16, 17, 18,
+        0, 0, 68, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
         // State 38
-        0, 0, 0, 0, 0, 0, 0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 68, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
         // State 39
-        0, 0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 68, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
         // State 40
-        0, 0, 0, 0, 20, 21, 0, -8, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 68, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 0, 0,
         // State 41
-        0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 68, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
         // State 42
-        -15, -15, 0, -15, -15, -15, -15, -15, 0, -15, -15, -15, -15, -15, -15, -15, -15,
+        0, 0, 68, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
         // State 43
-        12, -41, 0, -41, -41, -41, -41, -41, 0, 47, 13, 48, 14, 15, 16, 17, 18,
+        0, 0, -13, 0, 0, 0, 0, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -13, 0, -13, 0, -13, 0, -13, 0, -13, -13, 0, -13, 0, 0, -13, 0, 0, -13, 0,
         // State 44
-        25, 0, 0, -28, -28, -28, -28, -28, 54, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 68, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
         // State 45
-        0, 0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -15, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, -15, 0, -15, 0, -15, 0, -15, -15, 0, -15, 0, 0, -15, 0, 0, -15, 0,
         // State 46
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0,
+        0, 0, 68, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
         // State 47
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0,
+        0, 0, -17, 0, 0, 0, 0, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -17, 0, -17, 0, -17, 0, -17, 0, -17, -17, 0, -17, 0, 0, -17, 0, 0, -17, 0,
         // State 48
-        0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0,
+        0, 0, 68, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
         // State 49
-        0, -38, 0, -38, -38, -38, -38, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -26, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, -26, 0, -26, 0, -26, 0, -26, -26, 0, -26, 0, 0, -26, 0, 0, -26, 0,
         // State 50
-        12, -42, 0, -42, -42, -42, -42, -42, 0, 47, 13, 48, 14, 15, 16, 17, 18,
+        0, 0, -27, 0, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, -27, 0, -27, 0, -27, 0, -27, -27, 0, -27, 0, 0, -27, 0, 0, -27, 0,
         // State 51
-        -12, -12, 0, -12, -12, -12, -12, -12, 0, -12, -12, -12, -12, -12, -12, -12, -12,
+        0, 0, 68, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
         // State 52
-        -16, -16, 0, -16, -16, -16, -16, -16, 0, -16, -16, -16, -16, -16, -16, -16, -16,
+        0, 0, -35, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, -35, 0, -35, 0, -35, 0, -35, -35, 0, -35, 0, 0, -35, 0, 0, -35, 0,
         // State 53
-        12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 14, 15, 16, 17, 18,
+        0, 0, -33, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, -33, 0, -33, 0, -33, 0, -33, -33, 0, -33, 0, 0, -33, 0, 0, -33, 0,
         // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -34, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, -34, 0, -34, 0, -34, 0, -34, -34, 0, -34, 0, 0, -34, 0, 0, -34, 0,
         // State 55
-        0, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -36, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, -36, 0, -36, 0, -36, 0, -36, -36, 0, -36, 0, 0, -36, 0, 0, -36, 0,
         // State 56
-        0, 0, 0, 0, 20, 21, 0, -1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 68, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
         // State 57
-        12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 14, 15, 16, 17, 18,
+        0, 0, -68, 0, 0, 0, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, -68, 0, -68, 0, -68, 0, -68, -68, 0, -68, 0, 0, -68, 0, 0, -68, 0,
         // State 58
-        12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 14, 15, 16, 17, 18,
+        0, 0, -69, 0, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, -69, 0, -69, 0, -69, 0, -69, -69, 0, -69, 0, 0, -69, 0, 0, -69, 0,
         // State 59
-        0, 0, 0, 0, 20, 21, 0, -2, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 60
-        0, 0, 0, 0, 20, 21, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -47, -47, 0, -47, -45, -47, -47, -47, -47, -47, -47, -47, 0, -47, 0, -47, -47, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, -47,
+        // State 61
+        0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 62
+        0, 0, 28, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
+        // State 63
+        -108, -108, 0, -108, 0, -108, 0, -108, -108, -108, -108, -108, -108, -108, 0, -108, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, -108, -108, 0, -108,
+        // State 64
+        -51, -51, 0, -51, 0, -51, 0, -51, -51, -51, -51, -51, -51, -51, 0, -51, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, -51, -51, 0, -51,
+        // State 65
+        0, 0, 0, 0, 0, 0, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 66
+        -54, -54, 0, -54, 0, -54, -59, -54, -54, -54, -54, -54, -54, -54, 0, -54, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, -54, -54, 0, -54,
+        // State 67
+        0, 0, 28, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
+        // State 68
+        0, 0, 68, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 70, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 71, 0,
+        // State 69
+        -55, -55, 0, -55, 0, -55, -48, -55, -55, -55, -55, -55, -55, -55, 0, -55, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, -55, -55, 0, -55,
+        // State 70
+        0, 0, 68, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
+        // State 71
+        0, 0, 28, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 85, 0, 0, 86, 87, 88, 30, 0, 31, 89, 32, 0, 33, 90, 34, 35, 91, 36, 92, 93, 37, 0, -63, 38, 0,
+        // State 72
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0,
+        // State 73
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0,
+        // State 74
+        0, 0, -22, 0, 0, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, -22, -22, -22, -22, 0, -22, -22, -22, 0, -22, -22, -22, -22, -22, -22, -22, -22, -22, 0, 0, -22, 0,
+        // State 75
+        0, 0, 28, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 85, 0, 0, 86, 87, 88, 30, 0, 31, 89, 32, 0, 33, 90, 34, 35, 91, 36, 92, 93, 37, 0, 0, 38, 0,
+        // State 76
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0,
+        // State 77
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0,
+        // State 78
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0,
+        // State 79
+        -47, -47, 0, 0, 0, -47, -47, -47, -47, -47, -47, -47, -47, -47, 119, -47, -47, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, -47, 0, -47,
+        // State 80
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0,
+        // State 81
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 0, 0,
+        // State 82
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0,
+        // State 83
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0,
+        // State 84
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0,
+        // State 85
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 86
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0,
+        // State 87
+        0, 0, -21, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, -21, -21, -21, -21, 0, -21, -21, -21, 0, -21, -21, -21, -21, -21, -21, -21, -21, -21, 0, 0, -21, 0,
+        // State 88
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 89
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 90
+        0, 0, 28, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
+        // State 91
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0,
+        // State 92
+        0, 0, 28, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
+        // State 93
+        -109, -109, 0, -109, 0, -109, 0, -109, -109, -109, -109, -109, -109, -109, 0, -109, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, -109, -109, 0, -109,
+        // State 94
+        0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, -64, 0, 0,
+        // State 95
+        0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, -65, 0, 0,
+        // State 96
+        0, 0, -9, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, -9, 0, -9, 0, -9, 0, -9, -9, 0, -9, 0, 0, -9, 0, 0, 0, 0,
+        // State 97
+        0, 0, 68, 129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 0, 0,
+        // State 98
+        0, 0, -6, -6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -6, 0, -6, 0, -6, 0, -6, 0, -6, -6, 0, -6, 0, 0, -6, 0, 0, 0, 0,
+        // State 99
+        -82, -82, -82, -82, 0, -82, -82, -82, -82, -82, -82, -82, -82, -82, 0, -82, -82, 0, 0, 0, 0, 0, 0, -82, 0, -82, 0, -82, 0, -82, 0, -82, -82, 0, -82, 0, 0, -82, -82, -82, 0, -82,
+        // State 100
+        -56, -56, 0, -56, 0, -56, 0, -56, -56, -56, -56, -56, -56, -56, 0, -56, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, -56, -56, 0, -56,
+        // State 101
+        0, -92, 0, -92, 0, 0, 0, 0, 0, -92, -92, 0, -92, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, -92, -92, 0, 0,
+        // State 102
+        0, 0, 0, -94, 0, 0, 0, 0, 0, -94, -94, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, -94, -94, 0, 0,
+        // State 103
+        0, 0, 0, -96, 0, 0, 0, 0, 0, -96, -96, 0, -96, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, -96, -96, 0, 0,
+        // State 104
+        0, -98, 0, -98, 0, 0, 0, -98, -98, -98, -98, 0, -98, -98, 0, -98, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, -98, -98, 0, 0,
+        // State 105
+        -100, -100, 0, -100, 0, -100, 0, -100, -100, -100, -100, -100, -100, -100, 0, -100, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, -100, -100, 0, -100,
+        // State 106
+        0, -102, 0, -102, 0, 0, 0, 0, 0, -102, -102, 0, -102, -102, 0, -102, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, -102, -102, 0, 0,
+        // State 107
+        -91, -91, -91, -91, 0, -91, -91, -91, -91, -91, -91, -91, -91, -91, 0, -91, -91, 0, 0, 0, 0, 0, 0, -91, 0, -91, 0, -91, 0, -91, 0, -91, -91, 0, -91, 0, 0, -91, -91, -91, 0, -91,
+        // State 108
+        0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 109
+        0, 0, 28, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
+        // State 110
+        0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, -40, 0, 0,
+        // State 111
+        0, 0, 68, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
+        // State 112
+        -50, -50, 0, -50, 0, -50, 0, -50, -50, -50, -50, -50, -50, -50, 0, -50, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, -50, -50, 0, -50,
+        // State 113
+        -52, -52, 0, -52, 0, -52, 0, -52, -52, -52, -52, -52, -52, -52, 0, -52, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, -52, -52, 0, -52,
+        // State 114
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0,
+        // State 115
+        0, 0, -23, 0, 0, 0, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, -23, -23, -23, -23, 0, -23, -23, -23, 0, -23, -23, -23, -23, -23, -23, -23, -23, -23, 0, 0, -23, 0,
+        // State 116
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0,
+        // State 117
+        0, 0, -4, 0, 0, 0, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0, -4, 0, 0, -4, -4, -4, -4, 0, -4, -4, -4, 0, -4, -4, -4, -4, -4, -4, -4, -4, -4, 0, -4, -4, 0,
+        // State 118
+        0, 0, 28, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
+        // State 119
+        -84, -84, -84, -84, 0, -84, -84, -84, -84, -84, -84, -84, -84, -84, 0, -84, -84, 0, 0, 0, 0, 0, 0, -84, 0, -84, 0, -84, 0, -84, 0, -84, -84, 0, -84, 0, 0, -84, -84, -84, 0, -84,
+        // State 120
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 121
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 122
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 123
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0,
+        // State 124
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 125
+        0, 0, 28, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 85, 0, 0, 86, 87, 88, 30, 0, 31, 89, 32, 0, 33, 90, 34, 35, 91, 36, 92, 93, 37, 0, -61, 38, 0,
+        // State 126
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0,
+        // State 127
+        0, 0, -10, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, -10, 0, -10, 0, -10, 0, -10, -10, 0, -10, 0, 0, -10, 0, 0, 0, 0,
+        // State 128
+        -83, -83, -83, -83, 0, -83, -83, -83, -83, -83, -83, -83, -83, -83, 0, -83, -83, 0, 0, 0, 0, 0, 0, -83, 0, -83, 0, -83, 0, -83, 0, -83, -83, 0, -83, 0, 0, -83, -83, -83, 0, -83,
+        // State 129
+        0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, -41, 0, 0,
+        // State 130
+        -53, -53, 0, -53, 0, -53, 0, -53, -53, -53, -53, -53, -53, -53, 0, -53, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, -53, -53, 0, -53,
+        // State 131
+        0, 0, -5, 0, 0, 0, 0, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0, -5, 0, 0, -5, -5, -5, -5, 0, -5, -5, -5, 0, -5, -5, -5, -5, -5, -5, -5, -5, -5, 0, -5, -5, 0,
+        // State 132
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -11, 0, 0,
+        // State 133
+        0, 0, 28, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
+        // State 134
+        0, 0, 28, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
+        // State 135
+        0, 0, 28, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 31, 0, 32, 0, 33, 0, 34, 35, 0, 36, 0, 0, 37, 0, 0, 38, 0,
+        // State 136
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0,
+        // State 137
+        0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 138
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0,
+        // State 139
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0,
+        // State 140
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0,
+        // State 141
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 148, 0, 0, 0, 0,
+        // State 142
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, 0,
+        // State 143
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0,
+        // State 144
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0,
+        // State 146
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, 0, 0, 0, 0, 0, -18, 0, 0, 0, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, 0,
+        // State 147
+        0, 0, 28, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 85, 0, 0, 86, 87, 88, 30, 0, 31, 89, 32, 0, 33, 90, 34, 35, 91, 36, 92, 93, 37, 0, -61, 38, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0,
+        // State 149
+        0, 0, 0, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 150
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0,
+        // State 151
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0,
+        // State 153
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0,
     ];
-    const __EOF_ACTION: &'static [i8] = &[
+    const __EOF_ACTION: &'static [i16] = &[
         // State 0
         0,
         // State 1
-        -50,
+        -97,
         // State 2
-        -4,
+        -66,
         // State 3
-        -46,
+        -95,
         // State 4
-        -43,
+        -111,
         // State 5
-        -28,
+        -99,
         // State 6
-        -47,
+        -87,
         // State 7
-        -44,
+        -85,
         // State 8
-        -5,
+        -25,
         // State 9
-        -18,
+        -47,
         // State 10
-        -45,
+        -90,
         // State 11
-        0,
+        -58,
         // State 12
-        -21,
+        -110,
         // State 13
-        -49,
+        -57,
         // State 14
-        -36,
+        -24,
         // State 15
-        -34,
+        -93,
         // State 16
-        -23,
+        -89,
         // State 17
-        -22,
+        -103,
         // State 18
-        0,
+        -59,
         // State 19
-        0,
+        -12,
         // State 20
-        0,
+        -14,
         // State 21
-        0,
+        -16,
         // State 22
-        0,
+        -81,
         // State 23
-        0,
+        -32,
         // State 24
-        0,
+        -67,
         // State 25
-        0,
+        -86,
         // State 26
-        0,
+        -101,
         // State 27
         0,
         // State 28
-        -39,
+        0,
         // State 29
-        -3,
+        -37,
         // State 30
-        -17,
+        -38,
         // State 31
-        0,
+        -42,
         // State 32
-        0,
+        -48,
         // State 33
-        0,
+        -88,
         // State 34
-        -37,
+        -80,
         // State 35
-        -48,
+        -104,
         // State 36
         0,
         // State 37
-        -40,
+        0,
         // State 38
         0,
         // State 39
@@ -238,9 +433,9 @@ mod __parse__Expr {
         // State 41
         0,
         // State 42
-        -15,
+        0,
         // State 43
-        -41,
+        0,
         // State 44
         0,
         // State 45
@@ -252,13 +447,13 @@ mod __parse__Expr {
         // State 48
         0,
         // State 49
-        -38,
+        0,
         // State 50
-        -42,
+        0,
         // State 51
-        -12,
+        0,
         // State 52
-        -16,
+        0,
         // State 53
         0,
         // State 54
@@ -275,152 +470,549 @@ mod __parse__Expr {
         0,
         // State 60
         0,
+        // State 61
+        0,
+        // State 62
+        0,
+        // State 63
+        -108,
+        // State 64
+        -51,
+        // State 65
+        0,
+        // State 66
+        -54,
+        // State 67
+        0,
+        // State 68
+        0,
+        // State 69
+        -55,
+        // State 70
+        0,
+        // State 71
+        0,
+        // State 72
+        0,
+        // State 73
+        0,
+        // State 74
+        0,
+        // State 75
+        0,
+        // State 76
+        0,
+        // State 77
+        0,
+        // State 78
+        0,
+        // State 79
+        0,
+        // State 80
+        0,
+        // State 81
+        0,
+        // State 82
+        0,
+        // State 83
+        0,
+        // State 84
+        0,
+        // State 85
+        0,
+        // State 86
+        0,
+        // State 87
+        0,
+        // State 88
+        0,
+        // State 89
+        0,
+        // State 90
+        0,
+        // State 91
+        0,
+        // State 92
+        0,
+        // State 93
+        -109,
+        // State 94
+        -64,
+        // State 95
+        -65,
+        // State 96
+        0,
+        // State 97
+        0,
+        // State 98
+        0,
+        // State 99
+        -82,
+        // State 100
+        -56,
+        // State 101
+        -92,
+        // State 102
+        -94,
+        // State 103
+        -96,
+        // State 104
+        -98,
+        // State 105
+        -100,
+        // State 106
+        -102,
+        // State 107
+        -91,
+        // State 108
+        0,
+        // State 109
+        0,
+        // State 110
+        -40,
+        // State 111
+        0,
+        // State 112
+        -50,
+        // State 113
+        -52,
+        // State 114
+        0,
+        // State 115
+        0,
+        // State 116
+        0,
+        // State 117
+        0,
+        // State 118
+        0,
+        // State 119
+        -84,
+        // State 120
+        0,
+        // State 121
+        0,
+        // State 122
+        0,
+        // State 123
+        0,
+        // State 124
+        0,
+        // State 125
+        0,
+        // State 126
+        0,
+        // State 127
+        0,
+        // State 128
+        -83,
+        // State 129
+        -41,
+        // State 130
+        -53,
+        // State 131
+        0,
+        // State 132
+        0,
+        // State 133
+        0,
+        // State 134
+        0,
+        // State 135
+        0,
+        // State 136
+        0,
+        // State 137
+        0,
+        // State 138
+        0,
+        // State 139
+        0,
+        // State 140
+        0,
+        // State 141
+        0,
+        // State 142
+        0,
+        // State 143
+        0,
+        // State 144
+        0,
+        // State 145
+        0,
+        // State 146
+        0,
+        // State 147
+        0,
+        // State 148
+        0,
+        // State 149
+        0,
+        // State 150
+        0,
+        // State 151
+        0,
+        // State 152
+        0,
+        // State 153
+        0,
     ];
-    const __GOTO: &'static [i8] = &[
+    const __GOTO: &'static [i16] = &[
         // State 0
-        0, 0, 2, 0, 0, 0, 0, 0, 3, 0, 4, 5, 6, 0, 0, 7, 0, 0, 0, 8, 0, 9, 10, 11, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 6, 0, 7, 8, 0, 9, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 15, 16, 0, 0, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 0, 27, 0, 0,
         // State 1
-        0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 10
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 11
-        0, 0, 26, 0, 0, 0, 0, 0, 3, 0, 4, 5, 27, 0, 28, 7, 0, 0, 0, 8, 0, 9, 10, 11, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 12
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 13
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 14
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 15
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 16
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 17
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 18
-        0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 4, 5, 6, 0, 0, 7, 0, 0, 0, 8, 0, 0, 10, 11, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 19
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 20
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 21
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 5, 6, 0, 0, 7, 0, 0, 0, 8, 0, 0, 31, 11, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 22
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 23
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 24
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 33, 0, 34, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 25
-        0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 26
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 27
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 6, 0, 7, 8, 0, 9, 61, 0, 62, 11, 12, 0, 0, 0, 13, 14, 0, 15, 16, 0, 0, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 0, 27, 0, 0,
         // State 28
-        39, 40, 41, 0, 42, 43, 0, 44, 3, 0, 4, 5, 45, 0, 0, 7, 0, 0, 0, 8, 46, 9, 10, 11, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 10, 0, 0, 11, 12, 0, 64, 65, 0, 66, 0, 0, 0, 0, 0, 17, 0, 67, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0,
         // State 29
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 30
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 31
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 32
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 33
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 35
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 36
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 72, 0, 0, 0, 73, 2, 0, 3, 0, 4, 0, 0, 0, 74, 75, 76, 77, 0, 78, 0, 6, 0, 7, 8, 79, 9, 80, 0, 0, 11, 12, 81, 0, 0, 13, 14, 82, 15, 16, 0, 83, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 84, 27, 0, 0,
         // State 37
-        39, 40, 41, 0, 42, 43, 0, 51, 3, 0, 4, 5, 45, 0, 0, 7, 0, 0, 0, 8, 46, 9, 10, 11, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 0, 0, 0, 0, 17, 0, 19, 0, 0, 0, 0, 0, 0, 26, 0, 94, 0, 0,
         // State 38
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 95, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 8, 0, 0, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 0, 16, 0, 0, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 0, 27, 0, 0,
         // State 39
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 96, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 8, 0, 0, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 0, 16, 0, 0, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 0, 27, 0, 0,
         // State 40
-        0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 97, 0, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 10, 0, 0, 11, 12, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 17, 0, 19, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0,
         // State 41
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 0, 0, 0, 0, 17, 0, 19, 0, 0, 0, 0, 0, 0, 26, 0, 101, 0, 0,
         // State 42
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 8, 0, 0, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 0, 102, 0, 0, 17, 18, 19, 0, 0, 0, 23, 24, 25, 26, 0, 27, 0, 0,
         // State 43
-        39, 40, 41, 0, 42, 53, 0, 0, 3, 0, 4, 5, 45, 0, 0, 7, 0, 0, 0, 8, 46, 9, 10, 11, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 44
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 8, 0, 0, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 0, 16, 0, 0, 17, 18, 19, 20, 0, 22, 23, 24, 25, 26, 0, 27, 0, 0,
         // State 45
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 46
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 8, 0, 0, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 0, 16, 0, 0, 17, 18, 19, 20, 0, 0, 23, 24, 25, 26, 0, 27, 0, 0,
         // State 47
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 48
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 105, 0, 7, 8, 0, 0, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 0, 0, 0, 0, 17, 0, 19, 0, 0, 0, 0, 24, 0, 26, 0, 27, 0, 0,
         // State 49
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 50
-        39, 40, 41, 0, 42, 53, 0, 0, 3, 0, 4, 5, 45, 0, 0, 7, 0, 0, 0, 8, 46, 9, 10, 11, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 51
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 0, 0, 0, 0, 17, 0, 19, 0, 0, 0, 0, 0, 0, 26, 0, 106, 0, 0,
         // State 52
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 53
-        0, 0, 57, 0, 0, 0, 0, 0, 3, 0, 4, 5, 6, 0, 0, 7, 0, 0, 0, 8, 0, 9, 10, 11, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 55
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 56
-        0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 8, 0, 0, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 0, 0, 0, 0, 17, 107, 19, 0, 0, 0, 23, 24, 0, 26, 0, 27, 0, 0,
         // State 57
-        0, 0, 60, 0, 0, 0, 0, 0, 3, 0, 4, 5, 6, 0, 0, 7, 0, 0, 0, 8, 0, 9, 10, 11, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 58
-        0, 0, 61, 0, 0, 0, 0, 0, 3, 0, 4, 5, 6, 0, 0, 7, 0, 0, 0, 8, 0, 9, 10, 11, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 59
-        0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 60
-        0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 61
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 62
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 111, 0, 0, 0, 6, 0, 7, 8, 0, 9, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 15, 16, 0, 0, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 0, 27, 0, 0,
+        // State 63
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 64
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 65
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 66
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 67
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 6, 0, 7, 8, 0, 9, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 15, 16, 0, 0, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 0, 27, 0, 0,
+        // State 68
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 10, 0, 0, 11, 12, 0, 113, 65, 0, 66, 0, 0, 0, 0, 0, 17, 0, 67, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0,
+        // State 69
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 70
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 0, 0, 0, 0, 17, 0, 19, 0, 0, 0, 0, 0, 0, 26, 0, 114, 0, 0,
+        // State 71
+        0, 0, 0, 0, 0, 0, 73, 2, 0, 3, 0, 4, 0, 0, 0, 74, 75, 76, 77, 0, 115, 0, 6, 0, 7, 8, 79, 9, 80, 0, 0, 11, 12, 81, 0, 0, 13, 14, 0, 15, 16, 0, 83, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 84, 27, 0, 0,
+        // State 72
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 73
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 74
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 75
+        0, 0, 0, 0, 0, 0, 73, 2, 0, 3, 0, 4, 0, 0, 0, 74, 116, 0, 77, 0, 0, 0, 6, 0, 7, 8, 79, 9, 80, 0, 0, 11, 12, 81, 0, 0, 13, 14, 0, 15, 16, 0, 117, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 84, 27, 0, 0,
+        // State 76
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 77
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 78
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 79
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 80
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 81
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 82
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 83
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 84
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 85
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 86
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 87
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 88
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 89
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 90
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 6, 0, 7, 8, 0, 9, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 15, 16, 0, 0, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 0, 27, 0, 0,
+        // State 91
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 92
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 127, 0, 0, 0, 6, 0, 7, 8, 0, 9, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 15, 16, 0, 0, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 0, 27, 0, 0,
+        // State 93
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 94
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 95
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 96
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 97
+        0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 10, 0, 0, 11, 12, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 17, 0, 19, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0,
+        // State 98
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 99
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 100
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 101
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 102
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 103
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 104
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 105
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 106
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 107
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 108
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 109
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 6, 0, 7, 8, 0, 9, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 15, 16, 0, 0, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 0, 27, 0, 0,
+        // State 110
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 111
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 0, 0, 0, 0, 17, 0, 19, 0, 0, 0, 0, 0, 0, 26, 0, 131, 0, 0,
+        // State 112
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 113
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 114
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 115
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 116
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 117
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 118
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 6, 0, 7, 8, 0, 9, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 15, 16, 0, 0, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 0, 27, 0, 0,
+        // State 119
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 120
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 121
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 122
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 123
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 124
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 125
+        0, 0, 72, 0, 0, 0, 73, 2, 0, 3, 0, 4, 0, 0, 0, 74, 75, 76, 77, 0, 78, 0, 6, 0, 7, 8, 79, 9, 80, 0, 0, 11, 12, 81, 0, 0, 13, 14, 140, 15, 16, 0, 83, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 84, 27, 0, 0,
+        // State 126
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 127
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 128
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 129
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 130
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 131
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 132
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 133
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 141, 0, 0, 0, 6, 0, 7, 8, 0, 9, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 15, 16, 0, 0, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 0, 27, 0, 0,
+        // State 134
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 142, 0, 0, 0, 6, 0, 7, 8, 0, 9, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 15, 16, 0, 0, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 0, 27, 0, 0,
+        // State 135
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 143, 0, 0, 0, 6, 0, 7, 8, 0, 9, 10, 0, 0, 11, 12, 0, 0, 0, 13, 14, 0, 15, 16, 0, 0, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 0, 27, 0, 0,
+        // State 136
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 137
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 138
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 139
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 140
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 141
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 142
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 143
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 144
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 147
+        0, 0, 72, 0, 0, 0, 73, 2, 0, 3, 0, 4, 0, 0, 0, 74, 75, 76, 77, 0, 78, 0, 6, 0, 7, 8, 79, 9, 80, 0, 0, 11, 12, 81, 0, 0, 13, 14, 151, 15, 16, 0, 83, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 84, 27, 0, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 150
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 151
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 153
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
     fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
         const __TERMINAL: &'static [&'static str] = &[
+            r###""%""###,
+            r###""&""###,
             r###""(""###,
             r###"")""###,
-            r###"") => ""###,
+            r###"") =>""###,
             r###""*""###,
+            r###""**""###,
             r###""+""###,
             r###""-""###,
+            r###""..""###,
+            r###""..=""###,
             r###""/""###,
             r###"";""###,
+            r###""<<""###,
             r###""=""###,
+            r###"">>""###,
+            r###""^""###,
+            r###""break""###,
+            r###""call(""###,
+            r###""catch""###,
             r###""const""###,
+            r###""continue""###,
+            r###""doc comment""###,
             r###""false""###,
+            r###""finally""###,
+            r###""float""###,
+            r###""for""###,
+            r###""identifier""###,
+            r###""in""###,
+            r###""int""###,
             r###""let""###,
+            r###""nil""###,
+            r###""string""###,
+            r###""throw""###,
             r###""true""###,
-            r###"r#"\"[^\"]+\""#"###,
-            r###"r#"[0-9]+"#"###,
-            r###"r#"[A-z]([A-z|0-9|_])*"#"###,
-            r###"r#"\\d+\\.\\d+"#"###,
+            r###""try""###,
+            r###""yield""###,
+            r###""{""###,
+            r###""|""###,
+            r###""}""###,
+            r###""~""###,
+            r###""~/""###,
         ];
-        __ACTION[(__state * 17)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+        __ACTION[(__state * 42)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
             if state == 0 {
                 None
             } else {
@@ -429,15 +1021,12 @@ mod __parse__Expr {
         }).collect()
     }
     pub struct ExprParser {
-        builder: super::__intern_token::__MatcherBuilder,
         _priv: (),
     }
 
     impl ExprParser {
         pub fn new() -> ExprParser {
-            let __builder = super::__intern_token::__MatcherBuilder::new();
             ExprParser {
-                builder: __builder,
                 _priv: (),
             }
         }
@@ -445,13 +1034,16 @@ mod __parse__Expr {
         #[allow(dead_code)]
         pub fn parse<
             'input,
+            __TOKEN: __ToTriple<'input, Error=LexicalError>,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
         >(
             &self,
-            input: &'input str,
-        ) -> Result<Box<Expr>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            __tokens0: __TOKENS,
+        ) -> Result<Box<Expr>, __lalrpop_util::ParseError<usize, Tok<'input>, LexicalError>>
         {
-            let mut __tokens = self.builder.matcher(input);
-            let mut __states = vec![0_i8];
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            let mut __states = vec![0_i16];
             let mut __symbols = vec![];
             let mut __integer;
             let mut __lookahead;
@@ -460,27 +1052,52 @@ mod __parse__Expr {
                 __lookahead = match __tokens.next() {
                     Some(Ok(v)) => v,
                     None => break '__shift,
-                    Some(Err(e)) => return Err(e),
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
                 };
                 *__last_location = __lookahead.2.clone();
                 __integer = match __lookahead.1 {
-                    Token(4, _) if true => 0,
-                    Token(5, _) if true => 1,
-                    Token(6, _) if true => 2,
-                    Token(7, _) if true => 3,
-                    Token(8, _) if true => 4,
-                    Token(9, _) if true => 5,
-                    Token(10, _) if true => 6,
-                    Token(11, _) if true => 7,
-                    Token(12, _) if true => 8,
-                    Token(13, _) if true => 9,
-                    Token(14, _) if true => 10,
-                    Token(15, _) if true => 11,
-                    Token(16, _) if true => 12,
-                    Token(0, _) if true => 13,
-                    Token(1, _) if true => 14,
-                    Token(2, _) if true => 15,
-                    Token(3, _) if true => 16,
+                    Tok::Percent if true => 0,
+                    Tok::Amp if true => 1,
+                    Tok::LParen if true => 2,
+                    Tok::RParen if true => 3,
+                    Tok::RParenArrow if true => 4,
+                    Tok::Star if true => 5,
+                    Tok::StarStar if true => 6,
+                    Tok::Plus if true => 7,
+                    Tok::Minus if true => 8,
+                    Tok::DotDot if true => 9,
+                    Tok::DotDotEquals if true => 10,
+                    Tok::Slash if true => 11,
+                    Tok::Semicolon if true => 12,
+                    Tok::ShiftLeft if true => 13,
+                    Tok::Equals if true => 14,
+                    Tok::ShiftRight if true => 15,
+                    Tok::Caret if true => 16,
+                    Tok::Break if true => 17,
+                    Tok::CallParen if true => 18,
+                    Tok::Catch if true => 19,
+                    Tok::Const if true => 20,
+                    Tok::Continue if true => 21,
+                    Tok::DocComment(_) if true => 22,
+                    Tok::False if true => 23,
+                    Tok::Finally if true => 24,
+                    Tok::Float(_) if true => 25,
+                    Tok::For if true => 26,
+                    Tok::Identifier(_) if true => 27,
+                    Tok::In if true => 28,
+                    Tok::Int(_) if true => 29,
+                    Tok::Let if true => 30,
+                    Tok::Nil if true => 31,
+                    Tok::String(_) if true => 32,
+                    Tok::Throw if true => 33,
+                    Tok::True if true => 34,
+                    Tok::Try if true => 35,
+                    Tok::Yield if true => 36,
+                    Tok::LBrace if true => 37,
+                    Tok::Pipe if true => 38,
+                    Tok::RBrace if true => 39,
+                    Tok::Tilde if true => 40,
+                    Tok::TildeSlash if true => 41,
                     _ => {
                         let __state = *__states.last().unwrap() as usize;
                         let __error = __lalrpop_util::ParseError::UnrecognizedToken {
@@ -492,75 +1109,175 @@ mod __parse__Expr {
                 };
                 '__inner: loop {
                     let __state = *__states.last().unwrap() as usize;
-                    let __action = __ACTION[__state * 17 + __integer];
+                    let __action = __ACTION[__state * 42 + __integer];
                     if __action > 0 {
                         let __symbol = match __integer {
                             0 => match __lookahead.1 {
-                                Token(4, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::Percent => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             1 => match __lookahead.1 {
-                                Token(5, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::Amp => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             2 => match __lookahead.1 {
-                                Token(6, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::LParen => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             3 => match __lookahead.1 {
-                                Token(7, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::RParen => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             4 => match __lookahead.1 {
-                                Token(8, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::RParenArrow => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             5 => match __lookahead.1 {
-                                Token(9, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::Star => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             6 => match __lookahead.1 {
-                                Token(10, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::StarStar => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             7 => match __lookahead.1 {
-                                Token(11, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::Plus => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             8 => match __lookahead.1 {
-                                Token(12, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::Minus => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             9 => match __lookahead.1 {
-                                Token(13, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::DotDot => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             10 => match __lookahead.1 {
-                                Token(14, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::DotDotEquals => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             11 => match __lookahead.1 {
-                                Token(15, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::Slash => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             12 => match __lookahead.1 {
-                                Token(16, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::Semicolon => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             13 => match __lookahead.1 {
-                                Token(0, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::ShiftLeft => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             14 => match __lookahead.1 {
-                                Token(1, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::Equals => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             15 => match __lookahead.1 {
-                                Token(2, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::ShiftRight => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             16 => match __lookahead.1 {
-                                Token(3, __tok0) => __Symbol::Variant0((__tok0)),
+                                __tok @ Tok::Caret => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            17 => match __lookahead.1 {
+                                __tok @ Tok::Break => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            18 => match __lookahead.1 {
+                                __tok @ Tok::CallParen => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            19 => match __lookahead.1 {
+                                __tok @ Tok::Catch => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            20 => match __lookahead.1 {
+                                __tok @ Tok::Const => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            21 => match __lookahead.1 {
+                                __tok @ Tok::Continue => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            22 => match __lookahead.1 {
+                                Tok::DocComment(__tok0) => __Symbol::Variant1((__tok0)),
+                                _ => unreachable!(),
+                            },
+                            23 => match __lookahead.1 {
+                                __tok @ Tok::False => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            24 => match __lookahead.1 {
+                                __tok @ Tok::Finally => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            25 => match __lookahead.1 {
+                                Tok::Float(__tok0) => __Symbol::Variant2((__tok0)),
+                                _ => unreachable!(),
+                            },
+                            26 => match __lookahead.1 {
+                                __tok @ Tok::For => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            27 => match __lookahead.1 {
+                                Tok::Identifier(__tok0) => __Symbol::Variant1((__tok0)),
+                                _ => unreachable!(),
+                            },
+                            28 => match __lookahead.1 {
+                                __tok @ Tok::In => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            29 => match __lookahead.1 {
+                                Tok::Int(__tok0) => __Symbol::Variant1((__tok0)),
+                                _ => unreachable!(),
+                            },
+                            30 => match __lookahead.1 {
+                                __tok @ Tok::Let => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            31 => match __lookahead.1 {
+                                __tok @ Tok::Nil => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            32 => match __lookahead.1 {
+                                Tok::String(__tok0) => __Symbol::Variant1((__tok0)),
+                                _ => unreachable!(),
+                            },
+                            33 => match __lookahead.1 {
+                                __tok @ Tok::Throw => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            34 => match __lookahead.1 {
+                                __tok @ Tok::True => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            35 => match __lookahead.1 {
+                                __tok @ Tok::Try => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            36 => match __lookahead.1 {
+                                __tok @ Tok::Yield => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            37 => match __lookahead.1 {
+                                __tok @ Tok::LBrace => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            38 => match __lookahead.1 {
+                                __tok @ Tok::Pipe => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            39 => match __lookahead.1 {
+                                __tok @ Tok::RBrace => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            40 => match __lookahead.1 {
+                                __tok @ Tok::Tilde => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            41 => match __lookahead.1 {
+                                __tok @ Tok::TildeSlash => __Symbol::Variant0((__tok)),
                                 _ => unreachable!(),
                             },
                             _ => unreachable!(),
@@ -569,7 +1286,7 @@ mod __parse__Expr {
                         __symbols.push((__lookahead.0, __symbol, __lookahead.2));
                         continue '__shift;
                     } else if __action < 0 {
-                        if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        if let Some(r) = __reduce(__action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
                             if r.is_err() {
                                 return r;
                             }
@@ -591,7 +1308,7 @@ mod __parse__Expr {
                 let __state = *__states.last().unwrap() as usize;
                 let __action = __EOF_ACTION[__state];
                 if __action < 0 {
-                    if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    if let Some(r) = __reduce(__action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
                         return r;
                     }
                 } else {
@@ -610,172 +1327,354 @@ mod __parse__Expr {
     pub(crate) fn __reduce<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
-    ) -> Option<Result<Box<Expr>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    ) -> Option<Result<Box<Expr>,__lalrpop_util::ParseError<usize, Tok<'input>, LexicalError>>>
     {
         let (__pop_states, __symbol, __nonterminal) = match -__action {
             1 => {
-                __reduce1(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce1(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             2 => {
-                __reduce2(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce2(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             3 => {
-                __reduce3(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce3(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             4 => {
-                __reduce4(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce4(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             5 => {
-                __reduce5(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce5(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             6 => {
-                __reduce6(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce6(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             7 => {
-                __reduce7(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce7(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             8 => {
-                __reduce8(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce8(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             9 => {
-                __reduce9(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce9(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             10 => {
-                __reduce10(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce10(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             11 => {
-                __reduce11(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce11(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             12 => {
-                __reduce12(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce12(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             13 => {
-                __reduce13(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce13(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             14 => {
-                __reduce14(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce14(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             15 => {
-                __reduce15(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce15(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             16 => {
-                __reduce16(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce16(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             17 => {
-                __reduce17(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce17(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             18 => {
-                __reduce18(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce18(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             19 => {
-                __reduce19(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce19(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             20 => {
-                __reduce20(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce20(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             21 => {
-                __reduce21(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce21(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             22 => {
-                __reduce22(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce22(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             23 => {
-                __reduce23(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce23(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             24 => {
-                __reduce24(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce24(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             25 => {
-                __reduce25(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce25(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             26 => {
-                __reduce26(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce26(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             27 => {
-                __reduce27(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce27(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             28 => {
-                __reduce28(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce28(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             29 => {
-                __reduce29(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce29(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             30 => {
-                __reduce30(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce30(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             31 => {
-                __reduce31(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce31(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             32 => {
-                __reduce32(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce32(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             33 => {
-                __reduce33(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce33(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             34 => {
-                __reduce34(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce34(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             35 => {
-                __reduce35(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce35(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             36 => {
-                __reduce36(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce36(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             37 => {
-                __reduce37(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce37(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             38 => {
-                __reduce38(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce38(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             39 => {
-                __reduce39(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce39(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             40 => {
-                __reduce40(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce40(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             41 => {
-                __reduce41(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce41(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             42 => {
-                __reduce42(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce42(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             43 => {
-                __reduce43(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce43(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             44 => {
-                __reduce44(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce44(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             45 => {
-                __reduce45(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce45(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             46 => {
-                __reduce46(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce46(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             47 => {
-                __reduce47(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce47(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             48 => {
-                __reduce48(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce48(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             49 => {
-                __reduce49(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+                __reduce49(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             50 => {
+                __reduce50(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            51 => {
+                __reduce51(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            52 => {
+                __reduce52(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            53 => {
+                __reduce53(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            54 => {
+                __reduce54(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            55 => {
+                __reduce55(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            56 => {
+                __reduce56(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            57 => {
+                __reduce57(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            58 => {
+                __reduce58(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            59 => {
+                __reduce59(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            60 => {
+                __reduce60(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            61 => {
+                __reduce61(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            62 => {
+                __reduce62(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            63 => {
+                __reduce63(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            64 => {
+                __reduce64(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            65 => {
+                __reduce65(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            66 => {
+                __reduce66(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            67 => {
+                __reduce67(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            68 => {
+                __reduce68(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            69 => {
+                __reduce69(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            70 => {
+                __reduce70(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            71 => {
+                __reduce71(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            72 => {
+                __reduce72(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            73 => {
+                __reduce73(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            74 => {
+                __reduce74(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            75 => {
+                __reduce75(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            76 => {
+                __reduce76(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            77 => {
+                __reduce77(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            78 => {
+                __reduce78(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            79 => {
+                __reduce79(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            80 => {
+                __reduce80(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            81 => {
+                __reduce81(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            82 => {
+                __reduce82(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            83 => {
+                __reduce83(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            84 => {
+                __reduce84(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            85 => {
+                __reduce85(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            86 => {
+                __reduce86(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            87 => {
+                __reduce87(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            88 => {
+                __reduce88(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            89 => {
+                __reduce89(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            90 => {
+                __reduce90(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            91 => {
+                __reduce91(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            92 => {
+                __reduce92(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            93 => {
+                __reduce93(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            94 => {
+                __reduce94(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            95 => {
+                __reduce95(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            96 => {
+                __reduce96(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            97 => {
+                __reduce97(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            98 => {
+                __reduce98(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            99 => {
+                __reduce99(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            100 => {
+                __reduce100(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            101 => {
+                __reduce101(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            102 => {
+                __reduce102(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            103 => {
+                __reduce103(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            104 => {
+                __reduce104(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            105 => {
+                __reduce105(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            106 => {
+                __reduce106(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            107 => {
+                __reduce107(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            108 => {
+                __reduce108(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            109 => {
+                __reduce109(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            110 => {
+                __reduce110(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            111 => {
                 // __Expr = Expr => ActionFn(1);
-                let __sym0 = __pop_Variant1(__symbols);
+                let __sym0 = __pop_Variant3(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action1::<>(input, __sym0);
+                let __nt = super::__action1::<>(__sym0);
                 return Some(Ok(__nt));
             }
-            51 => {
-                __reduce51(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            112 => {
+                __reduce112(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
             _ => panic!("invalid action code {}", __action)
         };
@@ -783,18 +1682,18 @@ mod __parse__Expr {
         __states.truncate(__states_len - __pop_states);
         __symbols.push(__symbol);
         let __state = *__states.last().unwrap() as usize;
-        let __next_state = __GOTO[__state * 26 + __nonterminal] - 1;
+        let __next_state = __GOTO[__state * 57 + __nonterminal] - 1;
         __states.push(__next_state);
         None
     }
-    fn __pop_Variant1<
+    fn __pop_Variant9<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Expr>, usize)
+    ) -> (usize, (Identifier, Vec<Expr>), usize)
      {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant1(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Variant9(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
@@ -802,65 +1701,120 @@ mod __parse__Expr {
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
+    ) -> (usize, Box<Expr>, usize)
      {
         match __symbols.pop().unwrap() {
             (__l, __Symbol::Variant3(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Variant7<
+    fn __pop_Variant5<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Identifier, usize)
+    ) -> (usize, Expr, usize)
      {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant7(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Variant5(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Variant2<
+    fn __pop_Variant14<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Operator, usize)
+    ) -> (usize, Identifier, usize)
      {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant2(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Variant14(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Variant5<
+    fn __pop_Variant7<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, bool, usize)
+    ) -> (usize, Operator, usize)
      {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant5(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Variant7(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Variant6<
+    fn __pop_Variant10<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, f64, usize)
+    ) -> (usize, String, usize)
      {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant6(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Variant10(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Variant9<
+    fn __pop_Variant0<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, i32, usize)
+    ) -> (usize, Tok<'input>, usize)
      {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant9(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Variant0(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant16<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Value, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant16(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant8<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<Expr>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant8(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant13<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, bool, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant13(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant2<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, f64, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant2(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant12<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::option::Option<Box<Expr>>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant12(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
@@ -868,3528 +1822,7092 @@ mod __parse__Expr {
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<Expr>, usize)
+    ) -> (usize, ::std::vec::Vec<Box<Expr>>, usize)
      {
         match __symbols.pop().unwrap() {
             (__l, __Symbol::Variant4(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Variant8<
+    fn __pop_Variant6<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<Expr>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant6(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant15<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, ::std::vec::Vec<Identifier>, usize)
      {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant8(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Variant15(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Variant0<
+    fn __pop_Variant11<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<String>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant11(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant1<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize)
      {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant0(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Variant1(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
     pub(crate) fn __reduce1<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Assignment = Identifier, "=", Expr => ActionFn(31);
-        let __sym2 = __pop_Variant1(__symbols);
+        // (<ExprOrStmt> ";") = ExprOrStmt, ";" => ActionFn(100);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action31::<>(input, __sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (3, __symbol, 0)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action100::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 0)
     }
     pub(crate) fn __reduce2<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ConstAssignment = "const", Identifier, "=", Expr => ActionFn(30);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action30::<>(input, __sym0, __sym1, __sym2, __sym3);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (4, __symbol, 1)
+        // (<ExprOrStmt> ";")* =  => ActionFn(98);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action98::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (0, __symbol, 1)
     }
     pub(crate) fn __reduce3<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Expr = Expr, ExprOp, Factor => ActionFn(6);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // (<ExprOrStmt> ";")* = (<ExprOrStmt> ";")+ => ActionFn(99);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action6::<>(input, __sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (3, __symbol, 2)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action99::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 1)
     }
     pub(crate) fn __reduce4<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Expr = Factor => ActionFn(7);
-        let __sym0 = __pop_Variant1(__symbols);
+        // (<ExprOrStmt> ";")+ = ExprOrStmt, ";" => ActionFn(107);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action7::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 2)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action107::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (2, __symbol, 2)
     }
     pub(crate) fn __reduce5<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Expr = String => ActionFn(8);
-        let __sym0 = __pop_Variant1(__symbols);
+        // (<ExprOrStmt> ";")+ = (<ExprOrStmt> ";")+, ExprOrStmt, ";" => ActionFn(108);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action8::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 2)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action108::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 2)
     }
     pub(crate) fn __reduce6<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOp = "+" => ActionFn(9);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Argument = PowerBase => ActionFn(76);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action9::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
+        let __nt = super::__action76::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
         (1, __symbol, 3)
     }
     pub(crate) fn __reduce7<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOp = "-" => ActionFn(10);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action10::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
-        (1, __symbol, 3)
+        // Argument* =  => ActionFn(78);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action78::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (0, __symbol, 4)
     }
     pub(crate) fn __reduce8<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmt = Expr => ActionFn(2);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Argument* = Argument+ => ActionFn(79);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action2::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
+        let __nt = super::__action79::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
         (1, __symbol, 4)
     }
     pub(crate) fn __reduce9<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmt = LetAssignment => ActionFn(3);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Argument+ = Argument => ActionFn(105);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action3::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 4)
+        let __nt = super::__action105::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (1, __symbol, 5)
     }
     pub(crate) fn __reduce10<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmt = ConstAssignment => ActionFn(4);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Argument+ = Argument+, Argument => ActionFn(106);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action4::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 4)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action106::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (2, __symbol, 5)
     }
     pub(crate) fn __reduce11<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmt = Assignment => ActionFn(5);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Assignment = Identifier, "=", Expr => ActionFn(74);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action5::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 4)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action74::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 6)
     }
     pub(crate) fn __reduce12<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmtSep = ExprOrStmt, ";" => ActionFn(23);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BitAnd = Tier<BitAndOp, Shift> => ActionFn(30);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action23::<>(input, __sym0, __sym1);
+        let __end = __sym0.2.clone();
+        let __nt = super::__action30::<>(__sym0);
         let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (2, __symbol, 5)
+        (1, __symbol, 7)
     }
     pub(crate) fn __reduce13<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmtSep* =  => ActionFn(35);
-        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-        let __nt = super::__action35::<>(input, &__start, &__end);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (0, __symbol, 6)
+        // BitAndOp = "&" => ActionFn(36);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action36::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 8)
     }
     pub(crate) fn __reduce14<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmtSep* = ExprOrStmtSep+ => ActionFn(36);
-        let __sym0 = __pop_Variant4(__symbols);
+        // BitOr = Tier<BitOrOp, BitXor> => ActionFn(28);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action36::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (1, __symbol, 6)
+        let __nt = super::__action28::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 9)
     }
     pub(crate) fn __reduce15<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmtSep+ = ExprOrStmtSep => ActionFn(45);
-        let __sym0 = __pop_Variant3(__symbols);
+        // BitOrOp = "|" => ActionFn(34);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action45::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (1, __symbol, 7)
+        let __nt = super::__action34::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 10)
     }
     pub(crate) fn __reduce16<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmtSep+ = ExprOrStmtSep+, ExprOrStmtSep => ActionFn(46);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        // BitXor = Tier<BitXorOp, BitAnd> => ActionFn(29);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action46::<>(input, __sym0, __sym1);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (2, __symbol, 7)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action29::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 11)
     }
     pub(crate) fn __reduce17<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Factor = Factor, FactorOp, Term => ActionFn(11);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BitXorOp = "^" => ActionFn(35);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action11::<>(input, __sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (3, __symbol, 8)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action35::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 12)
     }
     pub(crate) fn __reduce18<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Factor = Term => ActionFn(12);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Body = "{", Program, "}" => ActionFn(20);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action12::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 8)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action20::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (3, __symbol, 13)
     }
     pub(crate) fn __reduce19<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // FactorOp = "*" => ActionFn(13);
+        // Catch = "catch", "(", Identifier, ")", Body => ActionFn(19);
+        let __sym4 = __pop_Variant8(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant14(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action13::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
-        (1, __symbol, 9)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action19::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant9(__nt), __end);
+        (5, __symbol, 14)
     }
     pub(crate) fn __reduce20<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // FactorOp = "/" => ActionFn(14);
+        // ConstAssignment = "const", Identifier, "=", Expr => ActionFn(73);
+        let __sym3 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action14::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
-        (1, __symbol, 9)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action73::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (4, __symbol, 15)
     }
     pub(crate) fn __reduce21<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // False = "false" => ActionFn(27);
-        let __sym0 = __pop_Variant0(__symbols);
+        // DocComment = "doc comment" => ActionFn(21);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action27::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
-        (1, __symbol, 10)
+        let __nt = super::__action21::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant10(__nt), __end);
+        (1, __symbol, 16)
     }
     pub(crate) fn __reduce22<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Float = r#"\\d+\\.\\d+"# => ActionFn(25);
-        let __sym0 = __pop_Variant0(__symbols);
+        // DocComment+ = DocComment => ActionFn(94);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action25::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
-        (1, __symbol, 11)
+        let __nt = super::__action94::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant11(__nt), __end);
+        (1, __symbol, 17)
     }
     pub(crate) fn __reduce23<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Identifier = r#"[A-z]([A-z|0-9|_])*"# => ActionFn(34);
-        let __sym0 = __pop_Variant0(__symbols);
+        // DocComment+ = DocComment+, DocComment => ActionFn(95);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action34::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
-        (1, __symbol, 12)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action95::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant11(__nt), __end);
+        (2, __symbol, 17)
     }
     pub(crate) fn __reduce24<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Identifier* =  => ActionFn(37);
-        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-        let __nt = super::__action37::<>(input, &__start, &__end);
-        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
-        (0, __symbol, 13)
+        // Expr = Range => ActionFn(22);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action22::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 18)
     }
     pub(crate) fn __reduce25<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Identifier* = Identifier+ => ActionFn(38);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Expr = Function => ActionFn(23);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action38::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
-        (1, __symbol, 13)
+        let __nt = super::__action23::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 18)
     }
     pub(crate) fn __reduce26<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Identifier+ = Identifier => ActionFn(43);
-        let __sym0 = __pop_Variant7(__symbols);
+        // ExprOp = "+" => ActionFn(39);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action43::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
-        (1, __symbol, 14)
+        let __nt = super::__action39::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 19)
     }
     pub(crate) fn __reduce27<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Identifier+ = Identifier+, Identifier => ActionFn(44);
-        let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant8(__symbols);
+        // ExprOp = "-" => ActionFn(40);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action44::<>(input, __sym0, __sym1);
-        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
-        (2, __symbol, 14)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action40::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 19)
     }
     pub(crate) fn __reduce28<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // IdentifierExpr = Identifier => ActionFn(32);
-        let __sym0 = __pop_Variant7(__symbols);
+        // ExprOrStmt = DocComment+, Stmt => ActionFn(3);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action32::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 15)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action3::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 20)
     }
     pub(crate) fn __reduce29<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // IdentifierExprUnboxed = Identifier => ActionFn(33);
-        let __sym0 = __pop_Variant7(__symbols);
+        // ExprOrStmt = Stmt => ActionFn(4);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action33::<>(input, __sym0);
+        let __nt = super::__action4::<>(__sym0);
         let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (1, __symbol, 16)
+        (1, __symbol, 20)
     }
     pub(crate) fn __reduce30<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // IdentifierExprUnboxed* =  => ActionFn(39);
-        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-        let __nt = super::__action39::<>(input, &__start, &__end);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (0, __symbol, 17)
+        // ExprOrStmt? = ExprOrStmt => ActionFn(96);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action96::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (1, __symbol, 21)
     }
     pub(crate) fn __reduce31<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // IdentifierExprUnboxed* = IdentifierExprUnboxed+ => ActionFn(40);
-        let __sym0 = __pop_Variant4(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action40::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (1, __symbol, 17)
+        // ExprOrStmt? =  => ActionFn(97);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action97::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (0, __symbol, 21)
     }
     pub(crate) fn __reduce32<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // IdentifierExprUnboxed+ = IdentifierExprUnboxed => ActionFn(41);
+        // Factor = Tier<FactorOp, Unary> => ActionFn(33);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action41::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (1, __symbol, 18)
+        let __nt = super::__action33::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 22)
     }
     pub(crate) fn __reduce33<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // IdentifierExprUnboxed+ = IdentifierExprUnboxed+, IdentifierExprUnboxed => ActionFn(42);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        // FactorOp = "*" => ActionFn(41);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action42::<>(input, __sym0, __sym1);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (2, __symbol, 18)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action41::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 23)
     }
     pub(crate) fn __reduce34<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Int = r#"[0-9]+"# => ActionFn(24);
+        // FactorOp = "/" => ActionFn(42);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action24::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant9(__nt), __end);
-        (1, __symbol, 19)
+        let __nt = super::__action42::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 23)
     }
     pub(crate) fn __reduce35<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // LetAssignment = "let", Identifier, "=", Expr => ActionFn(29);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant7(__symbols);
+        // FactorOp = "%" => ActionFn(43);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action29::<>(input, __sym0, __sym1, __sym2, __sym3);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (4, __symbol, 20)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action43::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 23)
     }
     pub(crate) fn __reduce36<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // String = r#"\"[^\"]+\""# => ActionFn(28);
+        // FactorOp = "~/" => ActionFn(44);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action28::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 21)
+        let __nt = super::__action44::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 23)
     }
     pub(crate) fn __reduce37<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = Identifier, "(", ")" => ActionFn(53);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
+        // False = "false" => ActionFn(70);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action53::<>(input, __sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (3, __symbol, 22)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action70::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant13(__nt), __end);
+        (1, __symbol, 24)
     }
     pub(crate) fn __reduce38<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = Identifier, "(", IdentifierExprUnboxed+, ")" => ActionFn(54);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant4(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Float = "float" => ActionFn(68);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action54::<>(input, __sym0, __sym1, __sym2, __sym3);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (4, __symbol, 22)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action68::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
+        (1, __symbol, 25)
     }
     pub(crate) fn __reduce39<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = "(", ") => " => ActionFn(49);
-        let __sym1 = __pop_Variant0(__symbols);
+        // For = "for", Identifier, "in", Expr, "{", Program, "}" => ActionFn(15);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant8(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action49::<>(input, __sym0, __sym1);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (2, __symbol, 22)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action15::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (7, __symbol, 26)
     }
     pub(crate) fn __reduce40<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = "(", Identifier+, ") => " => ActionFn(50);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant8(__symbols);
+        // Function = "(", ") =>", Expr => ActionFn(117);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action50::<>(input, __sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (3, __symbol, 22)
+        let __nt = super::__action117::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 27)
     }
     pub(crate) fn __reduce41<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = "(", ") => ", ExprOrStmtSep+ => ActionFn(51);
-        let __sym2 = __pop_Variant4(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // Function = "(", Identifier+, ") =>", Expr => ActionFn(118);
+        let __sym3 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant15(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action51::<>(input, __sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (3, __symbol, 22)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action118::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (4, __symbol, 27)
     }
     pub(crate) fn __reduce42<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = "(", Identifier+, ") => ", ExprOrStmtSep+ => ActionFn(52);
-        let __sym3 = __pop_Variant4(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant8(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Identifier = "identifier" => ActionFn(77);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action52::<>(input, __sym0, __sym1, __sym2, __sym3);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (4, __symbol, 22)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action77::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant14(__nt), __end);
+        (1, __symbol, 28)
     }
     pub(crate) fn __reduce43<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = Float => ActionFn(17);
-        let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action17::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 22)
+        // Identifier* =  => ActionFn(92);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action92::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant15(__nt), __end);
+        (0, __symbol, 29)
     }
     pub(crate) fn __reduce44<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = Int => ActionFn(18);
-        let __sym0 = __pop_Variant9(__symbols);
+        // Identifier* = Identifier+ => ActionFn(93);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action18::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 22)
+        let __nt = super::__action93::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant15(__nt), __end);
+        (1, __symbol, 29)
     }
     pub(crate) fn __reduce45<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = True => ActionFn(19);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Identifier+ = Identifier => ActionFn(103);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action19::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 22)
+        let __nt = super::__action103::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant15(__nt), __end);
+        (1, __symbol, 30)
     }
     pub(crate) fn __reduce46<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = False => ActionFn(20);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Identifier+ = Identifier+, Identifier => ActionFn(104);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action20::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 22)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action104::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant15(__nt), __end);
+        (2, __symbol, 30)
     }
     pub(crate) fn __reduce47<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = IdentifierExpr => ActionFn(21);
-        let __sym0 = __pop_Variant1(__symbols);
+        // IdentifierExpr = Identifier => ActionFn(75);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action21::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 22)
+        let __nt = super::__action75::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 31)
     }
     pub(crate) fn __reduce48<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Int = "int" => ActionFn(66);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action66::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant16(__nt), __end);
+        (1, __symbol, 32)
+    }
+    pub(crate) fn __reduce49<
+        'input,
+    >(
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = "(", Expr, ")" => ActionFn(22);
+        // LetAssignment = "let", Identifier, "=", Expr => ActionFn(72);
+        let __sym3 = __pop_Variant3(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action22::<>(input, __sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (3, __symbol, 22)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action72::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (4, __symbol, 33)
     }
-    pub(crate) fn __reduce49<
+    pub(crate) fn __reduce50<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // True = "true" => ActionFn(26);
+        // NegatedUnary = "-", NegatedUnary => ActionFn(48);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action26::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
-        (1, __symbol, 23)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action48::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 34)
     }
     pub(crate) fn __reduce51<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // NegatedUnary = NegativeInt => ActionFn(49);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action49::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 34)
+    }
+    pub(crate) fn __reduce52<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // NegatedUnary = "~", Unary => ActionFn(50);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action50::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 34)
+    }
+    pub(crate) fn __reduce53<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // NegatedUnary = PowerBase, "**", Unary => ActionFn(51);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action51::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 34)
+    }
+    pub(crate) fn __reduce54<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // NegatedUnary = Term => ActionFn(52);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action52::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 34)
+    }
+    pub(crate) fn __reduce55<
+        'input,
+    >(
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // __ExprOrStmt = ExprOrStmt => ActionFn(0);
+        // NegativeInt = "int" => ActionFn(67);
         let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action0::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 25)
+        let __nt = super::__action67::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant16(__nt), __end);
+        (1, __symbol, 35)
     }
-}
-pub use self::__parse__Expr::ExprParser;
-
-#[cfg_attr(rustfmt, rustfmt_skip)]
-mod __parse__ExprOrStmt {
-    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
-
-    use std::str::FromStr;
-    use ast::{Expr, Operator, Value, Identifier};
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    use super::__intern_token::Token;
-    #[allow(dead_code)]
-    pub enum __Symbol<'input>
-     {
-        Variant0(&'input str),
-        Variant1(Box<Expr>),
-        Variant2(Operator),
-        Variant3(Expr),
-        Variant4(::std::vec::Vec<Expr>),
-        Variant5(bool),
-        Variant6(f64),
-        Variant7(Identifier),
-        Variant8(::std::vec::Vec<Identifier>),
-        Variant9(i32),
-    }
-    const __ACTION: &'static [i8] = &[
-        // State 0
-        16, 0, 0, 0, 0, 0, 0, 0, 0, 17, 18, 19, 20, 21, 22, 23, 24,
-        // State 1
-        0, 0, 0, 0, 0, 0, 0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 2
-        0, 0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 3
-        0, 0, 0, 0, 26, 27, 0, -8, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 5
-        0, -4, 0, 29, -4, -4, 30, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 6
-        0, -46, 0, -46, -46, -46, -46, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 7
-        0, -43, 0, -43, -43, -43, -43, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 8
-        31, 0, 0, -28, -28, -28, -28, -28, 32, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 9
-        0, -47, 0, -47, -47, -47, -47, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 10
-        0, -44, 0, -44, -44, -44, -44, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 11
-        0, 0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 12
-        0, -5, 0, 0, -5, -5, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 13
-        0, -18, 0, -18, -18, -18, -18, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 14
-        0, -45, 0, -45, -45, -45, -45, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 15
-        16, 0, 36, 0, 0, 0, 0, 0, 0, 0, 18, 0, 20, 21, 22, 23, 24,
-        // State 16
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0,
-        // State 17
-        0, -21, 0, -21, -21, -21, -21, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 18
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0,
-        // State 19
-        0, -49, 0, -49, -49, -49, -49, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 20
-        0, -36, 0, 0, -36, -36, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 21
-        0, -34, 0, -34, -34, -34, -34, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 22
-        -23, -23, -23, -23, -23, -23, -23, -23, -23, 0, 0, 0, 0, 0, 0, -23, 0,
-        // State 23
-        0, -22, 0, -22, -22, -22, -22, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 24
-        16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 20, 0, 22, 23, 24,
-        // State 25
-        -6, 0, 0, 0, 0, 0, 0, 0, 0, 0, -6, 0, -6, 0, -6, -6, -6,
-        // State 26
-        -7, 0, 0, 0, 0, 0, 0, 0, 0, 0, -7, 0, -7, 0, -7, -7, -7,
-        // State 27
-        16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 20, 0, 22, 23, 24,
-        // State 28
-        -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, -19, 0, -19, -19, -19,
-        // State 29
-        -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, -20, 0, -20, -20, -20,
-        // State 30
-        0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0,
-        // State 31
-        16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 20, 21, 22, 23, 24,
-        // State 32
-        0, 47, 0, 0, 26, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 33
-        31, -28, -26, -28, -28, -28, -28, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0,
-        // State 34
-        0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0,
-        // State 35
-        16, -39, 0, -39, -39, -39, -39, -39, 0, 17, 18, 19, 20, 21, 22, 23, 24,
-        // State 36
-        0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 37
-        0, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 38
-        0, -3, 0, 29, -3, -3, 30, -3, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 39
-        31, -28, 0, -28, -28, -28, -28, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 40
-        0, -17, 0, -17, -17, -17, -17, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 41
-        0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0,
-        // State 42
-        0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0,
-        // State 43
-        0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0,
-        // State 44
-        0, -37, 0, -37, -37, -37, -37, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 45
-        0, 0, 0, 0, 26, 27, 0, -1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 46
-        0, -48, 0, -48, -48, -48, -48, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 47
-        0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0,
-        // State 48
-        16, -40, 0, -40, -40, -40, -40, -40, 0, 17, 18, 19, 20, 21, 22, 23, 24,
-        // State 49
-        0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 50
-        -15, -15, 0, -15, -15, -15, -15, -15, 0, -15, -15, -15, -15, -15, -15, -15, -15,
-        // State 51
-        16, -41, 0, -41, -41, -41, -41, -41, 0, 17, 18, 19, 20, 21, 22, 23, 24,
-        // State 52
-        16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 20, 21, 22, 23, 24,
-        // State 53
-        16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 20, 21, 22, 23, 24,
-        // State 54
-        0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0,
-        // State 55
-        0, -38, 0, -38, -38, -38, -38, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 56
-        16, -42, 0, -42, -42, -42, -42, -42, 0, 17, 18, 19, 20, 21, 22, 23, 24,
-        // State 57
-        -12, -12, 0, -12, -12, -12, -12, -12, 0, -12, -12, -12, -12, -12, -12, -12, -12,
-        // State 58
-        -16, -16, 0, -16, -16, -16, -16, -16, 0, -16, -16, -16, -16, -16, -16, -16, -16,
-        // State 59
-        0, 0, 0, 0, 26, 27, 0, -2, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 60
-        0, 0, 0, 0, 26, 27, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-    const __EOF_ACTION: &'static [i8] = &[
-        // State 0
-        0,
-        // State 1
-        -11,
-        // State 2
-        -10,
-        // State 3
-        -8,
-        // State 4
-        -51,
-        // State 5
-        -4,
-        // State 6
-        -46,
-        // State 7
-        -43,
-        // State 8
-        -28,
-        // State 9
-        -47,
-        // State 10
-        -44,
-        // State 11
-        -9,
-        // State 12
-        -5,
-        // State 13
-        -18,
-        // State 14
-        -45,
-        // State 15
-        0,
-        // State 16
-        0,
-        // State 17
-        -21,
-        // State 18
-        0,
-        // State 19
-        -49,
-        // State 20
-        -36,
-        // State 21
-        -34,
-        // State 22
-        -23,
-        // State 23
-        -22,
-        // State 24
-        0,
-        // State 25
-        0,
-        // State 26
-        0,
-        // State 27
-        0,
-        // State 28
-        0,
-        // State 29
-        0,
-        // State 30
-        0,
-        // State 31
-        0,
-        // State 32
-        0,
-        // State 33
-        0,
-        // State 34
-        0,
-        // State 35
-        -39,
-        // State 36
-        0,
-        // State 37
-        0,
-        // State 38
-        -3,
-        // State 39
-        -28,
-        // State 40
-        -17,
-        // State 41
-        0,
-        // State 42
-        0,
-        // State 43
-        0,
-        // State 44
-        -37,
-        // State 45
-        -1,
-        // State 46
-        -48,
-        // State 47
-        0,
-        // State 48
-        -40,
-        // State 49
-        0,
-        // State 50
-        -15,
-        // State 51
-        -41,
-        // State 52
-        0,
-        // State 53
-        0,
-        // State 54
-        0,
-        // State 55
-        -38,
-        // State 56
-        -42,
-        // State 57
-        -12,
-        // State 58
-        -16,
-        // State 59
-        -2,
-        // State 60
-        -35,
-    ];
-    const __GOTO: &'static [i8] = &[
-        // State 0
-        2, 3, 4, 0, 5, 0, 0, 0, 6, 0, 7, 8, 9, 0, 0, 10, 0, 0, 0, 11, 12, 13, 14, 15, 0, 0,
-        // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 2
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 3
-        0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 5
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 7
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 9
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 10
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 11
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 12
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 13
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 14
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 15
-        0, 0, 33, 0, 0, 0, 0, 0, 6, 0, 7, 8, 34, 0, 35, 10, 0, 0, 0, 11, 0, 13, 14, 15, 0, 0,
-        // State 16
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 17
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 18
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 19
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 20
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 21
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 22
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 23
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 24
-        0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 7, 8, 40, 0, 0, 10, 0, 0, 0, 11, 0, 0, 14, 15, 0, 0,
-        // State 25
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 26
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 27
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 8, 40, 0, 0, 10, 0, 0, 0, 11, 0, 0, 41, 15, 0, 0,
-        // State 28
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 29
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 30
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 43, 0, 44, 0, 0, 0, 0, 0, 0, 0,
-        // State 31
-        0, 0, 46, 0, 0, 0, 0, 0, 6, 0, 7, 8, 40, 0, 0, 10, 0, 0, 0, 11, 0, 13, 14, 15, 0, 0,
-        // State 32
-        0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 33
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 35
-        2, 3, 4, 0, 50, 51, 0, 52, 6, 0, 7, 8, 9, 0, 0, 10, 0, 0, 0, 11, 12, 13, 14, 15, 0, 0,
-        // State 36
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 37
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 38
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 39
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 40
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 41
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 42
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 43
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 44
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 45
-        0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 46
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 47
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 48
-        2, 3, 4, 0, 50, 51, 0, 57, 6, 0, 7, 8, 9, 0, 0, 10, 0, 0, 0, 11, 12, 13, 14, 15, 0, 0,
-        // State 49
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 50
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 51
-        2, 3, 4, 0, 50, 59, 0, 0, 6, 0, 7, 8, 9, 0, 0, 10, 0, 0, 0, 11, 12, 13, 14, 15, 0, 0,
-        // State 52
-        0, 0, 60, 0, 0, 0, 0, 0, 6, 0, 7, 8, 40, 0, 0, 10, 0, 0, 0, 11, 0, 13, 14, 15, 0, 0,
-        // State 53
-        0, 0, 61, 0, 0, 0, 0, 0, 6, 0, 7, 8, 40, 0, 0, 10, 0, 0, 0, 11, 0, 13, 14, 15, 0, 0,
-        // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 55
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 56
-        2, 3, 4, 0, 50, 59, 0, 0, 6, 0, 7, 8, 9, 0, 0, 10, 0, 0, 0, 11, 12, 13, 14, 15, 0, 0,
-        // State 57
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 58
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 59
-        0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 60
-        0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
-        const __TERMINAL: &'static [&'static str] = &[
-            r###""(""###,
-            r###"")""###,
-            r###"") => ""###,
-            r###""*""###,
-            r###""+""###,
-            r###""-""###,
-            r###""/""###,
-            r###"";""###,
-            r###""=""###,
-            r###""const""###,
-            r###""false""###,
-            r###""let""###,
-            r###""true""###,
-            r###"r#"\"[^\"]+\""#"###,
-            r###"r#"[0-9]+"#"###,
-            r###"r#"[A-z]([A-z|0-9|_])*"#"###,
-            r###"r#"\\d+\\.\\d+"#"###,
-        ];
-        __ACTION[(__state * 17)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
-            if state == 0 {
-                None
-            } else {
-                Some(terminal.to_string())
-            }
-        }).collect()
+    pub(crate) fn __reduce56<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Power = PowerBase, "**", Unary => ActionFn(53);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action53::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 36)
     }
-    pub struct ExprOrStmtParser {
-        builder: super::__intern_token::__MatcherBuilder,
-        _priv: (),
+    pub(crate) fn __reduce57<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Power = PowerBase => ActionFn(54);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action54::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 36)
     }
-
-    impl ExprOrStmtParser {
-        pub fn new() -> ExprOrStmtParser {
-            let __builder = super::__intern_token::__MatcherBuilder::new();
-            ExprOrStmtParser {
-                builder: __builder,
-                _priv: (),
-            }
-        }
-
-        #[allow(dead_code)]
-        pub fn parse<
-            'input,
-        >(
-            &self,
-            input: &'input str,
-        ) -> Result<Box<Expr>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
-        {
-            let mut __tokens = self.builder.matcher(input);
-            let mut __states = vec![0_i8];
-            let mut __symbols = vec![];
-            let mut __integer;
-            let mut __lookahead;
-            let __last_location = &mut Default::default();
-            '__shift: loop {
-                __lookahead = match __tokens.next() {
-                    Some(Ok(v)) => v,
-                    None => break '__shift,
-                    Some(Err(e)) => return Err(e),
-                };
-                *__last_location = __lookahead.2.clone();
-                __integer = match __lookahead.1 {
-                    Token(4, _) if true => 0,
-                    Token(5, _) if true => 1,
-                    Token(6, _) if true => 2,
-                    Token(7, _) if true => 3,
-                    Token(8, _) if true => 4,
-                    Token(9, _) if true => 5,
-                    Token(10, _) if true => 6,
-                    Token(11, _) if true => 7,
-                    Token(12, _) if true => 8,
-                    Token(13, _) if true => 9,
-                    Token(14, _) if true => 10,
-                    Token(15, _) if true => 11,
-                    Token(16, _) if true => 12,
-                    Token(0, _) if true => 13,
-                    Token(1, _) if true => 14,
-                    Token(2, _) if true => 15,
-                    Token(3, _) if true => 16,
-                    _ => {
-                        let __state = *__states.last().unwrap() as usize;
-                        let __error = __lalrpop_util::ParseError::UnrecognizedToken {
-                            token: Some(__lookahead),
-                            expected: __expected_tokens(__state),
-                        };
-                        return Err(__error);
-                    }
-                };
-                '__inner: loop {
-                    let __state = *__states.last().unwrap() as usize;
-                    let __action = __ACTION[__state * 17 + __integer];
-                    if __action > 0 {
-                        let __symbol = match __integer {
-                            0 => match __lookahead.1 {
-                                Token(4, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            1 => match __lookahead.1 {
-                                Token(5, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            2 => match __lookahead.1 {
-                                Token(6, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            3 => match __lookahead.1 {
-                                Token(7, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            4 => match __lookahead.1 {
-                                Token(8, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            5 => match __lookahead.1 {
-                                Token(9, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            6 => match __lookahead.1 {
-                                Token(10, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            7 => match __lookahead.1 {
-                                Token(11, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            8 => match __lookahead.1 {
-                                Token(12, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            9 => match __lookahead.1 {
-                                Token(13, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            10 => match __lookahead.1 {
-                                Token(14, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            11 => match __lookahead.1 {
-                                Token(15, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            12 => match __lookahead.1 {
-                                Token(16, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            13 => match __lookahead.1 {
-                                Token(0, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            14 => match __lookahead.1 {
-                                Token(1, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            15 => match __lookahead.1 {
-                                Token(2, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            16 => match __lookahead.1 {
-                                Token(3, __tok0) => __Symbol::Variant0((__tok0)),
-                                _ => unreachable!(),
-                            },
-                            _ => unreachable!(),
-                        };
-                        __states.push(__action - 1);
-                        __symbols.push((__lookahead.0, __symbol, __lookahead.2));
-                        continue '__shift;
-                    } else if __action < 0 {
-                        if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
-                            if r.is_err() {
-                                return r;
-                            }
-                            return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead });
-                        }
-                    } else {
-                        let mut __err_lookahead = Some(__lookahead);
-                        let mut __err_integer: Option<usize> = Some(__integer);
-                        let __state = *__states.last().unwrap() as usize;
-                        let __error = __lalrpop_util::ParseError::UnrecognizedToken {
-                            token: __err_lookahead,
-                            expected: __expected_tokens(__state),
-                        };
-                        return Err(__error)
-                    }
-                }
-            }
-            loop {
-                let __state = *__states.last().unwrap() as usize;
-                let __action = __EOF_ACTION[__state];
-                if __action < 0 {
-                    if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
-                        return r;
-                    }
-                } else {
-                    let mut __err_lookahead = None;
-                    let mut __err_integer: Option<usize> = None;
-                    let __state = *__states.last().unwrap() as usize;
-                    let __error = __lalrpop_util::ParseError::UnrecognizedToken {
-                        token: __err_lookahead,
-                        expected: __expected_tokens(__state),
-                    };
-                    return Err(__error)
-                }
-            }
-        }
-    }
-    pub(crate) fn __reduce<
+    pub(crate) fn __reduce58<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
-    ) -> Option<Result<Box<Expr>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        let (__pop_states, __symbol, __nonterminal) = match -__action {
-            1 => {
-                __reduce1(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            2 => {
-                __reduce2(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            3 => {
-                __reduce3(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            4 => {
-                __reduce4(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            5 => {
-                __reduce5(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            6 => {
-                __reduce6(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            7 => {
-                __reduce7(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            8 => {
-                __reduce8(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            9 => {
-                __reduce9(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            10 => {
-                __reduce10(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            11 => {
-                __reduce11(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            12 => {
-                __reduce12(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            13 => {
-                __reduce13(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            14 => {
-                __reduce14(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            15 => {
-                __reduce15(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            16 => {
-                __reduce16(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            17 => {
-                __reduce17(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            18 => {
-                __reduce18(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            19 => {
-                __reduce19(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            20 => {
-                __reduce20(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            21 => {
-                __reduce21(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            22 => {
-                __reduce22(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            23 => {
-                __reduce23(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            24 => {
-                __reduce24(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            25 => {
-                __reduce25(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            26 => {
-                __reduce26(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            27 => {
-                __reduce27(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            28 => {
-                __reduce28(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            29 => {
-                __reduce29(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            30 => {
-                __reduce30(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            31 => {
-                __reduce31(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            32 => {
-                __reduce32(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            33 => {
-                __reduce33(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            34 => {
-                __reduce34(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            35 => {
-                __reduce35(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            36 => {
-                __reduce36(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            37 => {
-                __reduce37(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            38 => {
-                __reduce38(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            39 => {
-                __reduce39(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            40 => {
-                __reduce40(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            41 => {
-                __reduce41(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            42 => {
-                __reduce42(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            43 => {
-                __reduce43(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            44 => {
-                __reduce44(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            45 => {
-                __reduce45(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            46 => {
-                __reduce46(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            47 => {
-                __reduce47(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            48 => {
-                __reduce48(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            49 => {
-                __reduce49(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            50 => {
-                __reduce50(input, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            51 => {
-                // __ExprOrStmt = ExprOrStmt => ActionFn(0);
-                let __sym0 = __pop_Variant1(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action0::<>(input, __sym0);
-                return Some(Ok(__nt));
-            }
-            _ => panic!("invalid action code {}", __action)
-        };
-        let __states_len = __states.len();
-        __states.truncate(__states_len - __pop_states);
-        __symbols.push(__symbol);
-        let __state = *__states.last().unwrap() as usize;
-        let __next_state = __GOTO[__state * 26 + __nonterminal] - 1;
-        __states.push(__next_state);
-        None
+        // PowerBase = Int => ActionFn(55);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action55::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 37)
     }
-    fn __pop_Variant1<
-      'input,
+    pub(crate) fn __reduce59<
+        'input,
     >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Expr>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant1(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // PowerBase = Term => ActionFn(56);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action56::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 37)
     }
-    fn __pop_Variant3<
-      'input,
+    pub(crate) fn __reduce60<
+        'input,
     >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Expr, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant3(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Program = ExprOrStmt => ActionFn(113);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action113::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 38)
     }
-    fn __pop_Variant7<
-      'input,
+    pub(crate) fn __reduce61<
+        'input,
     >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Identifier, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant7(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Program =  => ActionFn(114);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action114::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (0, __symbol, 38)
     }
-    fn __pop_Variant2<
-      'input,
+    pub(crate) fn __reduce62<
+        'input,
     >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Operator, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant2(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Program = (<ExprOrStmt> ";")+, ExprOrStmt => ActionFn(115);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action115::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (2, __symbol, 38)
     }
-    fn __pop_Variant5<
-      'input,
+    pub(crate) fn __reduce63<
+        'input,
     >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, bool, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant5(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Variant6<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, f64, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant6(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Variant9<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, i32, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant9(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Variant4<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<Expr>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant4(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Variant8<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, ::std::vec::Vec<Identifier>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant8(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Variant0<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant0(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    pub(crate) fn __reduce1<
-        'input,
-    >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Assignment = Identifier, "=", Expr => ActionFn(31);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Program = (<ExprOrStmt> ";")+ => ActionFn(116);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action31::<>(input, __sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (3, __symbol, 0)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action116::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 38)
     }
-    pub(crate) fn __reduce2<
+    pub(crate) fn __reduce64<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ConstAssignment = "const", Identifier, "=", Expr => ActionFn(30);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Range = BitOr, "..", BitOr => ActionFn(24);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action30::<>(input, __sym0, __sym1, __sym2, __sym3);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (4, __symbol, 1)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action24::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 39)
     }
-    pub(crate) fn __reduce3<
+    pub(crate) fn __reduce65<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Expr = Expr, ExprOp, Factor => ActionFn(6);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Range = BitOr, "..=", BitOr => ActionFn(25);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action6::<>(input, __sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (3, __symbol, 2)
+        let __nt = super::__action25::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 39)
     }
-    pub(crate) fn __reduce4<
+    pub(crate) fn __reduce66<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Expr = Factor => ActionFn(7);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Range = BitOr => ActionFn(26);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action7::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 2)
+        let __nt = super::__action26::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 39)
     }
-    pub(crate) fn __reduce5<
+    pub(crate) fn __reduce67<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Expr = String => ActionFn(8);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Shift = Tier<ShiftOp, Sum> => ActionFn(31);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action8::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 2)
+        let __nt = super::__action31::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 40)
     }
-    pub(crate) fn __reduce6<
+    pub(crate) fn __reduce68<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOp = "+" => ActionFn(9);
+        // ShiftOp = "<<" => ActionFn(37);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action9::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
-        (1, __symbol, 3)
+        let __nt = super::__action37::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 41)
     }
-    pub(crate) fn __reduce7<
+    pub(crate) fn __reduce69<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOp = "-" => ActionFn(10);
+        // ShiftOp = ">>" => ActionFn(38);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action10::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
-        (1, __symbol, 3)
+        let __nt = super::__action38::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 41)
     }
-    pub(crate) fn __reduce8<
+    pub(crate) fn __reduce70<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmt = Expr => ActionFn(2);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Stmt = Expr => ActionFn(5);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action2::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 4)
+        let __nt = super::__action5::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 42)
     }
-    pub(crate) fn __reduce9<
+    pub(crate) fn __reduce71<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmt = LetAssignment => ActionFn(3);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Stmt = LetAssignment => ActionFn(6);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action3::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 4)
+        let __nt = super::__action6::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 42)
     }
-    pub(crate) fn __reduce10<
+    pub(crate) fn __reduce72<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmt = ConstAssignment => ActionFn(4);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Stmt = ConstAssignment => ActionFn(7);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action4::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 4)
+        let __nt = super::__action7::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 42)
     }
-    pub(crate) fn __reduce11<
+    pub(crate) fn __reduce73<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmt = Assignment => ActionFn(5);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Stmt = Assignment => ActionFn(8);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action5::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 4)
+        let __nt = super::__action8::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 42)
     }
-    pub(crate) fn __reduce12<
+    pub(crate) fn __reduce74<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmtSep = ExprOrStmt, ";" => ActionFn(23);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Stmt = For => ActionFn(9);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action23::<>(input, __sym0, __sym1);
+        let __end = __sym0.2.clone();
+        let __nt = super::__action9::<>(__sym0);
         let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (2, __symbol, 5)
+        (1, __symbol, 42)
     }
-    pub(crate) fn __reduce13<
+    pub(crate) fn __reduce75<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmtSep* =  => ActionFn(35);
-        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-        let __nt = super::__action35::<>(input, &__start, &__end);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (0, __symbol, 6)
+        // Stmt = "break" => ActionFn(10);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action10::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 42)
     }
-    pub(crate) fn __reduce14<
+    pub(crate) fn __reduce76<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmtSep* = ExprOrStmtSep+ => ActionFn(36);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Stmt = "continue" => ActionFn(11);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action36::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (1, __symbol, 6)
+        let __nt = super::__action11::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 42)
     }
-    pub(crate) fn __reduce15<
+    pub(crate) fn __reduce77<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmtSep+ = ExprOrStmtSep => ActionFn(45);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Stmt = "yield", Expr => ActionFn(12);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action45::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (1, __symbol, 7)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action12::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 42)
     }
-    pub(crate) fn __reduce16<
+    pub(crate) fn __reduce78<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // ExprOrStmtSep+ = ExprOrStmtSep+, ExprOrStmtSep => ActionFn(46);
+        // Stmt = "throw", Expr => ActionFn(13);
         let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action46::<>(input, __sym0, __sym1);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (2, __symbol, 7)
+        let __nt = super::__action13::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 42)
     }
-    pub(crate) fn __reduce17<
+    pub(crate) fn __reduce79<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Factor = Factor, FactorOp, Term => ActionFn(11);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Stmt = Try => ActionFn(14);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action11::<>(input, __sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (3, __symbol, 8)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action14::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 42)
     }
-    pub(crate) fn __reduce18<
+    pub(crate) fn __reduce80<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Factor = Term => ActionFn(12);
+        // String = "string" => ActionFn(71);
         let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action12::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 8)
+        let __nt = super::__action71::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 43)
     }
-    pub(crate) fn __reduce19<
+    pub(crate) fn __reduce81<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // FactorOp = "*" => ActionFn(13);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Sum = Tier<ExprOp, Factor> => ActionFn(32);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action13::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
-        (1, __symbol, 9)
+        let __nt = super::__action32::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 44)
     }
-    pub(crate) fn __reduce20<
+    pub(crate) fn __reduce82<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // FactorOp = "/" => ActionFn(14);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Term = Identifier, "call(", ")" => ActionFn(111);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action14::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
-        (1, __symbol, 9)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action111::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 45)
     }
-    pub(crate) fn __reduce21<
+    pub(crate) fn __reduce83<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // False = "false" => ActionFn(27);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Term = Identifier, "call(", Argument+, ")" => ActionFn(112);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action27::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
-        (1, __symbol, 10)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action112::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (4, __symbol, 45)
     }
-    pub(crate) fn __reduce22<
+    pub(crate) fn __reduce84<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Float = r#"\\d+\\.\\d+"# => ActionFn(25);
+        // Term = "{", Program, "}" => ActionFn(58);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action25::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
-        (1, __symbol, 11)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action58::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 45)
     }
-    pub(crate) fn __reduce23<
+    pub(crate) fn __reduce85<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Identifier = r#"[A-z]([A-z|0-9|_])*"# => ActionFn(34);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Term = Float => ActionFn(59);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action34::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
-        (1, __symbol, 12)
+        let __nt = super::__action59::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 45)
     }
-    pub(crate) fn __reduce24<
+    pub(crate) fn __reduce86<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Identifier* =  => ActionFn(37);
-        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-        let __nt = super::__action37::<>(input, &__start, &__end);
-        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
-        (0, __symbol, 13)
+        // Term = True => ActionFn(60);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action60::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 45)
     }
-    pub(crate) fn __reduce25<
+    pub(crate) fn __reduce87<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Identifier* = Identifier+ => ActionFn(38);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Term = False => ActionFn(61);
+        let __sym0 = __pop_Variant13(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action38::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
-        (1, __symbol, 13)
+        let __nt = super::__action61::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 45)
     }
-    pub(crate) fn __reduce26<
+    pub(crate) fn __reduce88<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Identifier+ = Identifier => ActionFn(43);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Term = "nil" => ActionFn(62);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action43::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
-        (1, __symbol, 14)
+        let __nt = super::__action62::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 45)
     }
-    pub(crate) fn __reduce27<
+    pub(crate) fn __reduce89<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Identifier+ = Identifier+, Identifier => ActionFn(44);
-        let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Term = String => ActionFn(63);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action44::<>(input, __sym0, __sym1);
-        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
-        (2, __symbol, 14)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action63::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 45)
     }
-    pub(crate) fn __reduce28<
+    pub(crate) fn __reduce90<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // IdentifierExpr = Identifier => ActionFn(32);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Term = IdentifierExpr => ActionFn(64);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action32::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 15)
+        let __nt = super::__action64::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 45)
     }
-    pub(crate) fn __reduce29<
+    pub(crate) fn __reduce91<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // IdentifierExprUnboxed = Identifier => ActionFn(33);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Term = "(", Expr, ")" => ActionFn(65);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action33::<>(input, __sym0);
+        let __end = __sym2.2.clone();
+        let __nt = super::__action65::<>(__sym0, __sym1, __sym2);
         let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (1, __symbol, 16)
+        (3, __symbol, 45)
     }
-    pub(crate) fn __reduce30<
+    pub(crate) fn __reduce92<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // IdentifierExprUnboxed* =  => ActionFn(39);
-        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-        let __nt = super::__action39::<>(input, &__start, &__end);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (0, __symbol, 17)
+        // Tier<BitAndOp, Shift> = Tier<BitAndOp, Shift>, BitAndOp, Shift => ActionFn(86);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action86::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 46)
     }
-    pub(crate) fn __reduce31<
+    pub(crate) fn __reduce93<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // IdentifierExprUnboxed* = IdentifierExprUnboxed+ => ActionFn(40);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Tier<BitAndOp, Shift> = Shift => ActionFn(87);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action40::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (1, __symbol, 17)
+        let __nt = super::__action87::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 46)
     }
-    pub(crate) fn __reduce32<
+    pub(crate) fn __reduce94<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // IdentifierExprUnboxed+ = IdentifierExprUnboxed => ActionFn(41);
+        // Tier<BitOrOp, BitXor> = Tier<BitOrOp, BitXor>, BitOrOp, BitXor => ActionFn(90);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action41::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (1, __symbol, 18)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action90::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 47)
     }
-    pub(crate) fn __reduce33<
+    pub(crate) fn __reduce95<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // IdentifierExprUnboxed+ = IdentifierExprUnboxed+, IdentifierExprUnboxed => ActionFn(42);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action42::<>(input, __sym0, __sym1);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (2, __symbol, 18)
-    }
-    pub(crate) fn __reduce34<
-        'input,
-    >(
-        input: &'input str,
-        __action: i8,
-        __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: ::std::marker::PhantomData<()>,
-    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
-    {
-        // Int = r#"[0-9]+"# => ActionFn(24);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Tier<BitOrOp, BitXor> = BitXor => ActionFn(91);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action24::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant9(__nt), __end);
-        (1, __symbol, 19)
+        let __nt = super::__action91::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 47)
     }
-    pub(crate) fn __reduce35<
+    pub(crate) fn __reduce96<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // LetAssignment = "let", Identifier, "=", Expr => ActionFn(29);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
+        // Tier<BitXorOp, BitAnd> = Tier<BitXorOp, BitAnd>, BitXorOp, BitAnd => ActionFn(88);
+        let __sym2 = __pop_Variant3(__symbols);
         let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action29::<>(input, __sym0, __sym1, __sym2, __sym3);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (4, __symbol, 20)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action88::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 48)
     }
-    pub(crate) fn __reduce36<
+    pub(crate) fn __reduce97<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // String = r#"\"[^\"]+\""# => ActionFn(28);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Tier<BitXorOp, BitAnd> = BitAnd => ActionFn(89);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action28::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 21)
+        let __nt = super::__action89::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 48)
     }
-    pub(crate) fn __reduce37<
+    pub(crate) fn __reduce98<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = Identifier, "(", ")" => ActionFn(53);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Tier<ExprOp, Factor> = Tier<ExprOp, Factor>, ExprOp, Factor => ActionFn(82);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action53::<>(input, __sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (3, __symbol, 22)
+        let __nt = super::__action82::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 49)
     }
-    pub(crate) fn __reduce38<
+    pub(crate) fn __reduce99<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = Identifier, "(", IdentifierExprUnboxed+, ")" => ActionFn(54);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant4(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Tier<ExprOp, Factor> = Factor => ActionFn(83);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action54::<>(input, __sym0, __sym1, __sym2, __sym3);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (4, __symbol, 22)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action83::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 49)
     }
-    pub(crate) fn __reduce39<
+    pub(crate) fn __reduce100<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = "(", ") => " => ActionFn(49);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Tier<FactorOp, Unary> = Tier<FactorOp, Unary>, FactorOp, Unary => ActionFn(80);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action49::<>(input, __sym0, __sym1);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (2, __symbol, 22)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action80::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 50)
     }
-    pub(crate) fn __reduce40<
+    pub(crate) fn __reduce101<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = "(", Identifier+, ") => " => ActionFn(50);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant8(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Tier<FactorOp, Unary> = Unary => ActionFn(81);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action50::<>(input, __sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (3, __symbol, 22)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action81::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 50)
     }
-    pub(crate) fn __reduce41<
+    pub(crate) fn __reduce102<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = "(", ") => ", ExprOrStmtSep+ => ActionFn(51);
-        let __sym2 = __pop_Variant4(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Tier<ShiftOp, Sum> = Tier<ShiftOp, Sum>, ShiftOp, Sum => ActionFn(84);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action51::<>(input, __sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (3, __symbol, 22)
+        let __nt = super::__action84::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 51)
     }
-    pub(crate) fn __reduce42<
+    pub(crate) fn __reduce103<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = "(", Identifier+, ") => ", ExprOrStmtSep+ => ActionFn(52);
-        let __sym3 = __pop_Variant4(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant8(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Tier<ShiftOp, Sum> = Sum => ActionFn(85);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action52::<>(input, __sym0, __sym1, __sym2, __sym3);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (4, __symbol, 22)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action85::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 51)
     }
-    pub(crate) fn __reduce43<
+    pub(crate) fn __reduce104<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = Float => ActionFn(17);
-        let __sym0 = __pop_Variant6(__symbols);
+        // True = "true" => ActionFn(69);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action17::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 22)
+        let __nt = super::__action69::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant13(__nt), __end);
+        (1, __symbol, 52)
     }
-    pub(crate) fn __reduce44<
+    pub(crate) fn __reduce105<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = Int => ActionFn(18);
-        let __sym0 = __pop_Variant9(__symbols);
+        // Try = "try", Body, Catch => ActionFn(16);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action18::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 22)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action16::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 53)
     }
-    pub(crate) fn __reduce45<
+    pub(crate) fn __reduce106<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = True => ActionFn(19);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Try = "try", Body, "finally", Body => ActionFn(17);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action19::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 22)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action17::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (4, __symbol, 53)
     }
-    pub(crate) fn __reduce46<
+    pub(crate) fn __reduce107<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = False => ActionFn(20);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Try = "try", Body, Catch, "finally", Body => ActionFn(18);
+        let __sym4 = __pop_Variant8(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action20::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 22)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action18::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (5, __symbol, 53)
     }
-    pub(crate) fn __reduce47<
+    pub(crate) fn __reduce108<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = IdentifierExpr => ActionFn(21);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Unary = "-", NegatedUnary => ActionFn(45);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action21::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 22)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action45::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 54)
     }
-    pub(crate) fn __reduce48<
+    pub(crate) fn __reduce109<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // Term = "(", Expr, ")" => ActionFn(22);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
+        // Unary = "~", Unary => ActionFn(46);
+        let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action22::<>(input, __sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (3, __symbol, 22)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action46::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 54)
     }
-    pub(crate) fn __reduce49<
+    pub(crate) fn __reduce110<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // True = "true" => ActionFn(26);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Unary = Power => ActionFn(47);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action26::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
-        (1, __symbol, 23)
+        let __nt = super::__action47::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 54)
     }
-    pub(crate) fn __reduce50<
+    pub(crate) fn __reduce112<
         'input,
     >(
-        input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<'input>,usize), usize)
     {
-        // __Expr = Expr => ActionFn(1);
-        let __sym0 = __pop_Variant1(__symbols);
+        // __Program = Program => ActionFn(0);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action1::<>(input, __sym0);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (1, __symbol, 24)
+        let __nt = super::__action0::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 56)
     }
 }
-pub use self::__parse__ExprOrStmt::ExprOrStmtParser;
-#[cfg_attr(rustfmt, rustfmt_skip)]
-mod __intern_token {
-    #![allow(unused_imports)]
-    use std::str::FromStr;
-    use ast::{Expr, Operator, Value, Identifier};
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    extern crate regex as __regex;
-    use std::fmt as __fmt;
-
-    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-    pub struct Token<'input>(pub usize, pub &'input str);
-    impl<'a> __fmt::Display for Token<'a> {
-        fn fmt(&self, formatter: &mut __fmt::Formatter) -> Result<(), __fmt::Error> {
-            __fmt::Display::fmt(self.1, formatter)
-        }
-    }
+pub use self::__parse__Expr::ExprParser;
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+mod __parse__Program {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use std::str::FromStr;
+    use ast::{Expr, Operator, UnaryOperator, Value, Identifier};
+    use lexer::{LexicalError, Tok};
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub enum __Symbol<'input>
+     {
+        Variant0(Tok<'input>),
+        Variant1(&'input str),
+        Variant2(f64),
+        Variant3(Box<Expr>),
+        Variant4(::std::vec::Vec<Box<Expr>>),
+        Variant5(Expr),
+        Variant6(::std::vec::Vec<Expr>),
+        Variant7(Operator),
+        Variant8(Vec<Expr>),
+        Variant9((Identifier, Vec<Expr>)),
+        Variant10(String),
+        Variant11(::std::vec::Vec<String>),
+        Variant12(::std::option::Option<Box<Expr>>),
+        Variant13(bool),
+        Variant14(Identifier),
+        Variant15(::std::vec::Vec<Identifier>),
+        Variant16(Value),
+    }
+    const __ACTION: &'static [i16] = &[
+        // State 0
+        0, 0, 39, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 42, 43, 44, 45, 0, 46, 47, 48, 0, 49, 50, 51, 52, 53, 54, 55, 56, 57, 0, 0, 58, 0,
+        // State 1
+        0, 0, 39, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 42, 43, 44, 45, 0, 46, 47, 48, 0, 49, 50, 51, 52, 53, 54, 55, 56, 57, 0, -63, 58, 0,
+        // State 2
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0,
+        // State 3
+        0, 0, 0, -97, 0, 0, 0, 0, 0, -97, -97, 0, -97, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, -97, -97, 0, 0,
+        // State 4
+        0, 0, 0, -66, 0, 0, 0, 0, 0, 60, 61, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, -66, 0, 0,
+        // State 5
+        0, 0, 0, -95, 0, 0, 0, 0, 0, -95, -95, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, -95, -95, 0, 0,
+        // State 6
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0,
+        // State 7
+        0, 0, -22, 0, 0, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, -22, -22, -22, -22, 0, -22, -22, -22, 0, -22, -22, -22, -22, -22, -22, -22, -22, -22, 0, 0, -22, 0,
+        // State 8
+        0, 0, 39, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 42, 43, 44, 45, 0, 46, 47, 48, 0, 49, 50, 51, 52, 53, 54, 55, 56, 57, 0, 0, 58, 0,
+        // State 9
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0,
+        // State 10
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0,
+        // State 11
+        0, -99, 0, -99, 0, 0, 0, -99, -99, -99, -99, 0, -99, -99, 0, -99, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, -99, -99, 0, 0,
+        // State 12
+        -87, -87, -87, -87, 0, -87, -87, -87, -87, -87, -87, -87, -87, -87, 0, -87, -87, 0, 0, 0, 0, 0, 0, -87, 0, -87, 0, -87, 0, -87, 0, -87, -87, 0, -87, 0, 0, -87, -87, -87, 0, -87,
+        // State 13
+        -85, -85, -85, -85, 0, -85, -85, -85, -85, -85, -85, -85, -85, -85, 0, -85, -85, 0, 0, 0, 0, 0, 0, -85, 0, -85, 0, -85, 0, -85, 0, -85, -85, 0, -85, 0, 0, -85, -85, -85, 0, -85,
+        // State 14
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0,
+        // State 15
+        0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, -25, 0, 0,
+        // State 16
+        -47, -47, 0, 0, 0, -47, -47, -47, -47, -47, -47, -47, -47, -47, 65, -47, -47, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, -47, 0, -47,
+        // State 17
+        -90, -90, -90, -90, 0, -90, -90, -90, -90, -90, -90, -90, -90, -90, 0, -90, -90, 0, 0, 0, 0, 0, 0, -90, 0, -90, 0, -90, 0, -90, 0, -90, -90, 0, -90, 0, 0, -90, -90, -90, 0, -90,
+        // State 18
+        -58, -58, -58, -58, 0, -58, -58, -58, -58, -58, -58, -58, -58, -58, 0, -58, -58, 0, 0, 0, 0, 0, 0, -58, 0, -58, 0, -58, 0, -58, 0, -58, -58, 0, -58, 0, 0, -58, -58, -58, 0, -58,
+        // State 19
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0,
+        // State 20
+        -110, -110, 0, -110, 0, -110, 0, -110, -110, -110, -110, -110, -110, -110, 0, -110, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, -110, -110, 0, -110,
+        // State 21
+        -57, -57, 0, -57, 0, -57, 67, -57, -57, -57, -57, -57, -57, -57, 0, -57, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, -57, -57, 0, -57,
+        // State 22
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 23
+        0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, -24, 0, 0,
+        // State 24
+        0, -93, 0, -93, 0, 0, 0, 0, 0, -93, -93, 0, -93, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, -93, -93, 0, 0,
+        // State 25
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0,
+        // State 26
+        -89, -89, -89, -89, 0, -89, -89, -89, -89, -89, -89, -89, -89, -89, 0, -89, -89, 0, 0, 0, 0, 0, 0, -89, 0, -89, 0, -89, 0, -89, 0, -89, -89, 0, -89, 0, 0, -89, -89, -89, 0, -89,
+        // State 27
+        0, -103, 0, -103, 0, 0, 0, 0, 0, -103, -103, 0, -103, -103, 0, -103, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, -103, -103, 0, 0,
+        // State 28
+        -59, -59, -59, -59, 0, -59, -59, -59, -59, -59, -59, -59, -59, -59, 0, -59, -59, 0, 0, 0, 0, 0, 0, -59, 0, -59, 0, -59, 0, -59, 0, -59, -59, 0, -59, 0, 0, -59, -59, -59, 0, -59,
+        // State 29
+        0, 69, 0, -12, 0, 0, 0, 0, 0, -12, -12, 0, -12, 0, 0, 0, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -12, -12, -12, 0, 0,
+        // State 30
+        0, 0, 0, -14, 0, 0, 0, 0, 0, -14, -14, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 71, -14, 0, 0,
+        // State 31
+        0, 0, 0, -16, 0, 0, 0, 0, 0, -16, -16, 0, -16, 0, 0, 0, 73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16, -16, -16, 0, 0,
+        // State 32
+        0, -81, 0, -81, 0, 0, 0, 75, 76, -81, -81, 0, -81, -81, 0, -81, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, -81, -81, 0, 0,
+        // State 33
+        78, -32, 0, -32, 0, 79, 0, -32, -32, -32, -32, 80, -32, -32, 0, -32, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, -32, -32, 0, 81,
+        // State 34
+        0, -67, 0, -67, 0, 0, 0, 0, 0, -67, -67, 0, -67, 83, 0, 84, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67, -67, -67, 0, 0,
+        // State 35
+        -86, -86, -86, -86, 0, -86, -86, -86, -86, -86, -86, -86, -86, -86, 0, -86, -86, 0, 0, 0, 0, 0, 0, -86, 0, -86, 0, -86, 0, -86, 0, -86, -86, 0, -86, 0, 0, -86, -86, -86, 0, -86,
+        // State 36
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0,
+        // State 37
+        -101, -101, 0, -101, 0, -101, 0, -101, -101, -101, -101, -101, -101, -101, 0, -101, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, -101, -101, 0, -101,
+        // State 38
+        0, 0, 39, 0, 88, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 39
+        0, 0, 94, 0, 0, 0, 0, 0, 95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 96, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 97, 0,
+        // State 40
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0,
+        // State 41
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 42
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0,
+        // State 43
+        0, 0, -21, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, -21, -21, -21, -21, 0, -21, -21, -21, 0, -21, -21, -21, -21, -21, -21, -21, -21, -21, 0, 0, -21, 0,
+        // State 44
+        -37, -37, -37, -37, 0, -37, -37, -37, -37, -37, -37, -37, -37, -37, 0, -37, -37, 0, 0, 0, 0, 0, 0, -37, 0, -37, 0, -37, 0, -37, 0, -37, -37, 0, -37, 0, 0, -37, -37, -37, 0, -37,
+        // State 45
+        -38, -38, -38, -38, 0, -38, -38, -38, -38, -38, -38, -38, -38, -38, 0, -38, -38, 0, 0, 0, 0, 0, 0, -38, 0, -38, 0, -38, 0, -38, 0, -38, -38, 0, -38, 0, 0, -38, -38, -38, 0, -38,
+        // State 46
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 47
+        -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, 0, -42, 0, 0, 0, 0, -42, 0, -42, 0, -42, -42, -42, 0, -42, -42, 0, -42, 0, 0, -42, -42, -42, 0, -42,
+        // State 48
+        -48, -48, -48, -48, 0, -48, -48, -48, -48, -48, -48, -48, -48, -48, 0, -48, -48, 0, 0, 0, 0, 0, 0, -48, 0, -48, 0, -48, 0, -48, 0, -48, -48, 0, -48, 0, 0, -48, -48, -48, 0, -48,
+        // State 49
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 50
+        -88, -88, -88, -88, 0, -88, -88, -88, -88, -88, -88, -88, -88, -88, 0, -88, -88, 0, 0, 0, 0, 0, 0, -88, 0, -88, 0, -88, 0, -88, 0, -88, -88, 0, -88, 0, 0, -88, -88, -88, 0, -88,
+        // State 51
+        -80, -80, -80, -80, 0, -80, -80, -80, -80, -80, -80, -80, -80, -80, 0, -80, -80, 0, 0, 0, 0, 0, 0, -80, 0, -80, 0, -80, 0, -80, 0, -80, -80, 0, -80, 0, 0, -80, -80, -80, 0, -80,
+        // State 52
+        0, 0, 39, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 53
+        -104, -104, -104, -104, 0, -104, -104, -104, -104, -104, -104, -104, -104, -104, 0, -104, -104, 0, 0, 0, 0, 0, 0, -104, 0, -104, 0, -104, 0, -104, 0, -104, -104, 0, -104, 0, 0, -104, -104, -104, 0, -104,
+        // State 54
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0,
+        // State 55
+        0, 0, 39, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 56
+        0, 0, 39, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 42, 43, 44, 45, 0, 46, 47, 48, 0, 49, 50, 51, 52, 53, 54, 55, 56, 57, 0, -61, 58, 0,
+        // State 57
+        0, 0, 94, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 58
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0,
+        // State 59
+        0, 0, 94, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 60
+        0, 0, 94, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 61
+        0, 0, -23, 0, 0, 0, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, -23, -23, -23, -23, 0, -23, -23, -23, 0, -23, -23, -23, -23, -23, -23, -23, -23, -23, 0, 0, -23, 0,
+        // State 62
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0,
+        // State 63
+        0, 0, -4, 0, 0, 0, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0, -4, 0, 0, -4, -4, -4, -4, 0, -4, -4, -4, 0, -4, -4, -4, -4, -4, -4, -4, -4, -4, 0, -4, -4, 0,
+        // State 64
+        0, 0, 39, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 65
+        0, 0, 94, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 0, 0,
+        // State 66
+        0, 0, 94, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 67
+        0, 0, 94, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 68
+        0, 0, -13, 0, 0, 0, 0, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -13, 0, -13, 0, -13, 0, -13, 0, -13, -13, 0, -13, 0, 0, -13, 0, 0, -13, 0,
+        // State 69
+        0, 0, 94, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 70
+        0, 0, -15, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, -15, 0, -15, 0, -15, 0, -15, -15, 0, -15, 0, 0, -15, 0, 0, -15, 0,
+        // State 71
+        0, 0, 94, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 72
+        0, 0, -17, 0, 0, 0, 0, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -17, 0, -17, 0, -17, 0, -17, 0, -17, -17, 0, -17, 0, 0, -17, 0, 0, -17, 0,
+        // State 73
+        0, 0, 94, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 74
+        0, 0, -26, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, -26, 0, -26, 0, -26, 0, -26, -26, 0, -26, 0, 0, -26, 0, 0, -26, 0,
+        // State 75
+        0, 0, -27, 0, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, -27, 0, -27, 0, -27, 0, -27, -27, 0, -27, 0, 0, -27, 0, 0, -27, 0,
+        // State 76
+        0, 0, 94, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 77
+        0, 0, -35, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, -35, 0, -35, 0, -35, 0, -35, -35, 0, -35, 0, 0, -35, 0, 0, -35, 0,
+        // State 78
+        0, 0, -33, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, -33, 0, -33, 0, -33, 0, -33, -33, 0, -33, 0, 0, -33, 0, 0, -33, 0,
+        // State 79
+        0, 0, -34, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, -34, 0, -34, 0, -34, 0, -34, -34, 0, -34, 0, 0, -34, 0, 0, -34, 0,
+        // State 80
+        0, 0, -36, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, -36, 0, -36, 0, -36, 0, -36, -36, 0, -36, 0, 0, -36, 0, 0, -36, 0,
+        // State 81
+        0, 0, 94, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 82
+        0, 0, -68, 0, 0, 0, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, -68, 0, -68, 0, -68, 0, -68, -68, 0, -68, 0, 0, -68, 0, 0, -68, 0,
+        // State 83
+        0, 0, -69, 0, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, -69, 0, -69, 0, -69, 0, -69, -69, 0, -69, 0, 0, -69, 0, 0, -69, 0,
+        // State 84
+        0, 0, 0, 122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 85
+        -47, -47, 0, -47, -45, -47, -47, -47, -47, -47, -47, -47, 0, -47, 0, -47, -47, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, -47,
+        // State 86
+        0, 0, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 87
+        0, 0, 39, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 88
+        -47, -47, -47, -47, 0, -47, -47, -47, -47, -47, -47, -47, -47, -47, 0, -47, -47, 0, 66, 0, 0, 0, 0, -47, 0, -47, 0, -47, 0, -47, 0, -47, -47, 0, -47, 0, 0, -47, -47, -47, 0, -47,
+        // State 89
+        -108, -108, 0, -108, 0, -108, 0, -108, -108, -108, -108, -108, -108, -108, 0, -108, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, -108, -108, 0, -108,
+        // State 90
+        -51, -51, 0, -51, 0, -51, 0, -51, -51, -51, -51, -51, -51, -51, 0, -51, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, -51, -51, 0, -51,
+        // State 91
+        0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 92
+        -54, -54, 0, -54, 0, -54, -59, -54, -54, -54, -54, -54, -54, -54, 0, -54, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, -54, -54, 0, -54,
+        // State 93
+        0, 0, 39, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 94
+        0, 0, 94, 0, 0, 0, 0, 0, 95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 96, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 97, 0,
+        // State 95
+        -55, -55, 0, -55, 0, -55, -48, -55, -55, -55, -55, -55, -55, -55, 0, -55, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, -55, -55, 0, -55,
+        // State 96
+        0, 0, 94, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 97
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 98
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 99
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 100
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0,
+        // State 101
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 0, 134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 102
+        0, 0, 39, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 42, 43, 44, 45, 0, 46, 47, 48, 0, 49, 50, 51, 52, 53, 54, 55, 56, 57, 0, -61, 58, 0,
+        // State 103
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0,
+        // State 104
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 0, 0,
+        // State 105
+        -109, -109, 0, -109, 0, -109, 0, -109, -109, -109, -109, -109, -109, -109, 0, -109, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, -109, -109, 0, -109,
+        // State 106
+        0, 0, -5, 0, 0, 0, 0, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0, -5, 0, 0, -5, -5, -5, -5, 0, -5, -5, -5, 0, -5, -5, -5, -5, -5, -5, -5, -5, -5, 0, -5, -5, 0,
+        // State 107
+        0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, -64, 0, 0,
+        // State 108
+        0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, -65, 0, 0,
+        // State 109
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -11, 0, 0,
+        // State 110
+        0, 0, -9, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, -9, 0, -9, 0, -9, 0, -9, -9, 0, -9, 0, 0, -9, 0, 0, 0, 0,
+        // State 111
+        0, 0, 94, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 0, 0,
+        // State 112
+        0, 0, -6, -6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -6, 0, -6, 0, -6, 0, -6, 0, -6, -6, 0, -6, 0, 0, -6, 0, 0, 0, 0,
+        // State 113
+        -82, -82, -82, -82, 0, -82, -82, -82, -82, -82, -82, -82, -82, -82, 0, -82, -82, 0, 0, 0, 0, 0, 0, -82, 0, -82, 0, -82, 0, -82, 0, -82, -82, 0, -82, 0, 0, -82, -82, -82, 0, -82,
+        // State 114
+        -56, -56, 0, -56, 0, -56, 0, -56, -56, -56, -56, -56, -56, -56, 0, -56, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, -56, -56, 0, -56,
+        // State 115
+        0, -92, 0, -92, 0, 0, 0, 0, 0, -92, -92, 0, -92, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, -92, -92, 0, 0,
+        // State 116
+        0, 0, 0, -94, 0, 0, 0, 0, 0, -94, -94, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, -94, -94, 0, 0,
+        // State 117
+        0, 0, 0, -96, 0, 0, 0, 0, 0, -96, -96, 0, -96, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, -96, -96, 0, 0,
+        // State 118
+        0, -98, 0, -98, 0, 0, 0, -98, -98, -98, -98, 0, -98, -98, 0, -98, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, -98, -98, 0, 0,
+        // State 119
+        -100, -100, 0, -100, 0, -100, 0, -100, -100, -100, -100, -100, -100, -100, 0, -100, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, -100, -100, 0, -100,
+        // State 120
+        0, -102, 0, -102, 0, 0, 0, 0, 0, -102, -102, 0, -102, -102, 0, -102, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, -102, -102, 0, 0,
+        // State 121
+        -91, -91, -91, -91, 0, -91, -91, -91, -91, -91, -91, -91, -91, -91, 0, -91, -91, 0, 0, 0, 0, 0, 0, -91, 0, -91, 0, -91, 0, -91, 0, -91, -91, 0, -91, 0, 0, -91, -91, -91, 0, -91,
+        // State 122
+        0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 123
+        0, 0, 39, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 124
+        0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, -40, 0, 0,
+        // State 125
+        0, 0, 94, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 126
+        -50, -50, 0, -50, 0, -50, 0, -50, -50, -50, -50, -50, -50, -50, 0, -50, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, -50, -50, 0, -50,
+        // State 127
+        -52, -52, 0, -52, 0, -52, 0, -52, -52, -52, -52, -52, -52, -52, 0, -52, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, -52, -52, 0, -52,
+        // State 128
+        0, 0, 39, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 129
+        0, 0, 39, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 130
+        0, 0, 39, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 46, 0, 48, 0, 49, 0, 51, 52, 0, 54, 0, 0, 57, 0, 0, 58, 0,
+        // State 131
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0,
+        // State 132
+        0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 133
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0,
+        // State 134
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0,
+        // State 135
+        -84, -84, -84, -84, 0, -84, -84, -84, -84, -84, -84, -84, -84, -84, 0, -84, -84, 0, 0, 0, 0, 0, 0, -84, 0, -84, 0, -84, 0, -84, 0, -84, -84, 0, -84, 0, 0, -84, -84, -84, 0, -84,
+        // State 136
+        0, 0, -10, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, -10, 0, -10, 0, -10, 0, -10, -10, 0, -10, 0, 0, -10, 0, 0, 0, 0,
+        // State 137
+        -83, -83, -83, -83, 0, -83, -83, -83, -83, -83, -83, -83, -83, -83, 0, -83, -83, 0, 0, 0, 0, 0, 0, -83, 0, -83, 0, -83, 0, -83, 0, -83, -83, 0, -83, 0, 0, -83, -83, -83, 0, -83,
+        // State 138
+        0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, -41, 0, 0,
+        // State 139
+        -53, -53, 0, -53, 0, -53, 0, -53, -53, -53, -53, -53, -53, -53, 0, -53, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, -53, -53, 0, -53,
+        // State 140
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0,
+        // State 141
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 148, 0, 0, 0, 0,
+        // State 142
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, 0,
+        // State 143
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0,
+        // State 144
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0,
+        // State 146
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, 0, 0, 0, 0, 0, -18, 0, 0, 0, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, 0,
+        // State 147
+        0, 0, 39, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 42, 43, 44, 45, 0, 46, 47, 48, 0, 49, 50, 51, 52, 53, 54, 55, 56, 57, 0, -61, 58, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0,
+        // State 149
+        0, 0, 0, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 150
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0,
+        // State 151
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0,
+        // State 153
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0,
+    ];
+    const __EOF_ACTION: &'static [i16] = &[
+        // State 0
+        -61,
+        // State 1
+        -63,
+        // State 2
+        -73,
+        // State 3
+        -97,
+        // State 4
+        -66,
+        // State 5
+        -95,
+        // State 6
+        -72,
+        // State 7
+        0,
+        // State 8
+        0,
+        // State 9
+        -70,
+        // State 10
+        -60,
+        // State 11
+        -99,
+        // State 12
+        -87,
+        // State 13
+        -85,
+        // State 14
+        -74,
+        // State 15
+        -25,
+        // State 16
+        -47,
+        // State 17
+        -90,
+        // State 18
+        -58,
+        // State 19
+        -71,
+        // State 20
+        -110,
+        // State 21
+        -57,
+        // State 22
+        -112,
+        // State 23
+        -24,
+        // State 24
+        -93,
+        // State 25
+        -29,
+        // State 26
+        -89,
+        // State 27
+        -103,
+        // State 28
+        -59,
+        // State 29
+        -12,
+        // State 30
+        -14,
+        // State 31
+        -16,
+        // State 32
+        -81,
+        // State 33
+        -32,
+        // State 34
+        -67,
+        // State 35
+        -86,
+        // State 36
+        -79,
+        // State 37
+        -101,
+        // State 38
+        0,
+        // State 39
+        0,
+        // State 40
+        -75,
+        // State 41
+        0,
+        // State 42
+        -76,
+        // State 43
+        0,
+        // State 44
+        -37,
+        // State 45
+        -38,
+        // State 46
+        0,
+        // State 47
+        -42,
+        // State 48
+        -48,
+        // State 49
+        0,
+        // State 50
+        -88,
+        // State 51
+        -80,
+        // State 52
+        0,
+        // State 53
+        -104,
+        // State 54
+        0,
+        // State 55
+        0,
+        // State 56
+        0,
+        // State 57
+        0,
+        // State 58
+        -62,
+        // State 59
+        0,
+        // State 60
+        0,
+        // State 61
+        0,
+        // State 62
+        -28,
+        // State 63
+        -4,
+        // State 64
+        0,
+        // State 65
+        0,
+        // State 66
+        0,
+        // State 67
+        0,
+        // State 68
+        0,
+        // State 69
+        0,
+        // State 70
+        0,
+        // State 71
+        0,
+        // State 72
+        0,
+        // State 73
+        0,
+        // State 74
+        0,
+        // State 75
+        0,
+        // State 76
+        0,
+        // State 77
+        0,
+        // State 78
+        0,
+        // State 79
+        0,
+        // State 80
+        0,
+        // State 81
+        0,
+        // State 82
+        0,
+        // State 83
+        0,
+        // State 84
+        0,
+        // State 85
+        0,
+        // State 86
+        0,
+        // State 87
+        0,
+        // State 88
+        -47,
+        // State 89
+        -108,
+        // State 90
+        -51,
+        // State 91
+        0,
+        // State 92
+        -54,
+        // State 93
+        0,
+        // State 94
+        0,
+        // State 95
+        -55,
+        // State 96
+        0,
+        // State 97
+        0,
+        // State 98
+        0,
+        // State 99
+        0,
+        // State 100
+        -78,
+        // State 101
+        0,
+        // State 102
+        0,
+        // State 103
+        -77,
+        // State 104
+        0,
+        // State 105
+        -109,
+        // State 106
+        -5,
+        // State 107
+        -64,
+        // State 108
+        -65,
+        // State 109
+        -11,
+        // State 110
+        0,
+        // State 111
+        0,
+        // State 112
+        0,
+        // State 113
+        -82,
+        // State 114
+        -56,
+        // State 115
+        -92,
+        // State 116
+        -94,
+        // State 117
+        -96,
+        // State 118
+        -98,
+        // State 119
+        -100,
+        // State 120
+        -102,
+        // State 121
+        -91,
+        // State 122
+        0,
+        // State 123
+        0,
+        // State 124
+        -40,
+        // State 125
+        0,
+        // State 126
+        -50,
+        // State 127
+        -52,
+        // State 128
+        0,
+        // State 129
+        0,
+        // State 130
+        0,
+        // State 131
+        -105,
+        // State 132
+        0,
+        // State 133
+        0,
+        // State 134
+        0,
+        // State 135
+        -84,
+        // State 136
+        0,
+        // State 137
+        -83,
+        // State 138
+        -41,
+        // State 139
+        -53,
+        // State 140
+        -20,
+        // State 141
+        0,
+        // State 142
+        -49,
+        // State 143
+        0,
+        // State 144
+        0,
+        // State 145
+        -106,
+        // State 146
+        -18,
+        // State 147
+        0,
+        // State 148
+        -107,
+        // State 149
+        0,
+        // State 150
+        0,
+        // State 151
+        0,
+        // State 152
+        -39,
+        // State 153
+        -19,
+    ];
+    const __GOTO: &'static [i16] = &[
+        // State 0
+        0, 0, 2, 0, 0, 0, 3, 4, 0, 5, 0, 6, 0, 0, 0, 7, 8, 9, 10, 0, 11, 0, 12, 0, 13, 14, 15, 16, 17, 0, 0, 18, 19, 20, 0, 0, 21, 22, 23, 24, 25, 0, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 0, 0,
+        // State 1
+        0, 0, 0, 0, 0, 0, 3, 4, 0, 5, 0, 6, 0, 0, 0, 7, 8, 9, 10, 0, 59, 0, 12, 0, 13, 14, 15, 16, 17, 0, 0, 18, 19, 20, 0, 0, 21, 22, 0, 24, 25, 0, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 0, 0,
+        // State 2
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 3
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 4
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 5
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 6
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 7
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 8
+        0, 0, 0, 0, 0, 0, 3, 4, 0, 5, 0, 6, 0, 0, 0, 7, 62, 0, 10, 0, 0, 0, 12, 0, 13, 14, 15, 16, 17, 0, 0, 18, 19, 20, 0, 0, 21, 22, 0, 24, 25, 0, 63, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 0, 0,
+        // State 9
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 10
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 11
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 12
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 13
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 14
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 15
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 16
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 17
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 18
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 19
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 20
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 21
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 22
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 23
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 24
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 25
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 26
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 27
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 28
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 29
+        0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 30
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 31
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 32
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 33
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 34
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 35
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 36
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 37
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 38
+        0, 0, 0, 0, 0, 0, 0, 4, 0, 5, 0, 6, 0, 0, 0, 0, 0, 0, 85, 0, 0, 0, 12, 0, 13, 14, 0, 16, 86, 0, 87, 18, 19, 0, 0, 0, 21, 22, 0, 24, 25, 0, 0, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 0, 38, 0, 0,
+        // State 39
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 14, 0, 0, 89, 0, 0, 18, 19, 0, 90, 91, 0, 92, 0, 0, 0, 0, 0, 27, 0, 93, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0,
+        // State 40
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 41
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 42
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 43
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 44
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 45
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 46
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 47
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 48
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 49
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 50
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 51
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 52
+        0, 0, 0, 0, 0, 0, 0, 4, 0, 5, 0, 6, 0, 0, 0, 0, 0, 0, 101, 0, 0, 0, 12, 0, 13, 14, 0, 16, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 24, 25, 0, 0, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 0, 38, 0, 0,
+        // State 53
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 54
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 55
+        0, 0, 0, 0, 0, 0, 0, 4, 0, 5, 0, 6, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 12, 0, 13, 14, 0, 16, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 24, 25, 0, 0, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 0, 38, 0, 0,
+        // State 56
+        0, 0, 2, 0, 0, 0, 3, 4, 0, 5, 0, 6, 0, 0, 0, 7, 8, 9, 10, 0, 11, 0, 12, 0, 13, 14, 15, 16, 17, 0, 0, 18, 19, 20, 0, 0, 21, 22, 105, 24, 25, 0, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 0, 0,
+        // State 57
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 14, 0, 0, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 0, 0, 0, 0, 27, 0, 29, 0, 0, 0, 0, 0, 0, 36, 0, 106, 0, 0,
+        // State 58
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 59
+        0, 0, 0, 0, 0, 0, 0, 4, 0, 108, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 13, 14, 0, 0, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 0, 25, 0, 0, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 0, 38, 0, 0,
+        // State 60
+        0, 0, 0, 0, 0, 0, 0, 4, 0, 109, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 13, 14, 0, 0, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 0, 25, 0, 0, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 0, 38, 0, 0,
+        // State 61
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 62
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 63
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 64
+        0, 0, 0, 0, 0, 0, 0, 4, 0, 5, 0, 6, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 12, 0, 13, 14, 0, 16, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 24, 25, 0, 0, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 0, 38, 0, 0,
+        // State 65
+        0, 0, 0, 111, 0, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 14, 0, 0, 89, 0, 0, 18, 19, 0, 0, 0, 0, 113, 0, 0, 0, 0, 0, 27, 0, 29, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0,
+        // State 66
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 14, 0, 0, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 0, 0, 0, 0, 27, 0, 29, 0, 0, 0, 0, 0, 0, 36, 0, 115, 0, 0,
+        // State 67
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 13, 14, 0, 0, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 0, 116, 0, 0, 27, 28, 29, 0, 0, 0, 33, 34, 35, 36, 0, 38, 0, 0,
+        // State 68
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 69
+        0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 13, 14, 0, 0, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 0, 25, 0, 0, 27, 28, 29, 30, 0, 32, 33, 34, 35, 36, 0, 38, 0, 0,
+        // State 70
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 71
+        0, 0, 0, 0, 0, 0, 0, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 13, 14, 0, 0, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 0, 25, 0, 0, 27, 28, 29, 30, 0, 0, 33, 34, 35, 36, 0, 38, 0, 0,
+        // State 72
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 73
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 119, 0, 13, 14, 0, 0, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 0, 0, 0, 0, 27, 0, 29, 0, 0, 0, 0, 34, 0, 36, 0, 38, 0, 0,
+        // State 74
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 75
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 76
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 14, 0, 0, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 0, 0, 0, 0, 27, 0, 29, 0, 0, 0, 0, 0, 0, 36, 0, 120, 0, 0,
+        // State 77
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 78
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 79
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 80
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 81
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 13, 14, 0, 0, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 0, 0, 0, 0, 27, 121, 29, 0, 0, 0, 33, 34, 0, 36, 0, 38, 0, 0,
+        // State 82
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 83
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 84
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 85
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 86
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 87
+        0, 0, 0, 0, 0, 0, 0, 4, 0, 5, 0, 6, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 12, 0, 13, 14, 0, 16, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 24, 25, 0, 0, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 0, 38, 0, 0,
+        // State 88
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 89
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 90
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 91
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 92
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 93
+        0, 0, 0, 0, 0, 0, 0, 4, 0, 5, 0, 6, 0, 0, 0, 0, 0, 0, 85, 0, 0, 0, 12, 0, 13, 14, 0, 16, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 24, 25, 0, 0, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 0, 38, 0, 0,
+        // State 94
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 14, 0, 0, 89, 0, 0, 18, 19, 0, 127, 91, 0, 92, 0, 0, 0, 0, 0, 27, 0, 93, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0,
+        // State 95
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 96
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 14, 0, 0, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 0, 0, 0, 0, 27, 0, 29, 0, 0, 0, 0, 0, 0, 36, 0, 128, 0, 0,
+        // State 97
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 98
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 99
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 100
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 101
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 102
+        0, 0, 2, 0, 0, 0, 3, 4, 0, 5, 0, 6, 0, 0, 0, 7, 8, 9, 10, 0, 11, 0, 12, 0, 13, 14, 15, 16, 17, 0, 0, 18, 19, 20, 0, 0, 21, 22, 135, 24, 25, 0, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 0, 0,
+        // State 103
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 104
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 105
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 106
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 107
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 108
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 109
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 110
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 111
+        0, 0, 0, 137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 14, 0, 0, 89, 0, 0, 18, 19, 0, 0, 0, 0, 113, 0, 0, 0, 0, 0, 27, 0, 29, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0,
+        // State 112
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 113
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 114
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 115
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 116
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 117
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 118
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 119
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 120
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 121
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 122
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 123
+        0, 0, 0, 0, 0, 0, 0, 4, 0, 5, 0, 6, 0, 0, 0, 0, 0, 0, 139, 0, 0, 0, 12, 0, 13, 14, 0, 16, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 24, 25, 0, 0, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 0, 38, 0, 0,
+        // State 124
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 125
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 14, 0, 0, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 0, 0, 0, 0, 27, 0, 29, 0, 0, 0, 0, 0, 0, 36, 0, 140, 0, 0,
+        // State 126
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 127
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 128
+        0, 0, 0, 0, 0, 0, 0, 4, 0, 5, 0, 6, 0, 0, 0, 0, 0, 0, 141, 0, 0, 0, 12, 0, 13, 14, 0, 16, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 24, 25, 0, 0, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 0, 38, 0, 0,
+        // State 129
+        0, 0, 0, 0, 0, 0, 0, 4, 0, 5, 0, 6, 0, 0, 0, 0, 0, 0, 142, 0, 0, 0, 12, 0, 13, 14, 0, 16, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 24, 25, 0, 0, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 0, 38, 0, 0,
+        // State 130
+        0, 0, 0, 0, 0, 0, 0, 4, 0, 5, 0, 6, 0, 0, 0, 0, 0, 0, 143, 0, 0, 0, 12, 0, 13, 14, 0, 16, 89, 0, 0, 18, 19, 0, 0, 0, 21, 22, 0, 24, 25, 0, 0, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 0, 38, 0, 0,
+        // State 131
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 132
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 133
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 134
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 135
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 136
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 137
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 138
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 139
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 140
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 141
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 142
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 143
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 144
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 147
+        0, 0, 2, 0, 0, 0, 3, 4, 0, 5, 0, 6, 0, 0, 0, 7, 8, 9, 10, 0, 11, 0, 12, 0, 13, 14, 15, 16, 17, 0, 0, 18, 19, 20, 0, 0, 21, 22, 151, 24, 25, 0, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 0, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 150
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 151
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 153
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###""%""###,
+            r###""&""###,
+            r###""(""###,
+            r###"")""###,
+            r###"") =>""###,
+            r###""*""###,
+            r###""**""###,
+            r###""+""###,
+            r###""-""###,
+            r###""..""###,
+            r###""..=""###,
+            r###""/""###,
+            r###"";""###,
+            r###""<<""###,
+            r###""=""###,
+            r###"">>""###,
+            r###""^""###,
+            r###""break""###,
+            r###""call(""###,
+            r###""catch""###,
+            r###""const""###,
+            r###""continue""###,
+            r###""doc comment""###,
+            r###""false""###,
+            r###""finally""###,
+            r###""float""###,
+            r###""for""###,
+            r###""identifier""###,
+            r###""in""###,
+            r###""int""###,
+            r###""let""###,
+            r###""nil""###,
+            r###""string""###,
+            r###""throw""###,
+            r###""true""###,
+            r###""try""###,
+            r###""yield""###,
+            r###""{""###,
+            r###""|""###,
+            r###""}""###,
+            r###""~""###,
+            r###""~/""###,
+        ];
+        __ACTION[(__state * 42)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    pub struct ProgramParser {
+        _priv: (),
+    }
+
+    impl ProgramParser {
+        pub fn new() -> ProgramParser {
+            ProgramParser {
+                _priv: (),
+            }
+        }
+
+        #[allow(dead_code)]
+        pub fn parse<
+            'input,
+            __TOKEN: __ToTriple<'input, Error=LexicalError>,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
+        >(
+            &self,
+            __tokens0: __TOKENS,
+        ) -> Result<Vec<Expr>, __lalrpop_util::ParseError<usize, Tok<'input>, LexicalError>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            let mut __states = vec![0_i16];
+            let mut __symbols = vec![];
+            let mut __integer;
+            let mut __lookahead;
+            let __last_location = &mut Default::default();
+            '__shift: loop {
+                __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => v,
+                    None => break '__shift,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                *__last_location = __lookahead.2.clone();
+                __integer = match __lookahead.1 {
+                    Tok::Percent if true => 0,
+                    Tok::Amp if true => 1,
+                    Tok::LParen if true => 2,
+                    Tok::RParen if true => 3,
+                    Tok::RParenArrow if true => 4,
+                    Tok::Star if true => 5,
+                    Tok::StarStar if true => 6,
+                    Tok::Plus if true => 7,
+                    Tok::Minus if true => 8,
+                    Tok::DotDot if true => 9,
+                    Tok::DotDotEquals if true => 10,
+                    Tok::Slash if true => 11,
+                    Tok::Semicolon if true => 12,
+                    Tok::ShiftLeft if true => 13,
+                    Tok::Equals if true => 14,
+                    Tok::ShiftRight if true => 15,
+                    Tok::Caret if true => 16,
+                    Tok::Break if true => 17,
+                    Tok::CallParen if true => 18,
+                    Tok::Catch if true => 19,
+                    Tok::Const if true => 20,
+                    Tok::Continue if true => 21,
+                    Tok::DocComment(_) if true => 22,
+                    Tok::False if true => 23,
+                    Tok::Finally if true => 24,
+                    Tok::Float(_) if true => 25,
+                    Tok::For if true => 26,
+                    Tok::Identifier(_) if true => 27,
+                    Tok::In if true => 28,
+                    Tok::Int(_) if true => 29,
+                    Tok::Let if true => 30,
+                    Tok::Nil if true => 31,
+                    Tok::String(_) if true => 32,
+                    Tok::Throw if true => 33,
+                    Tok::True if true => 34,
+                    Tok::Try if true => 35,
+                    Tok::Yield if true => 36,
+                    Tok::LBrace if true => 37,
+                    Tok::Pipe if true => 38,
+                    Tok::RBrace if true => 39,
+                    Tok::Tilde if true => 40,
+                    Tok::TildeSlash if true => 41,
+                    _ => {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                            token: Some(__lookahead),
+                            expected: __expected_tokens(__state),
+                        };
+                        return Err(__error);
+                    }
+                };
+                '__inner: loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __ACTION[__state * 42 + __integer];
+                    if __action > 0 {
+                        let __symbol = match __integer {
+                            0 => match __lookahead.1 {
+                                __tok @ Tok::Percent => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            1 => match __lookahead.1 {
+                                __tok @ Tok::Amp => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            2 => match __lookahead.1 {
+                                __tok @ Tok::LParen => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            3 => match __lookahead.1 {
+                                __tok @ Tok::RParen => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            4 => match __lookahead.1 {
+                                __tok @ Tok::RParenArrow => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            5 => match __lookahead.1 {
+                                __tok @ Tok::Star => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            6 => match __lookahead.1 {
+                                __tok @ Tok::StarStar => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            7 => match __lookahead.1 {
+                                __tok @ Tok::Plus => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            8 => match __lookahead.1 {
+                                __tok @ Tok::Minus => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            9 => match __lookahead.1 {
+                                __tok @ Tok::DotDot => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            10 => match __lookahead.1 {
+                                __tok @ Tok::DotDotEquals => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            11 => match __lookahead.1 {
+                                __tok @ Tok::Slash => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            12 => match __lookahead.1 {
+                                __tok @ Tok::Semicolon => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            13 => match __lookahead.1 {
+                                __tok @ Tok::ShiftLeft => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            14 => match __lookahead.1 {
+                                __tok @ Tok::Equals => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            15 => match __lookahead.1 {
+                                __tok @ Tok::ShiftRight => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            16 => match __lookahead.1 {
+                                __tok @ Tok::Caret => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            17 => match __lookahead.1 {
+                                __tok @ Tok::Break => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            18 => match __lookahead.1 {
+                                __tok @ Tok::CallParen => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            19 => match __lookahead.1 {
+                                __tok @ Tok::Catch => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            20 => match __lookahead.1 {
+                                __tok @ Tok::Const => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            21 => match __lookahead.1 {
+                                __tok @ Tok::Continue => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            22 => match __lookahead.1 {
+                                Tok::DocComment(__tok0) => __Symbol::Variant1((__tok0)),
+                                _ => unreachable!(),
+                            },
+                            23 => match __lookahead.1 {
+                                __tok @ Tok::False => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            24 => match __lookahead.1 {
+                                __tok @ Tok::Finally => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            25 => match __lookahead.1 {
+                                Tok::Float(__tok0) => __Symbol::Variant2((__tok0)),
+                                _ => unreachable!(),
+                            },
+                            26 => match __lookahead.1 {
+                                __tok @ Tok::For => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            27 => match __lookahead.1 {
+                                Tok::Identifier(__tok0) => __Symbol::Variant1((__tok0)),
+                                _ => unreachable!(),
+                            },
+                            28 => match __lookahead.1 {
+                                __tok @ Tok::In => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            29 => match __lookahead.1 {
+                                Tok::Int(__tok0) => __Symbol::Variant1((__tok0)),
+                                _ => unreachable!(),
+                            },
+                            30 => match __lookahead.1 {
+                                __tok @ Tok::Let => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            31 => match __lookahead.1 {
+                                __tok @ Tok::Nil => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            32 => match __lookahead.1 {
+                                Tok::String(__tok0) => __Symbol::Variant1((__tok0)),
+                                _ => unreachable!(),
+                            },
+                            33 => match __lookahead.1 {
+                                __tok @ Tok::Throw => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            34 => match __lookahead.1 {
+                                __tok @ Tok::True => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            35 => match __lookahead.1 {
+                                __tok @ Tok::Try => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            36 => match __lookahead.1 {
+                                __tok @ Tok::Yield => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            37 => match __lookahead.1 {
+                                __tok @ Tok::LBrace => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            38 => match __lookahead.1 {
+                                __tok @ Tok::Pipe => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            39 => match __lookahead.1 {
+                                __tok @ Tok::RBrace => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            40 => match __lookahead.1 {
+                                __tok @ Tok::Tilde => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            41 => match __lookahead.1 {
+                                __tok @ Tok::TildeSlash => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            _ => unreachable!(),
+                        };
+                        __states.push(__action - 1);
+                        __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                        continue '__shift;
+                    } else if __action < 0 {
+                        if let Some(r) = __reduce(__action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            if r.is_err() {
+                                return r;
+                            }
+                            return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead });
+                        }
+                    } else {
+                        let mut __err_lookahead = Some(__lookahead);
+                        let mut __err_integer: Option<usize> = Some(__integer);
+                        let __state = *__states.last().unwrap() as usize;
+                        let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                            token: __err_lookahead,
+                            expected: __expected_tokens(__state),
+                        };
+                        return Err(__error)
+                    }
+                }
+            }
+            loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __EOF_ACTION[__state];
+                if __action < 0 {
+                    if let Some(r) = __reduce(__action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        return r;
+                    }
+                } else {
+                    let mut __err_lookahead = None;
+                    let mut __err_integer: Option<usize> = None;
+                    let __state = *__states.last().unwrap() as usize;
+                    let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                        token: __err_lookahead,
+                        expected: __expected_tokens(__state),
+                    };
+                    return Err(__error)
+                }
+            }
+        }
+    }
+    pub(crate) fn __reduce<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<Vec<Expr>,__lalrpop_util::ParseError<usize, Tok<'input>, LexicalError>>>
+    {
+        let (__pop_states, __symbol, __nonterminal) = match -__action {
+            1 => {
+                __reduce1(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            2 => {
+                __reduce2(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            3 => {
+                __reduce3(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            4 => {
+                __reduce4(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            5 => {
+                __reduce5(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            6 => {
+                __reduce6(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            7 => {
+                __reduce7(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            8 => {
+                __reduce8(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            9 => {
+                __reduce9(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            10 => {
+                __reduce10(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            11 => {
+                __reduce11(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            12 => {
+                __reduce12(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            13 => {
+                __reduce13(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            14 => {
+                __reduce14(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            15 => {
+                __reduce15(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            16 => {
+                __reduce16(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            17 => {
+                __reduce17(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            18 => {
+                __reduce18(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            19 => {
+                __reduce19(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            20 => {
+                __reduce20(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            21 => {
+                __reduce21(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            22 => {
+                __reduce22(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            23 => {
+                __reduce23(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            24 => {
+                __reduce24(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            25 => {
+                __reduce25(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            26 => {
+                __reduce26(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            27 => {
+                __reduce27(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            28 => {
+                __reduce28(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            29 => {
+                __reduce29(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            30 => {
+                __reduce30(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            31 => {
+                __reduce31(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            32 => {
+                __reduce32(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            33 => {
+                __reduce33(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            34 => {
+                __reduce34(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            35 => {
+                __reduce35(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            36 => {
+                __reduce36(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            37 => {
+                __reduce37(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            38 => {
+                __reduce38(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            39 => {
+                __reduce39(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            40 => {
+                __reduce40(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            41 => {
+                __reduce41(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            42 => {
+                __reduce42(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            43 => {
+                __reduce43(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            44 => {
+                __reduce44(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            45 => {
+                __reduce45(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            46 => {
+                __reduce46(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            47 => {
+                __reduce47(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            48 => {
+                __reduce48(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            49 => {
+                __reduce49(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            50 => {
+                __reduce50(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            51 => {
+                __reduce51(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            52 => {
+                __reduce52(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            53 => {
+                __reduce53(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            54 => {
+                __reduce54(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            55 => {
+                __reduce55(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            56 => {
+                __reduce56(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            57 => {
+                __reduce57(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            58 => {
+                __reduce58(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            59 => {
+                __reduce59(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            60 => {
+                __reduce60(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            61 => {
+                __reduce61(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            62 => {
+                __reduce62(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            63 => {
+                __reduce63(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            64 => {
+                __reduce64(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            65 => {
+                __reduce65(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            66 => {
+                __reduce66(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            67 => {
+                __reduce67(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            68 => {
+                __reduce68(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            69 => {
+                __reduce69(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            70 => {
+                __reduce70(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            71 => {
+                __reduce71(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            72 => {
+                __reduce72(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            73 => {
+                __reduce73(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            74 => {
+                __reduce74(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            75 => {
+                __reduce75(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            76 => {
+                __reduce76(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            77 => {
+                __reduce77(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            78 => {
+                __reduce78(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            79 => {
+                __reduce79(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            80 => {
+                __reduce80(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            81 => {
+                __reduce81(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            82 => {
+                __reduce82(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            83 => {
+                __reduce83(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            84 => {
+                __reduce84(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            85 => {
+                __reduce85(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            86 => {
+                __reduce86(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            87 => {
+                __reduce87(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            88 => {
+                __reduce88(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            89 => {
+                __reduce89(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            90 => {
+                __reduce90(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            91 => {
+                __reduce91(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            92 => {
+                __reduce92(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            93 => {
+                __reduce93(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            94 => {
+                __reduce94(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            95 => {
+                __reduce95(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            96 => {
+                __reduce96(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            97 => {
+                __reduce97(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            98 => {
+                __reduce98(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            99 => {
+                __reduce99(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            100 => {
+                __reduce100(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            101 => {
+                __reduce101(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            102 => {
+                __reduce102(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            103 => {
+                __reduce103(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            104 => {
+                __reduce104(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            105 => {
+                __reduce105(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            106 => {
+                __reduce106(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            107 => {
+                __reduce107(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            108 => {
+                __reduce108(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            109 => {
+                __reduce109(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            110 => {
+                __reduce110(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            111 => {
+                __reduce111(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            112 => {
+                // __Program = Program => ActionFn(0);
+                let __sym0 = __pop_Variant8(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(__sym0);
+                return Some(Ok(__nt));
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __states_len = __states.len();
+        __states.truncate(__states_len - __pop_states);
+        __symbols.push(__symbol);
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 57 + __nonterminal] - 1;
+        __states.push(__next_state);
+        None
+    }
+    fn __pop_Variant9<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Identifier, Vec<Expr>), usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant9(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant3<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant3(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant5<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Expr, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant5(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant14<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Identifier, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant14(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant7<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Operator, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant7(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant10<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant10(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant0<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Tok<'input>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant0(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant16<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Value, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant16(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant8<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<Expr>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant8(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant13<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, bool, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant13(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant2<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, f64, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant2(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant12<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::option::Option<Box<Expr>>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant12(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant4<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<Box<Expr>>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant4(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant6<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<Expr>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant6(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant15<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<Identifier>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant15(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant11<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<String>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant11(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant1<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant1(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    pub(crate) fn __reduce1<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // (<ExprOrStmt> ";") = ExprOrStmt, ";" => ActionFn(100);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action100::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 0)
+    }
+    pub(crate) fn __reduce2<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // (<ExprOrStmt> ";")* =  => ActionFn(98);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action98::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (0, __symbol, 1)
+    }
+    pub(crate) fn __reduce3<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // (<ExprOrStmt> ";")* = (<ExprOrStmt> ";")+ => ActionFn(99);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action99::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 1)
+    }
+    pub(crate) fn __reduce4<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // (<ExprOrStmt> ";")+ = ExprOrStmt, ";" => ActionFn(107);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action107::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (2, __symbol, 2)
+    }
+    pub(crate) fn __reduce5<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // (<ExprOrStmt> ";")+ = (<ExprOrStmt> ";")+, ExprOrStmt, ";" => ActionFn(108);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action108::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 2)
+    }
+    pub(crate) fn __reduce6<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Argument = PowerBase => ActionFn(76);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action76::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 3)
+    }
+    pub(crate) fn __reduce7<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Argument* =  => ActionFn(78);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action78::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (0, __symbol, 4)
+    }
+    pub(crate) fn __reduce8<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Argument* = Argument+ => ActionFn(79);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action79::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (1, __symbol, 4)
+    }
+    pub(crate) fn __reduce9<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Argument+ = Argument => ActionFn(105);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action105::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (1, __symbol, 5)
+    }
+    pub(crate) fn __reduce10<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Argument+ = Argument+, Argument => ActionFn(106);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action106::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (2, __symbol, 5)
+    }
+    pub(crate) fn __reduce11<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Assignment = Identifier, "=", Expr => ActionFn(74);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action74::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 6)
+    }
+    pub(crate) fn __reduce12<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // BitAnd = Tier<BitAndOp, Shift> => ActionFn(30);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action30::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 7)
+    }
+    pub(crate) fn __reduce13<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // BitAndOp = "&" => ActionFn(36);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action36::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 8)
+    }
+    pub(crate) fn __reduce14<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // BitOr = Tier<BitOrOp, BitXor> => ActionFn(28);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action28::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 9)
+    }
+    pub(crate) fn __reduce15<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // BitOrOp = "|" => ActionFn(34);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action34::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 10)
+    }
+    pub(crate) fn __reduce16<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // BitXor = Tier<BitXorOp, BitAnd> => ActionFn(29);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action29::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 11)
+    }
+    pub(crate) fn __reduce17<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // BitXorOp = "^" => ActionFn(35);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action35::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 12)
+    }
+    pub(crate) fn __reduce18<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Body = "{", Program, "}" => ActionFn(20);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action20::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (3, __symbol, 13)
+    }
+    pub(crate) fn __reduce19<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Catch = "catch", "(", Identifier, ")", Body => ActionFn(19);
+        let __sym4 = __pop_Variant8(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant14(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action19::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant9(__nt), __end);
+        (5, __symbol, 14)
+    }
+    pub(crate) fn __reduce20<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // ConstAssignment = "const", Identifier, "=", Expr => ActionFn(73);
+        let __sym3 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action73::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (4, __symbol, 15)
+    }
+    pub(crate) fn __reduce21<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // DocComment = "doc comment" => ActionFn(21);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action21::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant10(__nt), __end);
+        (1, __symbol, 16)
+    }
+    pub(crate) fn __reduce22<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // DocComment+ = DocComment => ActionFn(94);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action94::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant11(__nt), __end);
+        (1, __symbol, 17)
+    }
+    pub(crate) fn __reduce23<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // DocComment+ = DocComment+, DocComment => ActionFn(95);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action95::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant11(__nt), __end);
+        (2, __symbol, 17)
+    }
+    pub(crate) fn __reduce24<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Expr = Range => ActionFn(22);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action22::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 18)
+    }
+    pub(crate) fn __reduce25<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Expr = Function => ActionFn(23);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action23::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 18)
+    }
+    pub(crate) fn __reduce26<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // ExprOp = "+" => ActionFn(39);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action39::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 19)
+    }
+    pub(crate) fn __reduce27<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // ExprOp = "-" => ActionFn(40);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action40::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 19)
+    }
+    pub(crate) fn __reduce28<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // ExprOrStmt = DocComment+, Stmt => ActionFn(3);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action3::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 20)
+    }
+    pub(crate) fn __reduce29<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // ExprOrStmt = Stmt => ActionFn(4);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action4::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 20)
+    }
+    pub(crate) fn __reduce30<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // ExprOrStmt? = ExprOrStmt => ActionFn(96);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action96::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (1, __symbol, 21)
+    }
+    pub(crate) fn __reduce31<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // ExprOrStmt? =  => ActionFn(97);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action97::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (0, __symbol, 21)
+    }
+    pub(crate) fn __reduce32<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Factor = Tier<FactorOp, Unary> => ActionFn(33);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action33::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 22)
+    }
+    pub(crate) fn __reduce33<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // FactorOp = "*" => ActionFn(41);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action41::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 23)
+    }
+    pub(crate) fn __reduce34<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // FactorOp = "/" => ActionFn(42);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action42::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 23)
+    }
+    pub(crate) fn __reduce35<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // FactorOp = "%" => ActionFn(43);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action43::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 23)
+    }
+    pub(crate) fn __reduce36<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // FactorOp = "~/" => ActionFn(44);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action44::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 23)
+    }
+    pub(crate) fn __reduce37<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // False = "false" => ActionFn(70);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action70::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant13(__nt), __end);
+        (1, __symbol, 24)
+    }
+    pub(crate) fn __reduce38<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Float = "float" => ActionFn(68);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action68::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
+        (1, __symbol, 25)
+    }
+    pub(crate) fn __reduce39<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // For = "for", Identifier, "in", Expr, "{", Program, "}" => ActionFn(15);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant8(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action15::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (7, __symbol, 26)
+    }
+    pub(crate) fn __reduce40<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Function = "(", ") =>", Expr => ActionFn(117);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action117::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 27)
+    }
+    pub(crate) fn __reduce41<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Function = "(", Identifier+, ") =>", Expr => ActionFn(118);
+        let __sym3 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action118::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (4, __symbol, 27)
+    }
+    pub(crate) fn __reduce42<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Identifier = "identifier" => ActionFn(77);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action77::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant14(__nt), __end);
+        (1, __symbol, 28)
+    }
+    pub(crate) fn __reduce43<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Identifier* =  => ActionFn(92);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action92::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant15(__nt), __end);
+        (0, __symbol, 29)
+    }
+    pub(crate) fn __reduce44<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Identifier* = Identifier+ => ActionFn(93);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action93::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant15(__nt), __end);
+        (1, __symbol, 29)
+    }
+    pub(crate) fn __reduce45<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Identifier+ = Identifier => ActionFn(103);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action103::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant15(__nt), __end);
+        (1, __symbol, 30)
+    }
+    pub(crate) fn __reduce46<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Identifier+ = Identifier+, Identifier => ActionFn(104);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action104::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant15(__nt), __end);
+        (2, __symbol, 30)
+    }
+    pub(crate) fn __reduce47<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // IdentifierExpr = Identifier => ActionFn(75);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action75::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 31)
+    }
+    pub(crate) fn __reduce48<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Int = "int" => ActionFn(66);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action66::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant16(__nt), __end);
+        (1, __symbol, 32)
+    }
+    pub(crate) fn __reduce49<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // LetAssignment = "let", Identifier, "=", Expr => ActionFn(72);
+        let __sym3 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action72::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (4, __symbol, 33)
+    }
+    pub(crate) fn __reduce50<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // NegatedUnary = "-", NegatedUnary => ActionFn(48);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action48::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 34)
+    }
+    pub(crate) fn __reduce51<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // NegatedUnary = NegativeInt => ActionFn(49);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action49::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 34)
+    }
+    pub(crate) fn __reduce52<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // NegatedUnary = "~", Unary => ActionFn(50);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action50::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 34)
+    }
+    pub(crate) fn __reduce53<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // NegatedUnary = PowerBase, "**", Unary => ActionFn(51);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action51::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 34)
+    }
+    pub(crate) fn __reduce54<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // NegatedUnary = Term => ActionFn(52);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action52::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 34)
+    }
+    pub(crate) fn __reduce55<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // NegativeInt = "int" => ActionFn(67);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action67::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant16(__nt), __end);
+        (1, __symbol, 35)
+    }
+    pub(crate) fn __reduce56<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Power = PowerBase, "**", Unary => ActionFn(53);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action53::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 36)
+    }
+    pub(crate) fn __reduce57<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Power = PowerBase => ActionFn(54);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action54::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 36)
+    }
+    pub(crate) fn __reduce58<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // PowerBase = Int => ActionFn(55);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action55::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 37)
+    }
+    pub(crate) fn __reduce59<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // PowerBase = Term => ActionFn(56);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action56::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 37)
+    }
+    pub(crate) fn __reduce60<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Program = ExprOrStmt => ActionFn(113);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action113::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 38)
+    }
+    pub(crate) fn __reduce61<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Program =  => ActionFn(114);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action114::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (0, __symbol, 38)
+    }
+    pub(crate) fn __reduce62<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Program = (<ExprOrStmt> ";")+, ExprOrStmt => ActionFn(115);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action115::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (2, __symbol, 38)
+    }
+    pub(crate) fn __reduce63<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Program = (<ExprOrStmt> ";")+ => ActionFn(116);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action116::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 38)
+    }
+    pub(crate) fn __reduce64<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Range = BitOr, "..", BitOr => ActionFn(24);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action24::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 39)
+    }
+    pub(crate) fn __reduce65<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Range = BitOr, "..=", BitOr => ActionFn(25);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action25::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 39)
+    }
+    pub(crate) fn __reduce66<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Range = BitOr => ActionFn(26);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action26::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 39)
+    }
+    pub(crate) fn __reduce67<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Shift = Tier<ShiftOp, Sum> => ActionFn(31);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action31::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 40)
+    }
+    pub(crate) fn __reduce68<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // ShiftOp = "<<" => ActionFn(37);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action37::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 41)
+    }
+    pub(crate) fn __reduce69<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // ShiftOp = ">>" => ActionFn(38);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action38::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 41)
+    }
+    pub(crate) fn __reduce70<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Stmt = Expr => ActionFn(5);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action5::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 42)
+    }
+    pub(crate) fn __reduce71<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Stmt = LetAssignment => ActionFn(6);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action6::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 42)
+    }
+    pub(crate) fn __reduce72<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Stmt = ConstAssignment => ActionFn(7);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action7::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 42)
+    }
+    pub(crate) fn __reduce73<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Stmt = Assignment => ActionFn(8);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action8::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 42)
+    }
+    pub(crate) fn __reduce74<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Stmt = For => ActionFn(9);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action9::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 42)
+    }
+    pub(crate) fn __reduce75<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Stmt = "break" => ActionFn(10);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action10::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 42)
+    }
+    pub(crate) fn __reduce76<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Stmt = "continue" => ActionFn(11);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action11::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 42)
+    }
+    pub(crate) fn __reduce77<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Stmt = "yield", Expr => ActionFn(12);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action12::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 42)
+    }
+    pub(crate) fn __reduce78<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Stmt = "throw", Expr => ActionFn(13);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action13::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 42)
+    }
+    pub(crate) fn __reduce79<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Stmt = Try => ActionFn(14);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action14::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 42)
+    }
+    pub(crate) fn __reduce80<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // String = "string" => ActionFn(71);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action71::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 43)
+    }
+    pub(crate) fn __reduce81<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Sum = Tier<ExprOp, Factor> => ActionFn(32);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action32::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 44)
+    }
+    pub(crate) fn __reduce82<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Term = Identifier, "call(", ")" => ActionFn(111);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action111::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 45)
+    }
+    pub(crate) fn __reduce83<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Term = Identifier, "call(", Argument+, ")" => ActionFn(112);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action112::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (4, __symbol, 45)
+    }
+    pub(crate) fn __reduce84<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Term = "{", Program, "}" => ActionFn(58);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action58::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 45)
+    }
+    pub(crate) fn __reduce85<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Term = Float => ActionFn(59);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action59::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 45)
+    }
+    pub(crate) fn __reduce86<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Term = True => ActionFn(60);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action60::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 45)
+    }
+    pub(crate) fn __reduce87<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Term = False => ActionFn(61);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action61::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 45)
+    }
+    pub(crate) fn __reduce88<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Term = "nil" => ActionFn(62);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action62::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 45)
+    }
+    pub(crate) fn __reduce89<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Term = String => ActionFn(63);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action63::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 45)
+    }
+    pub(crate) fn __reduce90<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Term = IdentifierExpr => ActionFn(64);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action64::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 45)
+    }
+    pub(crate) fn __reduce91<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Term = "(", Expr, ")" => ActionFn(65);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action65::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 45)
+    }
+    pub(crate) fn __reduce92<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Tier<BitAndOp, Shift> = Tier<BitAndOp, Shift>, BitAndOp, Shift => ActionFn(86);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action86::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 46)
+    }
+    pub(crate) fn __reduce93<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Tier<BitAndOp, Shift> = Shift => ActionFn(87);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action87::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 46)
+    }
+    pub(crate) fn __reduce94<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Tier<BitOrOp, BitXor> = Tier<BitOrOp, BitXor>, BitOrOp, BitXor => ActionFn(90);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action90::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 47)
+    }
+    pub(crate) fn __reduce95<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Tier<BitOrOp, BitXor> = BitXor => ActionFn(91);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action91::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 47)
+    }
+    pub(crate) fn __reduce96<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Tier<BitXorOp, BitAnd> = Tier<BitXorOp, BitAnd>, BitXorOp, BitAnd => ActionFn(88);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action88::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 48)
+    }
+    pub(crate) fn __reduce97<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Tier<BitXorOp, BitAnd> = BitAnd => ActionFn(89);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action89::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 48)
+    }
+    pub(crate) fn __reduce98<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Tier<ExprOp, Factor> = Tier<ExprOp, Factor>, ExprOp, Factor => ActionFn(82);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action82::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 49)
+    }
+    pub(crate) fn __reduce99<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Tier<ExprOp, Factor> = Factor => ActionFn(83);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action83::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 49)
+    }
+    pub(crate) fn __reduce100<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Tier<FactorOp, Unary> = Tier<FactorOp, Unary>, FactorOp, Unary => ActionFn(80);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action80::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 50)
+    }
+    pub(crate) fn __reduce101<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Tier<FactorOp, Unary> = Unary => ActionFn(81);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action81::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 50)
+    }
+    pub(crate) fn __reduce102<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Tier<ShiftOp, Sum> = Tier<ShiftOp, Sum>, ShiftOp, Sum => ActionFn(84);
+        let __sym2 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action84::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 51)
+    }
+    pub(crate) fn __reduce103<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Tier<ShiftOp, Sum> = Sum => ActionFn(85);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action85::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 51)
+    }
+    pub(crate) fn __reduce104<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // True = "true" => ActionFn(69);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action69::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant13(__nt), __end);
+        (1, __symbol, 52)
+    }
+    pub(crate) fn __reduce105<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Try = "try", Body, Catch => ActionFn(16);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action16::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 53)
+    }
+    pub(crate) fn __reduce106<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Try = "try", Body, "finally", Body => ActionFn(17);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action17::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (4, __symbol, 53)
+    }
+    pub(crate) fn __reduce107<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Try = "try", Body, Catch, "finally", Body => ActionFn(18);
+        let __sym4 = __pop_Variant8(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action18::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (5, __symbol, 53)
+    }
+    pub(crate) fn __reduce108<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Unary = "-", NegatedUnary => ActionFn(45);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action45::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 54)
+    }
+    pub(crate) fn __reduce109<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Unary = "~", Unary => ActionFn(46);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action46::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 54)
+    }
+    pub(crate) fn __reduce110<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // Unary = Power => ActionFn(47);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action47::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 54)
+    }
+    pub(crate) fn __reduce111<
+        'input,
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<'input>,usize), usize)
+    {
+        // __Expr = Expr => ActionFn(1);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action1::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (1, __symbol, 55)
+    }
+}
+pub use self::__parse__Program::ProgramParser;
+
+fn __action0<
+    'input,
+>(
+    (_, __0, _): (usize, Vec<Expr>, usize),
+) -> Vec<Expr>
+{
+    (__0)
+}
+
+fn __action1<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action2<
+    'input,
+>(
+    (_, stmts, _): (usize, ::std::vec::Vec<Box<Expr>>, usize),
+    (_, last, _): (usize, ::std::option::Option<Box<Expr>>, usize),
+) -> Vec<Expr>
+{
+    {
+        let mut program: Vec<Expr> = stmts.into_iter().map(|stmt| *stmt).collect();
+        program.extend(last.map(|stmt| *stmt));
+        program
+    }
+}
+
+fn __action3<
+    'input,
+>(
+    (_, docs, _): (usize, ::std::vec::Vec<String>, usize),
+    (_, stmt, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Documented(docs.join("\n"), stmt))
+}
+
+fn __action4<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action5<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action6<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action7<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action8<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action9<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action10<
+    'input,
+>(
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Break)
+}
+
+fn __action11<
+    'input,
+>(
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Continue)
+}
+
+fn __action12<
+    'input,
+>(
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Yield(__0))
+}
+
+fn __action13<
+    'input,
+>(
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Throw(__0))
+}
+
+fn __action14<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action15<
+    'input,
+>(
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, ident, _): (usize, Identifier, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, iterable, _): (usize, Box<Expr>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, body, _): (usize, Vec<Expr>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::For(ident, iterable, body))
+}
+
+fn __action16<
+    'input,
+>(
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, body, _): (usize, Vec<Expr>, usize),
+    (_, catch, _): (usize, (Identifier, Vec<Expr>), usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Try(body, Some(catch), None))
+}
+
+fn __action17<
+    'input,
+>(
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, body, _): (usize, Vec<Expr>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, finally, _): (usize, Vec<Expr>, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Try(body, None, Some(finally)))
+}
+
+fn __action18<
+    'input,
+>(
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, body, _): (usize, Vec<Expr>, usize),
+    (_, catch, _): (usize, (Identifier, Vec<Expr>), usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, finally, _): (usize, Vec<Expr>, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Try(body, Some(catch), Some(finally)))
+}
+
+fn __action19<
+    'input,
+>(
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, ident, _): (usize, Identifier, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, body, _): (usize, Vec<Expr>, usize),
+) -> (Identifier, Vec<Expr>)
+{
+    (ident, body)
+}
+
+fn __action20<
+    'input,
+>(
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, __0, _): (usize, Vec<Expr>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+) -> Vec<Expr>
+{
+    (__0)
+}
+
+fn __action21<
+    'input,
+>(
+    (_, __0, _): (usize, &'input str, usize),
+) -> String
+{
+    __0.to_string()
+}
+
+fn __action22<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action23<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action24<
+    'input,
+>(
+    (_, start, _): (usize, Box<Expr>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, end, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Range(start, end, false))
+}
+
+fn __action25<
+    'input,
+>(
+    (_, start, _): (usize, Box<Expr>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, end, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Range(start, end, true))
+}
+
+fn __action26<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action27<
+    'input,
+>(
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, ids, _): (usize, ::std::vec::Vec<Identifier>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, body, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    match *body {
+        Expr::Block(body) => Box::new(Expr::Function(ids, body)),
+        body => Box::new(Expr::Function(ids, vec![body])),
+    }
+}
+
+fn __action28<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action29<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action30<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action31<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action32<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action33<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action34<
+    'input,
+>(
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Operator
+{
+    Operator::BitOr
+}
+
+fn __action35<
+    'input,
+>(
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Operator
+{
+    Operator::BitXor
+}
+
+fn __action36<
+    'input,
+>(
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Operator
+{
+    Operator::BitAnd
+}
 
-    pub struct __MatcherBuilder {
-        regex_set: __regex::RegexSet,
-        regex_vec: Vec<__regex::Regex>,
-    }
-
-    impl __MatcherBuilder {
-        pub fn new() -> __MatcherBuilder {
-            let __strs: &[&str] = &[
-                "^((?u:\")(?u:[\u{0}-!\\#-\u{10ffff}])+(?u:\"))",
-                "^((?u:[0-9])+)",
-                "^((?u:[A-z])((?u:[0-9A-z\\|-\\|]))*)",
-                "^((?u:[0-9٠-٩۰-۹߀-߉०-९০-৯੦-੯૦-૯୦-୯௦-௯౦-౯೦-೯൦-൯෦-෯๐-๙໐-໙༠-༩၀-၉႐-႙០-៩᠐-᠙᥆-᥏᧐-᧙᪀-᪉᪐-᪙᭐-᭙᮰-᮹᱀-᱉᱐-᱙꘠-꘩꣐-꣙꤀-꤉꧐-꧙꧰-꧹꩐-꩙꯰-꯹０-９𐒠-𐒩𑁦-𑁯𑃰-𑃹𑄶-𑄿𑇐-𑇙𑋰-𑋹𑑐-𑑙𑓐-𑓙𑙐-𑙙𑛀-𑛉𑜰-𑜹𑣠-𑣩𑱐-𑱙𑵐-𑵙𖩠-𖩩𖭐-𖭙𝟎-𝟿𞥐-𞥙])+(?u:\\.)(?u:[0-9٠-٩۰-۹߀-߉०-९০-৯੦-੯૦-૯୦-୯௦-௯౦-౯೦-೯൦-൯෦-෯๐-๙໐-໙༠-༩၀-၉႐-႙០-៩᠐-᠙᥆-᥏᧐-᧙᪀-᪉᪐-᪙᭐-᭙᮰-᮹᱀-᱉᱐-᱙꘠-꘩꣐-꣙꤀-꤉꧐-꧙꧰-꧹꩐-꩙꯰-꯹０-９𐒠-𐒩𑁦-𑁯𑃰-𑃹𑄶-𑄿𑇐-𑇙𑋰-𑋹𑑐-𑑙𑓐-𑓙𑙐-𑙙𑛀-𑛉𑜰-𑜹𑣠-𑣩𑱐-𑱙𑵐-𑵙𖩠-𖩩𖭐-𖭙𝟎-𝟿𞥐-𞥙])+)",
-                "^((?u:\\())",
-                "^((?u:\\)))",
-                "^((?u:\\) => ))",
-                "^((?u:\\*))",
-                "^((?u:\\+))",
-                "^((?u:\\-))",
-                "^((?u:/))",
-                "^((?u:;))",
-                "^((?u:=))",
-                "^((?u:const))",
-                "^((?u:false))",
-                "^((?u:let))",
-                "^((?u:true))",
-            ];
-            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
-            let __regex_vec = vec![
-                __regex::Regex::new("^((?u:\")(?u:[\u{0}-!\\#-\u{10ffff}])+(?u:\"))").unwrap(),
-                __regex::Regex::new("^((?u:[0-9])+)").unwrap(),
-                __regex::Regex::new("^((?u:[A-z])((?u:[0-9A-z\\|-\\|]))*)").unwrap(),
-                __regex::Regex::new("^((?u:[0-9٠-٩۰-۹߀-߉०-९০-৯੦-੯૦-૯୦-୯௦-௯౦-౯೦-೯൦-൯෦-෯๐-๙໐-໙༠-༩၀-၉႐-႙០-៩᠐-᠙᥆-᥏᧐-᧙᪀-᪉᪐-᪙᭐-᭙᮰-᮹᱀-᱉᱐-᱙꘠-꘩꣐-꣙꤀-꤉꧐-꧙꧰-꧹꩐-꩙꯰-꯹０-９𐒠-𐒩𑁦-𑁯𑃰-𑃹𑄶-𑄿𑇐-𑇙𑋰-𑋹𑑐-𑑙𑓐-𑓙𑙐-𑙙𑛀-𑛉𑜰-𑜹𑣠-𑣩𑱐-𑱙𑵐-𑵙𖩠-𖩩𖭐-𖭙𝟎-𝟿𞥐-𞥙])+(?u:\\.)(?u:[0-9٠-٩۰-۹߀-߉०-९০-৯੦-੯૦-૯୦-୯௦-௯౦-౯೦-೯൦-൯෦-෯๐-๙໐-໙༠-༩၀-၉႐-႙០-៩᠐-᠙᥆-᥏᧐-᧙᪀-᪉᪐-᪙᭐-᭙᮰-᮹᱀-᱉᱐-᱙꘠-꘩꣐-꣙꤀-꤉꧐-꧙꧰-꧹꩐-꩙꯰-꯹０-９𐒠-𐒩𑁦-𑁯𑃰-𑃹𑄶-𑄿𑇐-𑇙𑋰-𑋹𑑐-𑑙𑓐-𑓙𑙐-𑙙𑛀-𑛉𑜰-𑜹𑣠-𑣩𑱐-𑱙𑵐-𑵙𖩠-𖩩𖭐-𖭙𝟎-𝟿𞥐-𞥙])+)").unwrap(),
-                __regex::Regex::new("^((?u:\\())").unwrap(),
-                __regex::Regex::new("^((?u:\\)))").unwrap(),
-                __regex::Regex::new("^((?u:\\) => ))").unwrap(),
-                __regex::Regex::new("^((?u:\\*))").unwrap(),
-                __regex::Regex::new("^((?u:\\+))").unwrap(),
-                __regex::Regex::new("^((?u:\\-))").unwrap(),
-                __regex::Regex::new("^((?u:/))").unwrap(),
-                __regex::Regex::new("^((?u:;))").unwrap(),
-                __regex::Regex::new("^((?u:=))").unwrap(),
-                __regex::Regex::new("^((?u:const))").unwrap(),
-                __regex::Regex::new("^((?u:false))").unwrap(),
-                __regex::Regex::new("^((?u:let))").unwrap(),
-                __regex::Regex::new("^((?u:true))").unwrap(),
-            ];
-            __MatcherBuilder { regex_set: __regex_set, regex_vec: __regex_vec }
-        }
-        pub fn matcher<'input, 'builder>(&'builder self, s: &'input str) -> __Matcher<'input, 'builder> {
-            __Matcher {
-                text: s,
-                consumed: 0,
-                regex_set: &self.regex_set,
-                regex_vec: &self.regex_vec,
-            }
-        }
-    }
+fn __action37<
+    'input,
+>(
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Operator
+{
+    Operator::ShiftLeft
+}
 
-    pub struct __Matcher<'input, 'builder> {
-        text: &'input str,
-        consumed: usize,
-        regex_set: &'builder __regex::RegexSet,
-        regex_vec: &'builder Vec<__regex::Regex>,
-    }
+fn __action38<
+    'input,
+>(
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Operator
+{
+    Operator::ShiftRight
+}
 
-    impl<'input, 'builder> Iterator for __Matcher<'input, 'builder> {
-        type Item = Result<(usize, Token<'input>, usize), __lalrpop_util::ParseError<usize,Token<'input>,&'static str>>;
+fn __action39<
+    'input,
+>(
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Operator
+{
+    Operator::Add
+}
 
-        fn next(&mut self) -> Option<Self::Item> {
-            let __text = self.text.trim_left();
-            let __whitespace = self.text.len() - __text.len();
-            let __start_offset = self.consumed + __whitespace;
-            if __text.is_empty() {
-                self.text = __text;
-                self.consumed = __start_offset;
-                None
-            } else {
-                let __matches = self.regex_set.matches(__text);
-                if !__matches.matched_any() {
-                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
-                        location: __start_offset,
-                    }))
-                } else {
-                    let mut __longest_match = 0;
-                    let mut __index = 0;
-                    for __i in 0 .. 17 {
-                        if __matches.matched(__i) {
-                            let __match = self.regex_vec[__i].find(__text).unwrap();
-                            let __len = __match.end();
-                            if __len >= __longest_match {
-                                __longest_match = __len;
-                                __index = __i;
-                            }
-                        }
-                    }
-                    let __result = &__text[..__longest_match];
-                    let __remaining = &__text[__longest_match..];
-                    let __end_offset = __start_offset + __longest_match;
-                    self.text = __remaining;
-                    self.consumed = __end_offset;
-                    Some(Ok((__start_offset, Token(__index, __result), __end_offset)))
-                }
-            }
-        }
-    }
+fn __action40<
+    'input,
+>(
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Operator
+{
+    Operator::Sub
 }
-pub use self::__intern_token::Token;
 
-#[allow(unused_variables)]
-fn __action0<
+fn __action41<
+    'input,
+>(
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Operator
+{
+    Operator::Mul
+}
+
+fn __action42<
+    'input,
+>(
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Operator
+{
+    Operator::Div
+}
+
+fn __action43<
     'input,
 >(
-    input: &'input str,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Operator
+{
+    Operator::Mod
+}
+
+fn __action44<
+    'input,
+>(
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Operator
+{
+    Operator::FloorDiv
+}
+
+fn __action45<
+    'input,
+>(
+    (_, _, _): (usize, Tok<'input>, usize),
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
     (__0)
 }
 
-#[allow(unused_variables)]
-fn __action1<
+fn __action46<
     'input,
 >(
-    input: &'input str,
+    (_, _, _): (usize, Tok<'input>, usize),
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
-    (__0)
+    Box::new(Expr::UnaryOp(UnaryOperator::BitNot, __0))
 }
 
-#[allow(unused_variables)]
-fn __action2<
+fn __action47<
     'input,
 >(
-    input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
     (__0)
 }
 
-#[allow(unused_variables)]
-fn __action3<
+fn __action48<
     'input,
 >(
-    input: &'input str,
+    (_, _, _): (usize, Tok<'input>, usize),
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
-    (__0)
+    Box::new(Expr::UnaryOp(UnaryOperator::Neg, __0))
 }
 
-#[allow(unused_variables)]
-fn __action4<
+fn __action49<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Box<Expr>, usize),
+    (_, __0, _): (usize, Value, usize),
 ) -> Box<Expr>
 {
-    (__0)
+    Box::new(Expr::Literal(__0))
 }
 
-#[allow(unused_variables)]
-fn __action5<
+fn __action50<
     'input,
 >(
-    input: &'input str,
+    (_, _, _): (usize, Tok<'input>, usize),
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
-    (__0)
+    Box::new(Expr::UnaryOp(UnaryOperator::Neg, Box::new(Expr::UnaryOp(UnaryOperator::BitNot, __0))))
 }
 
-#[allow(unused_variables)]
-fn __action6<
+fn __action51<
+    'input,
+>(
+    (_, base, _): (usize, Box<Expr>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, exponent, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::UnaryOp(UnaryOperator::Neg, Box::new(Expr::BinaryOp(base, Operator::Pow, exponent))))
+}
+
+fn __action52<
     'input,
 >(
-    input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
-    (_, __1, _): (usize, Operator, usize),
-    (_, __2, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
-    Box::new(Expr::BinaryOp(__0, __1, __2))
+    Box::new(Expr::UnaryOp(UnaryOperator::Neg, __0))
 }
 
-#[allow(unused_variables)]
-fn __action7<
+fn __action53<
+    'input,
+>(
+    (_, base, _): (usize, Box<Expr>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, exponent, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::BinaryOp(base, Operator::Pow, exponent))
+}
+
+fn __action54<
     'input,
 >(
-    input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
     (__0)
 }
 
-#[allow(unused_variables)]
-fn __action8<
+fn __action55<
+    'input,
+>(
+    (_, __0, _): (usize, Value, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Literal(__0))
+}
+
+fn __action56<
     'input,
 >(
-    input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
     (__0)
 }
 
-#[allow(unused_variables)]
-fn __action9<
+fn __action57<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+    (_, ident, _): (usize, Identifier, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, args, _): (usize, ::std::vec::Vec<Expr>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+) -> Box<Expr>
 {
-    Operator::Add
+    Box::new(Expr::CallFunction(ident, args))
 }
 
-#[allow(unused_variables)]
-fn __action10<
+fn __action58<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, __0, _): (usize, Vec<Expr>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+) -> Box<Expr>
 {
-    Operator::Sub
+    Box::new(Expr::Block(__0))
 }
 
-#[allow(unused_variables)]
-fn __action11<
+fn __action59<
+    'input,
+>(
+    (_, __0, _): (usize, f64, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Literal(Value::Float(__0)))
+}
+
+fn __action60<
+    'input,
+>(
+    (_, __0, _): (usize, bool, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Literal(Value::Bool(true)))
+}
+
+fn __action61<
+    'input,
+>(
+    (_, __0, _): (usize, bool, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Literal(Value::Bool(false)))
+}
+
+fn __action62<
+    'input,
+>(
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Literal(Value::Nil))
+}
+
+fn __action63<
     'input,
 >(
-    input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
-    (_, __1, _): (usize, Operator, usize),
-    (_, __2, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
-    Box::new(Expr::BinaryOp(__0, __1, __2))
+    (__0)
 }
 
-#[allow(unused_variables)]
-fn __action12<
+fn __action64<
     'input,
 >(
-    input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
     (__0)
 }
 
-#[allow(unused_variables)]
-fn __action13<
+fn __action65<
+    'input,
+>(
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, __0, _): (usize, Box<Expr>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action66<
     'input,
 >(
-    input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+) -> Value
 {
-    Operator::Mul
+    Value::from_int_literal(__0, false)
 }
 
-#[allow(unused_variables)]
-fn __action14<
+fn __action67<
     'input,
 >(
-    input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
-) -> Operator
+) -> Value
 {
-    Operator::Div
+    Value::from_int_literal(__0, true)
 }
 
-#[allow(unused_variables)]
-fn __action15<
+fn __action68<
     'input,
 >(
-    input: &'input str,
-    (_, ident, _): (usize, Identifier, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, exprs, _): (usize, ::std::vec::Vec<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Box<Expr>
+    (_, __0, _): (usize, f64, usize),
+) -> f64
 {
-    Box::new(Expr::CallFunction(ident, exprs))
+    (__0)
 }
 
-#[allow(unused_variables)]
-fn __action16<
+fn __action69<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, ids, _): (usize, ::std::vec::Vec<Identifier>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, exprs, _): (usize, ::std::vec::Vec<Expr>, usize),
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> bool
+{
+    true
+}
+
+fn __action70<
+    'input,
+>(
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> bool
+{
+    false
+}
+
+fn __action71<
+    'input,
+>(
+    (_, s, _): (usize, &'input str, usize),
 ) -> Box<Expr>
 {
-    Box::new(Expr::Function(ids, exprs))
+    Box::new(Expr::Literal(Value::String(s[1..s.len() - 1].to_string())))
 }
 
-#[allow(unused_variables)]
-fn __action17<
+fn __action72<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, f64, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, ident, _): (usize, Identifier, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, expr, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
-    Box::new(Expr::Literal(Value::Float(__0)))
+    Box::new(Expr::LetAssignment(ident, expr))
 }
 
-#[allow(unused_variables)]
-fn __action18<
+fn __action73<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, i32, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, ident, _): (usize, Identifier, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, expr, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
-    Box::new(Expr::Literal(Value::Int(__0)))
+    Box::new(Expr::ConstAssignment(ident, expr))
 }
 
-#[allow(unused_variables)]
-fn __action19<
+fn __action74<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, bool, usize),
+    (_, ident, _): (usize, Identifier, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, expr, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
-    Box::new(Expr::Literal(Value::Bool(true)))
+    Box::new(Expr::Assignment(ident, expr))
 }
 
-#[allow(unused_variables)]
-fn __action20<
+fn __action75<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, bool, usize),
+    (_, __0, _): (usize, Identifier, usize),
 ) -> Box<Expr>
 {
-    Box::new(Expr::Literal(Value::Bool(false)))
+    Box::new(Expr::Identifier(__0))
+}
+
+fn __action76<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Expr
+{
+    *__0
+}
+
+fn __action77<
+    'input,
+>(
+    (_, __0, _): (usize, &'input str, usize),
+) -> Identifier
+{
+    Identifier::new(String::from_str(__0).unwrap())
+}
+
+fn __action78<
+    'input,
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<Expr>
+{
+    vec![]
+}
+
+fn __action79<
+    'input,
+>(
+    (_, v, _): (usize, ::std::vec::Vec<Expr>, usize),
+) -> ::std::vec::Vec<Expr>
+{
+    v
 }
 
-#[allow(unused_variables)]
-fn __action21<
+fn __action80<
     'input,
 >(
-    input: &'input str,
     (_, __0, _): (usize, Box<Expr>, usize),
+    (_, __1, _): (usize, Operator, usize),
+    (_, __2, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
-    (__0)
+    Box::new(Expr::BinaryOp(__0, __1, __2))
 }
 
-#[allow(unused_variables)]
-fn __action22<
+fn __action81<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
     (_, __0, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
 ) -> Box<Expr>
 {
     (__0)
 }
 
-#[allow(unused_variables)]
-fn __action23<
+fn __action82<
     'input,
 >(
-    input: &'input str,
-    (_, expr, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Expr
+    (_, __0, _): (usize, Box<Expr>, usize),
+    (_, __1, _): (usize, Operator, usize),
+    (_, __2, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
 {
-    *expr
+    Box::new(Expr::BinaryOp(__0, __1, __2))
 }
 
-#[allow(unused_variables)]
-fn __action24<
+fn __action83<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> i32
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
 {
-    i32::from_str(__0).unwrap()
+    (__0)
 }
 
-#[allow(unused_variables)]
-fn __action25<
+fn __action84<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> f64
+    (_, __0, _): (usize, Box<Expr>, usize),
+    (_, __1, _): (usize, Operator, usize),
+    (_, __2, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
 {
-    f64::from_str(__0).unwrap()
+    Box::new(Expr::BinaryOp(__0, __1, __2))
 }
 
-#[allow(unused_variables)]
-fn __action26<
+fn __action85<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> bool
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
 {
-    true
+    (__0)
 }
 
-#[allow(unused_variables)]
-fn __action27<
+fn __action86<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> bool
+    (_, __0, _): (usize, Box<Expr>, usize),
+    (_, __1, _): (usize, Operator, usize),
+    (_, __2, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
 {
-    false
+    Box::new(Expr::BinaryOp(__0, __1, __2))
 }
 
-#[allow(unused_variables)]
-fn __action28<
+fn __action87<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
-    Box::new(Expr::Literal(Value::String(String::from_str(__0).unwrap())))
+    (__0)
 }
 
-#[allow(unused_variables)]
-fn __action29<
+fn __action88<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, ident, _): (usize, Identifier, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, expr, _): (usize, Box<Expr>, usize),
+    (_, __0, _): (usize, Box<Expr>, usize),
+    (_, __1, _): (usize, Operator, usize),
+    (_, __2, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
-    Box::new(Expr::LetAssignment(ident, expr))
+    Box::new(Expr::BinaryOp(__0, __1, __2))
 }
 
-#[allow(unused_variables)]
-fn __action30<
+fn __action89<
     'input,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, ident, _): (usize, Identifier, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, expr, _): (usize, Box<Expr>, usize),
+    (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
-    Box::new(Expr::ConstAssignment(ident, expr))
+    (__0)
 }
 
-#[allow(unused_variables)]
-fn __action31<
+fn __action90<
     'input,
 >(
-    input: &'input str,
-    (_, ident, _): (usize, Identifier, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, expr, _): (usize, Box<Expr>, usize),
+    (_, __0, _): (usize, Box<Expr>, usize),
+    (_, __1, _): (usize, Operator, usize),
+    (_, __2, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
-    Box::new(Expr::Assignment(ident, expr))
+    Box::new(Expr::BinaryOp(__0, __1, __2))
 }
 
-#[allow(unused_variables)]
-fn __action32<
+fn __action91<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Identifier, usize),
+    (_, __0, _): (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
-    Box::new(Expr::Identifier(__0))
+    (__0)
 }
 
-#[allow(unused_variables)]
-fn __action33<
+fn __action92<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Identifier, usize),
-) -> Expr
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<Identifier>
 {
-    Expr::Identifier(__0)
+    vec![]
 }
 
-#[allow(unused_variables)]
-fn __action34<
+fn __action93<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Identifier
+    (_, v, _): (usize, ::std::vec::Vec<Identifier>, usize),
+) -> ::std::vec::Vec<Identifier>
 {
-    Identifier::new(String::from_str(__0).unwrap())
+    v
 }
 
-#[allow(unused_variables)]
-fn __action35<
+fn __action94<
     'input,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::vec::Vec<Expr>
+    (_, __0, _): (usize, String, usize),
+) -> ::std::vec::Vec<String>
 {
-    vec![]
+    vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action36<
+fn __action95<
     'input,
 >(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<Expr>, usize),
-) -> ::std::vec::Vec<Expr>
+    (_, v, _): (usize, ::std::vec::Vec<String>, usize),
+    (_, e, _): (usize, String, usize),
+) -> ::std::vec::Vec<String>
 {
-    v
+    { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action37<
+fn __action96<
     'input,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::vec::Vec<Identifier>
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> ::std::option::Option<Box<Expr>>
 {
-    vec![]
+    Some(__0)
 }
 
-#[allow(unused_variables)]
-fn __action38<
+fn __action97<
     'input,
 >(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<Identifier>, usize),
-) -> ::std::vec::Vec<Identifier>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<Box<Expr>>
 {
-    v
+    None
 }
 
-#[allow(unused_variables)]
-fn __action39<
+fn __action98<
     'input,
 >(
-    input: &'input str,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> ::std::vec::Vec<Expr>
+) -> ::std::vec::Vec<Box<Expr>>
 {
     vec![]
 }
 
-#[allow(unused_variables)]
-fn __action40<
+fn __action99<
     'input,
 >(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<Expr>, usize),
-) -> ::std::vec::Vec<Expr>
+    (_, v, _): (usize, ::std::vec::Vec<Box<Expr>>, usize),
+) -> ::std::vec::Vec<Box<Expr>>
 {
     v
 }
 
-#[allow(unused_variables)]
-fn __action41<
+fn __action100<
     'input,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Expr, usize),
-) -> ::std::vec::Vec<Expr>
+    (_, __0, _): (usize, Box<Expr>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+fn __action101<
+    'input,
+>(
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> ::std::vec::Vec<Box<Expr>>
 {
     vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action42<
+fn __action102<
     'input,
 >(
-    input: &'input str,
-    (_, v, _): (usize, ::std::vec::Vec<Expr>, usize),
-    (_, e, _): (usize, Expr, usize),
-) -> ::std::vec::Vec<Expr>
+    (_, v, _): (usize, ::std::vec::Vec<Box<Expr>>, usize),
+    (_, e, _): (usize, Box<Expr>, usize),
+) -> ::std::vec::Vec<Box<Expr>>
 {
     { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action43<
+fn __action103<
     'input,
 >(
-    input: &'input str,
     (_, __0, _): (usize, Identifier, usize),
 ) -> ::std::vec::Vec<Identifier>
 {
     vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action44<
+fn __action104<
     'input,
 >(
-    input: &'input str,
     (_, v, _): (usize, ::std::vec::Vec<Identifier>, usize),
     (_, e, _): (usize, Identifier, usize),
 ) -> ::std::vec::Vec<Identifier>
@@ -4397,22 +8915,18 @@ fn __action44<
     { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action45<
+fn __action105<
     'input,
 >(
-    input: &'input str,
     (_, __0, _): (usize, Expr, usize),
 ) -> ::std::vec::Vec<Expr>
 {
     vec![__0]
 }
 
-#[allow(unused_variables)]
-fn __action46<
+fn __action106<
     'input,
 >(
-    input: &'input str,
     (_, v, _): (usize, ::std::vec::Vec<Expr>, usize),
     (_, e, _): (usize, Expr, usize),
 ) -> ::std::vec::Vec<Expr>
@@ -4420,232 +8934,264 @@ fn __action46<
     { let mut v = v; v.push(e); v }
 }
 
-#[allow(unused_variables)]
-fn __action47<
+fn __action107<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, ::std::vec::Vec<Identifier>, usize),
-    __2: (usize, &'input str, usize),
-) -> Box<Expr>
+    __0: (usize, Box<Expr>, usize),
+    __1: (usize, Tok<'input>, usize),
+) -> ::std::vec::Vec<Box<Expr>>
 {
-    let __start0 = __2.2.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action35(
-        input,
-        &__start0,
-        &__end0,
-    );
-    let __temp0 = (__start0, __temp0, __end0);
-    __action16(
-        input,
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action100(
         __0,
         __1,
-        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action101(
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action48<
+fn __action108<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, ::std::vec::Vec<Identifier>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, ::std::vec::Vec<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, ::std::vec::Vec<Box<Expr>>, usize),
+    __1: (usize, Box<Expr>, usize),
+    __2: (usize, Tok<'input>, usize),
+) -> ::std::vec::Vec<Box<Expr>>
 {
-    let __start0 = __3.0.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action36(
-        input,
-        __3,
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action100(
+        __1,
+        __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action16(
-        input,
+    __action102(
         __0,
-        __1,
-        __2,
         __temp0,
     )
 }
 
-#[allow(unused_variables)]
-fn __action49<
+fn __action109<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
-) -> Box<Expr>
+    __0: (usize, ::std::option::Option<Box<Expr>>, usize),
+) -> Vec<Expr>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action37(
-        input,
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action98(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action47(
-        input,
+    __action2(
+        __temp0,
+        __0,
+    )
+}
+
+fn __action110<
+    'input,
+>(
+    __0: (usize, ::std::vec::Vec<Box<Expr>>, usize),
+    __1: (usize, ::std::option::Option<Box<Expr>>, usize),
+) -> Vec<Expr>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action99(
         __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action2(
         __temp0,
         __1,
     )
 }
 
-#[allow(unused_variables)]
-fn __action50<
+fn __action111<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, ::std::vec::Vec<Identifier>, usize),
-    __2: (usize, &'input str, usize),
+    __0: (usize, Identifier, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Tok<'input>, usize),
 ) -> Box<Expr>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action38(
-        input,
-        __1,
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action78(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action47(
-        input,
+    __action57(
         __0,
+        __1,
         __temp0,
         __2,
     )
 }
 
-#[allow(unused_variables)]
-fn __action51<
+fn __action112<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, &'input str, usize),
+    __0: (usize, Identifier, usize),
+    __1: (usize, Tok<'input>, usize),
     __2: (usize, ::std::vec::Vec<Expr>, usize),
+    __3: (usize, Tok<'input>, usize),
 ) -> Box<Expr>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action37(
-        input,
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action79(
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action57(
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
+}
+
+fn __action113<
+    'input,
+>(
+    __0: (usize, Box<Expr>, usize),
+) -> Vec<Expr>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action96(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action109(
+        __temp0,
+    )
+}
+
+fn __action114<
+    'input,
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<Expr>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action97(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action48(
-        input,
-        __0,
+    __action109(
         __temp0,
-        __1,
-        __2,
     )
 }
 
-#[allow(unused_variables)]
-fn __action52<
+fn __action115<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, ::std::vec::Vec<Identifier>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, ::std::vec::Vec<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, ::std::vec::Vec<Box<Expr>>, usize),
+    __1: (usize, Box<Expr>, usize),
+) -> Vec<Expr>
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action38(
-        input,
+    let __temp0 = __action96(
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action48(
-        input,
+    __action110(
         __0,
         __temp0,
-        __2,
-        __3,
     )
 }
 
-#[allow(unused_variables)]
-fn __action53<
+fn __action116<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, Identifier, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize),
+    __0: (usize, ::std::vec::Vec<Box<Expr>>, usize),
+) -> Vec<Expr>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action97(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action110(
+        __0,
+        __temp0,
+    )
+}
+
+fn __action117<
+    'input,
+>(
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action39(
-        input,
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action92(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action15(
-        input,
+    __action27(
         __0,
-        __1,
         __temp0,
+        __1,
         __2,
     )
 }
 
-#[allow(unused_variables)]
-fn __action54<
+fn __action118<
     'input,
 >(
-    input: &'input str,
-    __0: (usize, Identifier, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, ::std::vec::Vec<Expr>, usize),
-    __3: (usize, &'input str, usize),
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, ::std::vec::Vec<Identifier>, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Box<Expr>, usize),
 ) -> Box<Expr>
 {
-    let __start0 = __2.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action40(
-        input,
-        __2,
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action93(
+        __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action15(
-        input,
+    __action27(
         __0,
-        __1,
         __temp0,
+        __2,
         __3,
     )
 }
 
 pub trait __ToTriple<'input, > {
     type Error;
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),Self::Error>;
+    fn to_triple(value: Self) -> Result<(usize,Tok<'input>,usize),Self::Error>;
 }
 
-impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize) {
-    type Error = &'static str;
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+impl<'input, > __ToTriple<'input, > for (usize, Tok<'input>, usize) {
+    type Error = LexicalError;
+    fn to_triple(value: Self) -> Result<(usize,Tok<'input>,usize),LexicalError> {
         Ok(value)
     }
 }
-impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize),&'static str> {
-    type Error = &'static str;
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize),&'static str> {
+impl<'input, > __ToTriple<'input, > for Result<(usize, Tok<'input>, usize),LexicalError> {
+    type Error = LexicalError;
+    fn to_triple(value: Self) -> Result<(usize,Tok<'input>,usize),LexicalError> {
         value
     }
 }
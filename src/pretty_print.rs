@@ -3,10 +3,35 @@ use bytecode::Value;
 pub fn pretty_print(value: &Value) -> String {
     match value {
         Value::Int(value) => format!("{}", value),
+        Value::BigInt(value) => format!("{}", value),
         Value::Float(value) => format!("{}", value),
         Value::Bool(value) => format!("{}", value),
-        Value::Pointer(_) => "<pointer>".to_owned(),
-        Value::Bytes(_) => "<bytes>".to_owned(),
-        Value::Function(_, _, _) => "<function>".to_owned(),
+        Value::String(value) => format!("\"{}\"", value),
+        Value::List(items) => {
+            let items: Vec<String> = items.iter().map(pretty_print).collect();
+            format!("[{}]", items.join(", "))
+        }
+        Value::Map(entries) => {
+            let entries: Vec<String> = entries
+                .iter()
+                .map(|(k, v)| format!("\"{}\": {}", k, pretty_print(v)))
+                .collect();
+            format!("{{{}}}", entries.join(", "))
+        }
+        Value::Function(_) | Value::Builtin(_) | Value::NativeFunction(_) => "<function>".to_owned(),
+        Value::Range(range) if range.inclusive => format!("{}..={}", range.start, range.end),
+        Value::Range(range) => format!("{}..{}", range.start, range.end),
+        Value::Iterator(_) => "<iterator>".to_owned(),
+        Value::Generator(_) => "<generator>".to_owned(),
+        Value::Nil => "nil".to_owned(),
+    }
+}
+
+/// How `print` shows a value, which is as `pretty_print` does but without
+/// quotes around strings
+pub fn display(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.to_string(),
+        value => pretty_print(value),
     }
 }
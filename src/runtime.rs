@@ -1,14 +1,28 @@
-extern crate lalrpop_util;
+use std::cell::RefCell;
+use std::io::Write;
+use std::rc::Rc;
 
+use bytecode::{Builtin, NativeFn, NativeFunction, Value};
 use codegen;
+use convert::IntoNativeFunction;
+use lexer::Lexer;
 use parser;
-use semantic_analysis::{AnalysisError, Analyzer};
-use vm::{EvaluationError, ReturnValue, VM};
+use semantic_analysis::{AnalysisError, AnalysisWarning, Analyzer};
+use stdlib;
+use stdlib::fs::Capabilities;
+use stdlib::random::Rng;
+use stdlib::time::{Clock, SharedClock};
+use vm::{EvaluationError, Output, ReturnValue, VM};
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct Runtime {
     analyzer: Analyzer,
     vm: VM,
+    warnings: Vec<AnalysisWarning>,
+    // shared with the file functions, which check it on every call
+    pub(crate) capabilities: Rc<RefCell<Capabilities>>,
+    pub(crate) rng: Rc<RefCell<Rng>>,
+    pub(crate) clock: SharedClock,
 }
 
 #[derive(Debug, PartialEq)]
@@ -19,34 +33,151 @@ pub enum EvaluationResult {
     Success(ReturnValue),
 }
 
+impl Default for Runtime {
+    fn default() -> Runtime {
+        Runtime::new()
+    }
+}
+
 impl Runtime {
     pub fn new() -> Runtime {
-        Runtime::default()
+        let mut runtime = Runtime {
+            analyzer: Analyzer::new(),
+            vm: VM::new(),
+            warnings: Vec::new(),
+            capabilities: Rc::new(RefCell::new(Capabilities::default())),
+            rng: Rc::new(RefCell::new(Rng::default())),
+            clock: SharedClock::default(),
+        };
+        runtime.define_builtin("print", Builtin::Print);
+        runtime.define_builtin("println", Builtin::Println);
+        stdlib::register(&mut runtime);
+        runtime
+    }
+
+    fn define_builtin(&mut self, name: &str, builtin: Builtin) {
+        self.define_global(name, None, Value::Builtin(builtin));
+    }
+
+    fn define_global(&mut self, name: &str, arity: Option<usize>, value: Value) {
+        self.analyzer.declare_builtin(name, arity);
+        let index = self.vm.globals.resolve(name);
+        self.vm.globals.values[index as usize] = Some(value);
+    }
+
+    /// Defines a global const that scripts can't reassign
+    pub fn define_const(&mut self, name: &str, value: Value) {
+        self.define_global(name, None, value);
+    }
+
+    /// Makes a Rust function callable from scripts as a global const, which
+    /// is called with exactly `arity` arguments
+    pub fn register_fn<F>(&mut self, name: &str, arity: usize, function: F)
+    where
+        F: Fn(&[Value]) -> Result<Value, EvaluationError> + 'static,
+    {
+        let function = move |_: &mut VM, args: &[Value]| function(args);
+        self.define_native(name, Some(arity), Rc::new(function));
+    }
+
+    /// Makes a Rust function callable from scripts with any number of arguments
+    pub fn register_variadic_fn<F>(&mut self, name: &str, function: F)
+    where
+        F: Fn(&[Value]) -> Result<Value, EvaluationError> + 'static,
+    {
+        let function = move |_: &mut VM, args: &[Value]| function(args);
+        self.define_native(name, None, Rc::new(function));
+    }
+
+    /// Registers a Rust function that is also given the VM, so that it can
+    /// call functions it's passed with `VM::call`, and that is called with
+    /// exactly `arity` arguments unless that's `None`
+    pub fn register_higher_order_fn<F>(&mut self, name: &str, arity: Option<usize>, function: F)
+    where
+        F: Fn(&mut VM, &[Value]) -> Result<Value, EvaluationError> + 'static,
+    {
+        self.define_native(name, arity, Rc::new(function));
+    }
+
+    /// Registers a Rust function whose arguments and result are converted
+    /// to and from values, such as `fn(i64, String) -> Result<f64, E>`
+    pub fn register_typed_fn<Args, F>(&mut self, name: &str, function: F)
+    where
+        F: IntoNativeFunction<Args>,
+    {
+        self.define_native(name, Some(F::ARITY), function.into_native());
+    }
+
+    fn define_native(&mut self, name: &str, arity: Option<usize>, function: Rc<NativeFn>) {
+        let native = NativeFunction {
+            name: Rc::from(name),
+            arity,
+            function,
+        };
+        self.define_global(name, arity, Value::NativeFunction(native));
+    }
+
+    pub fn with_max_call_depth(max_call_depth: usize) -> Runtime {
+        let mut runtime = Runtime::new();
+        runtime.vm.max_call_depth = max_call_depth;
+        runtime
+    }
+
+    pub fn set_trace(&mut self, trace: bool) {
+        self.vm.trace = trace;
+    }
+
+    /// Replaces where `print` and `println` write to, which is stdout by default
+    pub fn set_output(&mut self, output: Box<dyn Write>) {
+        self.vm.output = Output(output);
+    }
+
+    /// Replaces what the file functions may access, which is nothing by default
+    pub fn set_capabilities(&mut self, capabilities: Capabilities) {
+        *self.capabilities.borrow_mut() = capabilities;
+    }
+
+    /// Restarts the random functions from a seed, so that they give the same
+    /// numbers every run; they're seeded from the time otherwise
+    pub fn set_seed(&mut self, seed: u64) {
+        *self.rng.borrow_mut() = Rng::new(seed);
+    }
+
+    /// Replaces where `now` and `monotonic` get the time, which is the
+    /// system clock by default
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        *self.clock.0.borrow_mut() = clock;
+    }
+
+    /// The warnings the analyzer gave for the last source evaluated
+    pub fn warnings(&self) -> &[AnalysisWarning] {
+        &self.warnings
     }
 
     pub fn evaluate(&mut self, source: &str) -> EvaluationResult {
+        self.warnings.clear();
         match source {
             ":env\n" => {
                 let mut env_string = String::new();
-                for (k, v) in &self.vm.symbol_table {
-                    env_string.push_str(&format!("{:?}: {:?}\n", k, self.vm.heap[v.0]));
+                let globals = &self.vm.globals;
+                for (k, v) in globals.names.iter().zip(&globals.values) {
+                    if let Some(v) = v {
+                        env_string.push_str(&format!("{:?}: {:?}\n", k, v));
+                    }
                 }
 
                 EvaluationResult::Success(ReturnValue::DisplayString(env_string))
             }
 
             _ => {
-                let parse_result = parser::ExprOrStmtParser::new().parse(source);
+                let parse_result = parser::ProgramParser::new().parse(Lexer::new(source));
                 match parse_result {
-                    Ok(node) => {
-                        let program = vec![*node];
+                    Ok(program) => {
                         let analysis = self.analyzer.analyze(&program);
+                        self.warnings = analysis.warnings;
 
                         if analysis.errors.is_empty() {
-                            if !analysis.warnings.is_empty() {
-                                println!("warning: {:?}", analysis.warnings)
-                            }
-                            let bytecode = codegen::generate(program);
+                            let bytecode = codegen::generate(program, &mut self.vm.globals);
                             let result = self.vm.run(bytecode);
                             match result {
                                 Ok(r) => EvaluationResult::Success(r),
@@ -64,9 +195,45 @@ impl Runtime {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
-    use bytecode::Value;
+    use ast::{Expr, Identifier};
+    use bytecode::{Chunk, Globals, Handler, Instruction, Value};
+    use num_bigint::BigInt;
+    use convert::{ConversionError, FromValue, IntoValue};
+    use pretty_print::pretty_print;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::io;
+    use std::rc::Rc;
+    use std::str::FromStr;
+
+    /// Evaluates a source in the given runtime and checks the value it gives,
+    /// as the REPL would show it, or `nil` for a result the REPL doesn't echo
+    pub(crate) fn assert_evaluates_in(runtime: &mut Runtime, source: &str, expected: &str) {
+        let shown = match runtime.evaluate(source) {
+            EvaluationResult::Success(ReturnValue::Value(v)) => pretty_print(&v),
+            EvaluationResult::Success(ReturnValue::DisplayString(s)) => s,
+            EvaluationResult::Success(ReturnValue::Empty) => "nil".to_string(),
+            result => panic!("{}: {:?}", source, result),
+        };
+        assert_eq!(shown, expected, "{}", source);
+    }
+
+    /// Evaluates a source in a new runtime and checks the value it gives
+    pub(crate) fn assert_evaluates(source: &str, expected: &str) {
+        assert_evaluates_in(&mut Runtime::new(), source, expected);
+    }
+
+    /// Evaluates a source in a new runtime and checks the error it fails with
+    pub(crate) fn assert_fails(source: &str, expected: EvaluationError) {
+        assert_eq!(
+            Runtime::new().evaluate(source),
+            EvaluationResult::EvaluationError(expected),
+            "{}",
+            source
+        );
+    }
 
     #[test]
     fn test_int() {
@@ -132,4 +299,1254 @@ mod tests {
             EvaluationResult::Success(ReturnValue::Value(Value::Int(3)))
         );
     }
+
+    #[test]
+    fn test_function_arguments() {
+        let mut runtime = Runtime::new();
+        let _ = runtime.evaluate("let a = 5");
+        let _ = runtime.evaluate("let b = 3");
+        let _ = runtime.evaluate("let first = (x y) => x;");
+        assert_eq!(
+            runtime.evaluate("first(a b)"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(5)))
+        );
+    }
+
+    #[test]
+    fn test_stack_overflow() {
+        let mut runtime = Runtime::with_max_call_depth(64);
+        let _ = runtime.evaluate("let f = () => f();");
+        assert_eq!(
+            runtime.evaluate("f()"),
+            EvaluationResult::EvaluationError(EvaluationError::StackOverflow(64))
+        );
+        assert_eq!(
+            runtime.evaluate("3"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(3)))
+        );
+    }
+
+    #[test]
+    fn test_division_by_zero() {
+        let mut runtime = Runtime::new();
+        assert_eq!(
+            runtime.evaluate("0 / 0"),
+            EvaluationResult::EvaluationError(EvaluationError::DivisionByZero)
+        );
+        assert_eq!(
+            runtime.evaluate("0.0 / 0.0"),
+            EvaluationResult::EvaluationError(EvaluationError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_int_promotion() {
+        let big = |digits: &str| Value::BigInt(BigInt::from_str(digits).unwrap());
+        let cases = vec![
+            ("2147483647 + 1", Value::Int(2147483648)),
+            ("9223372036854775807 + 1", big("9223372036854775808")),
+            ("-9223372036854775808 - 1", big("-9223372036854775809")),
+            ("4294967296 * 4294967296", big("18446744073709551616")),
+            ("9223372036854775807 + 1 - 1", Value::Int(9223372036854775807)),
+            ("-9223372036854775808 ~/ -1", big("9223372036854775808")),
+            ("-9223372036854775808 / -1", big("9223372036854775808")),
+            ("-9223372036854775808 % -1", Value::Int(0)),
+            ("2 ** 64", big("18446744073709551616")),
+            ("2 ** 64 ~/ 2 ** 32", Value::Int(4294967296)),
+            ("2 ** 64 % 10", Value::Int(6)),
+            ("-(2 ** 64) % 10", Value::Int(4)),
+            ("2 ** 64 * 0.5", Value::Float(9223372036854775808.0)),
+            // the exponent can be as big as it likes when the result stays small
+            ("1 ** (2 ** 64)", Value::Int(1)),
+            ("0 ** (2 ** 64)", Value::Int(0)),
+            ("(0 - 1) ** (2 ** 64 + 1)", Value::Int(-1)),
+        ];
+        for (source, expected) in cases {
+            let mut runtime = Runtime::new();
+            assert_eq!(
+                runtime.evaluate(source),
+                EvaluationResult::Success(ReturnValue::Value(expected)),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_stack_underflow() {
+        let mut vm = VM::new();
+        assert_eq!(
+            vm.run(Chunk {
+                code: vec![Instruction::Constant(0), Instruction::Add],
+                constants: vec![Value::Int(1)],
+                handlers: Vec::new(),
+                generator: false,
+            }),
+            Err(EvaluationError::StackUnderflow)
+        );
+        assert_eq!(
+            vm.run(Chunk {
+                code: vec![Instruction::Call(0)],
+                constants: vec![],
+                handlers: Vec::new(),
+                generator: false,
+            }),
+            Err(EvaluationError::StackUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_conformance() {
+        let cases = vec![
+            ("7 + 2", Value::Int(9)),
+            ("2 + 7", Value::Int(9)),
+            ("7 - 2", Value::Int(5)),
+            ("2 - 7", Value::Int(-5)),
+            ("7 * 2", Value::Int(14)),
+            ("2 * 7", Value::Int(14)),
+            ("7 / 2", Value::Int(3)),
+            ("2 / 7", Value::Int(0)),
+            ("7.5 + 2.5", Value::Float(10.0)),
+            ("2.5 + 7.5", Value::Float(10.0)),
+            ("7.5 - 2.5", Value::Float(5.0)),
+            ("2.5 - 7.5", Value::Float(-5.0)),
+            ("7.5 * 2.5", Value::Float(18.75)),
+            ("2.5 * 7.5", Value::Float(18.75)),
+            ("7.5 / 2.5", Value::Float(3.0)),
+            ("2.5 / 0.5", Value::Float(5.0)),
+            ("7 + 2.5", Value::Float(9.5)),
+            ("2.5 + 7", Value::Float(9.5)),
+            ("7 - 2.5", Value::Float(4.5)),
+            ("2.5 - 7", Value::Float(-4.5)),
+            ("7 * 2.5", Value::Float(17.5)),
+            ("2.5 * 7", Value::Float(17.5)),
+            ("5 / 2.5", Value::Float(2.0)),
+            ("2.5 / 5", Value::Float(0.5)),
+            ("7 % 3", Value::Int(1)),
+            ("3 % 7", Value::Int(3)),
+            ("-7 % 3", Value::Int(2)),
+            ("7 % -3", Value::Int(-2)),
+            ("7 ~/ 2", Value::Int(3)),
+            ("2 ~/ 7", Value::Int(0)),
+            ("-7 ~/ 2", Value::Int(-4)),
+            ("7 ~/ -2", Value::Int(-4)),
+            ("2 ** 3", Value::Int(8)),
+            ("3 ** 2", Value::Int(9)),
+            ("7.5 % 2.5", Value::Float(0.0)),
+            ("2.5 % 7.5", Value::Float(2.5)),
+            ("-7.5 % 2.0", Value::Float(0.5)),
+            ("7.5 ~/ 2.0", Value::Float(3.0)),
+            ("2.0 ~/ 7.5", Value::Float(0.0)),
+            ("-7.5 ~/ 2.0", Value::Float(-4.0)),
+            ("2.5 ** 2.0", Value::Float(6.25)),
+            ("4.0 ** 0.5", Value::Float(2.0)),
+            ("7 % 2.5", Value::Float(2.0)),
+            ("2.5 % 7", Value::Float(2.5)),
+            ("7 ~/ 2.5", Value::Float(2.0)),
+            ("2.5 ~/ 7", Value::Float(0.0)),
+            ("2 ** 0.5", Value::Float(2f64.sqrt())),
+            ("0.5 ** 2", Value::Float(0.25)),
+            ("10 - 4 - 3", Value::Int(3)),
+            ("24 / 4 / 2", Value::Int(3)),
+            ("2 + 3 * 4", Value::Int(14)),
+            ("10 - 6 / 2", Value::Int(7)),
+            ("(10 - 6) / 2", Value::Int(2)),
+        ];
+        for (source, expected) in cases {
+            let mut runtime = Runtime::new();
+            assert_eq!(
+                runtime.evaluate(source),
+                EvaluationResult::Success(ReturnValue::Value(expected)),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_unary_minus() {
+        let cases = vec![
+            ("-3", Value::Int(-3)),
+            ("-3.5", Value::Float(-3.5)),
+            ("--3", Value::Int(3)),
+            ("2 - -3", Value::Int(5)),
+            ("2 * -3", Value::Int(-6)),
+            ("-2 * 3", Value::Int(-6)),
+            ("-(2 - 5)", Value::Int(3)),
+            ("-9223372036854775808", Value::Int(-9223372036854775808)),
+        ];
+        for (source, expected) in cases {
+            let mut runtime = Runtime::new();
+            assert_eq!(
+                runtime.evaluate(source),
+                EvaluationResult::Success(ReturnValue::Value(expected)),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_negating_variables() {
+        let mut runtime = Runtime::new();
+        let _ = runtime.evaluate("let x = 4");
+        assert_eq!(
+            runtime.evaluate("x * -1"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(-4)))
+        );
+        assert_eq!(
+            runtime.evaluate("-x"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(-4)))
+        );
+        let _ = runtime.evaluate("let min = -9223372036854775808");
+        assert_eq!(
+            runtime.evaluate("-min"),
+            EvaluationResult::Success(ReturnValue::Value(Value::BigInt(
+                BigInt::from_str("9223372036854775808").unwrap()
+            )))
+        );
+    }
+
+    #[test]
+    fn test_big_int_literal() {
+        let mut runtime = Runtime::new();
+        assert_eq!(
+            runtime.evaluate("123456789012345678901234567890"),
+            EvaluationResult::Success(ReturnValue::Value(Value::BigInt(
+                BigInt::from_str("123456789012345678901234567890").unwrap()
+            )))
+        );
+        assert_eq!(
+            runtime.evaluate("-123456789012345678901234567890 + 123456789012345678901234567890"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(0)))
+        );
+    }
+
+    #[test]
+    fn test_exponent_precedence() {
+        let cases = vec![
+            ("2 ** 3 ** 2", Value::Int(512)),
+            ("2 * 3 ** 2", Value::Int(18)),
+            ("-2 ** 2", Value::Int(-4)),
+            ("(-2) ** 2", Value::Int(4)),
+            ("2 ** -1.0", Value::Float(0.5)),
+            ("10 % 4 * 3", Value::Int(6)),
+            ("10 ~/ 4 * 3", Value::Int(6)),
+        ];
+        for (source, expected) in cases {
+            let mut runtime = Runtime::new();
+            assert_eq!(
+                runtime.evaluate(source),
+                EvaluationResult::Success(ReturnValue::Value(expected)),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_modulo_and_exponent_errors() {
+        let cases = vec![
+            ("1 % 0", EvaluationError::DivisionByZero),
+            ("1.5 % 0.0", EvaluationError::DivisionByZero),
+            ("1 ~/ 0", EvaluationError::DivisionByZero),
+            ("1.5 ~/ 0", EvaluationError::DivisionByZero),
+            ("2 ** -1", EvaluationError::NegativeExponent),
+            ("2 ** -(2 ** 64)", EvaluationError::NegativeExponent),
+            ("2 ** (2 ** 64)", EvaluationError::IntegerOverflow),
+            // results too big to keep are refused before any memory is used for them
+            ("(2 ** 64) ** 4000000000", EvaluationError::IntegerOverflow),
+            ("3 ** 100000000", EvaluationError::IntegerOverflow),
+        ];
+        for (source, expected) in cases {
+            assert_fails(source, expected);
+        }
+    }
+
+    #[test]
+    fn test_numeric_literals() {
+        let cases = vec![
+            ("0xff", Value::Int(255)),
+            ("0XFF", Value::Int(255)),
+            ("0b1010", Value::Int(10)),
+            ("0o17", Value::Int(15)),
+            ("1_000_000", Value::Int(1000000)),
+            ("0xffff_ffff", Value::Int(4294967295)),
+            ("-0x10", Value::Int(-16)),
+            ("-0x8000_0000_0000_0000", Value::Int(-9223372036854775808)),
+            ("0x1_0000_0000_0000_0000", Value::BigInt(BigInt::from_str("18446744073709551616").unwrap())),
+            ("1e3", Value::Float(1000.0)),
+            ("1e-9", Value::Float(1e-9)),
+            ("2.5E+2", Value::Float(250.0)),
+            ("1_000.000_5", Value::Float(1000.0005)),
+            (".5", Value::Float(0.5)),
+            ("5.", Value::Float(5.0)),
+            (".5e1", Value::Float(5.0)),
+            ("5.e1", Value::Float(50.0)),
+            ("-.5", Value::Float(-0.5)),
+        ];
+        for (source, expected) in cases {
+            let mut runtime = Runtime::new();
+            assert_eq!(
+                runtime.evaluate(source),
+                EvaluationResult::Success(ReturnValue::Value(expected)),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_numeric_literals() {
+        let mut runtime = Runtime::new();
+        match runtime.evaluate("1e999") {
+            EvaluationResult::ParseError(e) => assert!(e.contains("float literal is out of range")),
+            result => panic!("expected a parse error, got {:?}", result),
+        }
+        for source in &["0x", "0b102", "1__000", "1_", "0x_ff"] {
+            let mut runtime = Runtime::new();
+            match runtime.evaluate(source) {
+                EvaluationResult::ParseError(_) => (),
+                result => panic!("expected a parse error for {}, got {:?}", source, result),
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitwise_operators() {
+        let cases = vec![
+            ("12 & 10", Value::Int(8)),
+            ("12 | 10", Value::Int(14)),
+            ("12 ^ 10", Value::Int(6)),
+            ("~5", Value::Int(-6)),
+            ("~-1", Value::Int(0)),
+            ("-~5", Value::Int(6)),
+            ("1 << 4", Value::Int(16)),
+            ("256 >> 4", Value::Int(16)),
+            ("-8 >> 1", Value::Int(-4)),
+            ("-1 >> 100", Value::Int(-1)),
+            ("1 << 64", Value::BigInt(BigInt::from_str("18446744073709551616").unwrap())),
+            ("0 << 100000000000", Value::Int(0)),
+            ("(1 << 64) >> 60", Value::Int(16)),
+            ("(1 << 64) | 1 & 0", Value::BigInt(BigInt::from_str("18446744073709551616").unwrap())),
+            ("~(1 << 64)", Value::BigInt(BigInt::from_str("-18446744073709551617").unwrap())),
+            ("-(1 << 64) & 0xff", Value::Int(0)),
+            ("6 & 3 | 8", Value::Int(10)),
+            ("5 ^ 1 & 3", Value::Int(4)),
+            ("1 | 6 ^ 3", Value::Int(5)),
+            ("1 << 2 + 1", Value::Int(8)),
+            ("2 * 3 & 4", Value::Int(4)),
+            ("~2 ** 2", Value::Int(-5)),
+        ];
+        for (source, expected) in cases {
+            let mut runtime = Runtime::new();
+            assert_eq!(
+                runtime.evaluate(source),
+                EvaluationResult::Success(ReturnValue::Value(expected)),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_bitwise_errors() {
+        let cases = vec![
+            (
+                "1.5 & 1",
+                EvaluationError::InvalidOperation("Can't take the bitwise and of 1.5 and 1".to_string()),
+            ),
+            (
+                "true | 1",
+                EvaluationError::InvalidOperation("Can't take the bitwise or of true and 1".to_string()),
+            ),
+            (
+                "1 ^ 2.0",
+                EvaluationError::InvalidOperation("Can't take the bitwise xor of 1 and 2".to_string()),
+            ),
+            (
+                "1.0 << 2",
+                EvaluationError::InvalidOperation("Can't shift 1 by 2".to_string()),
+            ),
+            (
+                "~1.5",
+                EvaluationError::InvalidOperation("Can't take the bitwise not of 1.5".to_string()),
+            ),
+            ("1 << -1", EvaluationError::NegativeShiftCount),
+            ("1 >> -1", EvaluationError::NegativeShiftCount),
+            // results too big to keep are refused before any memory is used for them
+            ("1 << 100000000000", EvaluationError::IntegerOverflow),
+            ("(2 ** 64) << 16777216", EvaluationError::IntegerOverflow),
+        ];
+        for (source, expected) in cases {
+            assert_fails(source, expected);
+        }
+    }
+
+    #[test]
+    fn test_comments() {
+        let cases = vec![
+            ("1 + 2 // a comment", Value::Int(3)),
+            ("1 /* inline */ + 2", Value::Int(3)),
+            ("/* outer /* inner */ still outer */ 4", Value::Int(4)),
+            ("//// not a doc comment\n5", Value::Int(5)),
+            ("6 / 2 /* division still works */", Value::Int(3)),
+            ("7 ~/ 2 // floor division", Value::Int(3)),
+        ];
+        for (source, expected) in cases {
+            let mut runtime = Runtime::new();
+            assert_eq!(
+                runtime.evaluate(source),
+                EvaluationResult::Success(ReturnValue::Value(expected)),
+                "{}",
+                source
+            );
+        }
+
+        let mut runtime = Runtime::new();
+        match runtime.evaluate("1 /* /* */") {
+            EvaluationResult::ParseError(e) => assert!(e.contains("unterminated block comment")),
+            result => panic!("expected a parse error, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_doc_comments() {
+        let program = parser::ProgramParser::new()
+            .parse(Lexer::new("/// The answer\n/// to everything\nlet answer = 42"))
+            .unwrap();
+        match program[0] {
+            Expr::Documented(ref doc, ref stmt) => {
+                assert_eq!(doc, "The answer\nto everything");
+                match **stmt {
+                    Expr::LetAssignment(ref ident, _) => assert_eq!(ident.value, "answer"),
+                    ref other => panic!("expected a let assignment, got {:?}", other),
+                }
+            }
+            ref other => panic!("expected a documented statement, got {:?}", other),
+        }
+
+        let mut runtime = Runtime::new();
+        let _ = runtime.evaluate("/// three\nlet x = 3");
+        assert_eq!(
+            runtime.evaluate("x"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(3)))
+        );
+    }
+
+    #[test]
+    fn test_operators_without_spaces() {
+        let mut runtime = Runtime::new();
+        let _ = runtime.evaluate("let a = 6");
+        let _ = runtime.evaluate("let b = 3");
+        assert_eq!(
+            runtime.evaluate("a|b"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(7)))
+        );
+        assert_eq!(
+            runtime.evaluate("a^b-1"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(4)))
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let cases = vec![
+            ("{ 1; 2 }", Value::Int(2)),
+            ("{ let x = 2; x * 3 }", Value::Int(6)),
+            ("let a = 1; a + 1", Value::Int(2)),
+            ("{ let x = 1; { let y = x + 1; y * 10 } }", Value::Int(20)),
+            ("let f = (x) => { let y = x * 2; y + 1 }; let n = 4; f(n)", Value::Int(9)),
+            ("let g = { let k = 3; (x) => x * k }; let n = 5; g(n)", Value::Int(15)),
+        ];
+        for (source, expected) in cases {
+            let mut runtime = Runtime::new();
+            assert_eq!(
+                runtime.evaluate(source),
+                EvaluationResult::Success(ReturnValue::Value(expected)),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_block_scoping() {
+        let mut runtime = Runtime::new();
+        let _ = runtime.evaluate("let x = 1");
+        let _ = runtime.evaluate("let y = 1");
+        assert_eq!(
+            runtime.evaluate("{ let x = 2; y = 3; x }"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(2)))
+        );
+        // the shadowing declaration ends with its block, but the assignment persists
+        assert_eq!(
+            runtime.evaluate("x + y"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(4)))
+        );
+
+        // names declared in a block are not visible after it
+        let mut runtime = Runtime::new();
+        assert_eq!(
+            runtime.evaluate("{ let z = 1; z }; z"),
+            EvaluationResult::SemanticAnalysisError(vec![AnalysisError::UnboundIdentifier(
+                Identifier::new("z".to_string())
+            )])
+        );
+
+        // redeclaring in the same scope is still an error
+        let mut runtime = Runtime::new();
+        assert_eq!(
+            runtime.evaluate("{ let w = 1; let w = 2; w }"),
+            EvaluationResult::SemanticAnalysisError(vec![AnalysisError::RedeclaringIdentifier(
+                Identifier::new("w".to_string())
+            )])
+        );
+    }
+
+    #[test]
+    fn test_shadowed_function_arity() {
+        // a shadowing function's arity only applies within its block
+        let mut runtime = Runtime::new();
+        assert_eq!(
+            runtime.evaluate("let f = (a) => a; { let f = (a b) => a; f(1 2) }; f(1)"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(1)))
+        );
+        assert_eq!(
+            runtime.evaluate("{ let f = 2; 0 }; f(1 2)"),
+            EvaluationResult::SemanticAnalysisError(vec![
+                AnalysisError::FunctionCallWithIncorrectArity(Identifier::new("f".to_string()))
+            ])
+        );
+        // and so does the arity of a function a parameter shadows
+        assert_eq!(
+            runtime.evaluate("let h = (x) => x; let g = (h) => h(1 2); g(((a b) => a + b))"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(3)))
+        );
+    }
+
+    #[test]
+    fn test_calls_without_a_value() {
+        // a function whose body ends in a statement returns nil
+        let mut runtime = Runtime::new();
+        let _ = runtime.evaluate("let t = 0; let bump = () => { t = t + 1 }");
+        assert_eq!(
+            runtime.evaluate("bump(); 3"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(3)))
+        );
+        assert_eq!(runtime.evaluate("let r = bump(); r"), EvaluationResult::Success(ReturnValue::Empty));
+        assert_eq!(
+            runtime.evaluate("for i in 0..3 { bump() }; t"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(5)))
+        );
+        assert_eq!(
+            runtime.evaluate("map((0..2) ((x) => { let y = x }))"),
+            EvaluationResult::Success(ReturnValue::Value(Value::List(Rc::new(vec![Value::Nil, Value::Nil]))))
+        );
+    }
+
+    #[test]
+    fn test_variable_slots() {
+        let program = parser::ProgramParser::new()
+            .parse(Lexer::new(
+                "let g = 1; let f = (a b) => { let c = a; { let d = b; d }; { let e = g; e }; c + g }",
+            ))
+            .unwrap();
+        let mut globals = Globals::new();
+        let function = Chunk {
+            code: vec![
+                Instruction::GetLocal(0),
+                Instruction::SetLocal(2),
+                Instruction::GetLocal(1),
+                Instruction::SetLocal(3),
+                Instruction::GetLocal(3),
+                Instruction::Pop,
+                // the slot of a variable is reused once its block has ended
+                Instruction::GetGlobal(0),
+                Instruction::SetLocal(3),
+                Instruction::GetLocal(3),
+                Instruction::Pop,
+                Instruction::GetLocal(2),
+                Instruction::GetGlobal(0),
+                Instruction::Add,
+            ],
+            constants: vec![],
+            handlers: Vec::new(),
+            generator: false,
+        };
+        assert_eq!(
+            codegen::generate(program, &mut globals),
+            Chunk {
+                code: vec![
+                    Instruction::Constant(0),
+                    Instruction::SetGlobal(0),
+                    Instruction::MakeFunction(2, Rc::new(function), vec![]),
+                    Instruction::SetGlobal(1),
+                ],
+                constants: vec![Value::Int(1)],
+                handlers: Vec::new(),
+                generator: false,
+            }
+        );
+        assert_eq!(globals.names, vec!["g", "f"]);
+    }
+
+    #[test]
+    fn test_closures() {
+        let cases = vec![
+            ("{ let k = 3; let times = (x) => x * k; let n = 5; times(n) }", Value::Int(15)),
+            (
+                "let make = (a) => { let inner = (b) => { let innermost = (c) => a + b * c; innermost(b) }; inner(a) }; \
+                 let n = 3; make(n)",
+                Value::Int(12),
+            ),
+            (
+                "let total = 0; let add = (x) => { total = total + x; total }; let n = 4; add(n); add(n); total",
+                Value::Int(8),
+            ),
+            // closures share the variables they capture with the function that
+            // declared them, and with each other
+            (
+                "let mk = () => { let c = 0; let inc = () => { c = c + 1; c }; inc(); inc(); c }; mk()",
+                Value::Int(2),
+            ),
+            ("let f = () => { let x = 1; let g = () => x; x = 2; g() }; f()", Value::Int(2)),
+            (
+                "let f = () => { let x = 1; let g = () => { let h = () => { x = x + 10 }; h() }; g(); x }; f()",
+                Value::Int(11),
+            ),
+            (
+                "let counter = () => { let c = 0; () => { c = c + 1; c } }; \
+                 let a = counter(); let b = counter(); a(); a(); b(); a()",
+                Value::Int(3),
+            ),
+            // each run of a loop's body has variables of its own
+            (
+                "let gen = () => { for i in 0..3 { yield () => i } }; let keep = (f) => f; \
+                 reduce(map(gen() keep) ((total f) => total * 10 + f()) 0)",
+                Value::Int(12),
+            ),
+            // local functions can call themselves
+            (
+                "let f = () => { let sum = (n) => reduce(map(range(n) ((k) => sum(k) + 1)) ((a b) => a + b) 0); sum(4) }; f()",
+                Value::Int(15),
+            ),
+        ];
+        for (source, expected) in cases {
+            let mut runtime = Runtime::new();
+            assert_eq!(
+                runtime.evaluate(source),
+                EvaluationResult::Success(ReturnValue::Value(expected)),
+                "{}",
+                source
+            );
+        }
+
+        // the analyzer only knows the arity of names bound directly to a function
+        let mut runtime = Runtime::new();
+        assert_eq!(
+            runtime.evaluate("let g = { (x) => x }; g()"),
+            EvaluationResult::EvaluationError(EvaluationError::IncorrectArity(1, 0))
+        );
+        let mut runtime = Runtime::with_max_call_depth(64);
+        assert_eq!(
+            runtime.evaluate("let f2 = () => { let rec = (n) => rec(n); rec(1) }; f2()"),
+            EvaluationResult::EvaluationError(EvaluationError::StackOverflow(64))
+        );
+    }
+
+    #[test]
+    fn test_constant_pool() {
+        let program = parser::ProgramParser::new()
+            .parse(Lexer::new("let s = \"hi\"; let f = () => \"hi\"; 2 + 2 + 2.0 + 2.0 + 2"))
+            .unwrap();
+        let chunk = codegen::generate(program, &mut Globals::new());
+        assert_eq!(
+            chunk.constants[1..],
+            [Value::Int(2), Value::Float(2.0)]
+        );
+
+        // string literals are shared between chunks
+        let function = match chunk.code[2] {
+            Instruction::MakeFunction(_, ref function, _) => function,
+            ref other => panic!("expected a function, got {:?}", other),
+        };
+        match (&chunk.constants[0], &function.constants[0]) {
+            (Value::String(a), Value::String(b)) => assert!(Rc::ptr_eq(a, b)),
+            other => panic!("expected two strings, got {:?}", other),
+        }
+    }
+
+    // an output that can still be read after it's been given to a runtime
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedBuffer {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.borrow().clone()).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_print() {
+        let buffer = SharedBuffer::default();
+        let mut runtime = Runtime::new();
+        runtime.set_output(Box::new(buffer.clone()));
+
+        assert_eq!(
+            runtime.evaluate("let x = 2; print(\"x is\" x); println(); println((x * 3) 2.5 true)"),
+            EvaluationResult::Success(ReturnValue::Empty)
+        );
+        let _ = runtime.evaluate("let greet = (name) => println(\"hello\" name); greet(\"world\"); 7");
+        assert_eq!(buffer.contents(), "x is 2\n6 2.5 true\nhello world\n");
+
+        // strings are still quoted when they're the result of an evaluation
+        assert_eq!(
+            runtime.evaluate("\"x\""),
+            EvaluationResult::Success(ReturnValue::DisplayString("\"x\"".to_string()))
+        );
+        assert_eq!(
+            runtime.evaluate("print = 1"),
+            EvaluationResult::SemanticAnalysisError(vec![AnalysisError::ReassigningConst(
+                Identifier::new("print".to_string())
+            )])
+        );
+        // but a program may define its own
+        assert_eq!(
+            runtime.evaluate("let print = 1; print"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(1)))
+        );
+    }
+
+    #[test]
+    fn test_warnings() {
+        // warnings are kept for the caller rather than printed
+        let buffer = SharedBuffer::default();
+        let mut runtime = Runtime::new();
+        runtime.set_output(Box::new(buffer.clone()));
+
+        assert_eq!(
+            runtime.evaluate("let x = 1; x = 2; x"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(2)))
+        );
+        assert_eq!(
+            runtime.warnings(),
+            &[AnalysisWarning::ReassigningIdentifier(Identifier::new("x".to_string()))]
+        );
+        assert_eq!(buffer.contents(), "");
+
+        let _ = runtime.evaluate("x");
+        assert!(runtime.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_call_arguments() {
+        let cases = vec![
+            ("let first = (a b) => a; first(1 2)", Value::Int(1)),
+            ("let first = (a b) => a; first((1 - 2) 3)", Value::Int(-1)),
+            ("let first = (a b) => a; first(first(4 5) 6)", Value::Int(4)),
+            ("let first = (a b) => a; let f = 7; first(f (8))", Value::Int(7)),
+            ("let first = (a b) => a; first({ let y = 9; y } 10)", Value::Int(9)),
+        ];
+        for (source, expected) in cases {
+            let mut runtime = Runtime::new();
+            assert_eq!(
+                runtime.evaluate(source),
+                EvaluationResult::Success(ReturnValue::Value(expected)),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_native_functions() {
+        let mut runtime = Runtime::new();
+        runtime.register_fn("hypot", 2, |args| match (&args[0], &args[1]) {
+            (Value::Float(x), Value::Float(y)) => Ok(Value::Float(x.hypot(*y))),
+            _ => Err(EvaluationError::InvalidOperation("hypot takes floats".to_string())),
+        });
+        let calls = Rc::new(RefCell::new(0));
+        let counter = Rc::clone(&calls);
+        runtime.register_fn("tick", 0, move |_| {
+            *counter.borrow_mut() += 1;
+            Ok(Value::Int(*counter.borrow()))
+        });
+
+        assert_eq!(
+            runtime.evaluate("hypot(3.0 4.0)"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Float(5.0)))
+        );
+        assert_eq!(
+            runtime.evaluate("let twice = () => tick() + tick(); twice()"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(3)))
+        );
+        assert_eq!(*calls.borrow(), 2);
+        assert_eq!(
+            runtime.evaluate("hypot(1 2)"),
+            EvaluationResult::EvaluationError(EvaluationError::InvalidOperation(
+                "hypot takes floats".to_string()
+            ))
+        );
+        assert_eq!(
+            runtime.evaluate("hypot(1.0)"),
+            EvaluationResult::SemanticAnalysisError(vec![
+                AnalysisError::FunctionCallWithIncorrectArity(Identifier::new("hypot".to_string()))
+            ])
+        );
+        // arity is also checked when the analyzer can't see which function is called
+        assert_eq!(
+            runtime.evaluate("let h = { hypot }; h(1.0)"),
+            EvaluationResult::EvaluationError(EvaluationError::IncorrectArity(2, 1))
+        );
+    }
+
+    #[test]
+    fn test_value_conversions() {
+        let mut scores = HashMap::new();
+        scores.insert("a".to_string(), vec![Some(1.5), None]);
+        let value = scores.clone().into_value();
+        assert_eq!(pretty_print(&value), "{\"a\": [1.5, nil]}");
+        assert_eq!(HashMap::<String, Vec<Option<f64>>>::from_value(&value), Ok(scores));
+
+        let tuple = (1, "two".to_string(), true);
+        assert_eq!(<(i32, String, bool)>::from_value(&tuple.clone().into_value()), Ok(tuple));
+        assert_eq!(f64::from_value(&Value::Int(2)), Ok(2.0));
+
+        let errors = vec![
+            (i64::from_value(&Value::Bool(true)), ConversionError::UnexpectedType("int", "bool")),
+            (
+                i32::from_value(&Value::Int(1 << 40)).map(i64::from),
+                ConversionError::OutOfRange("i32", "1099511627776".to_string()),
+            ),
+            (
+                <(i64, i64)>::from_value(&vec![1i64].into_value()).map(|(a, _)| a),
+                ConversionError::WrongLength(2, 1),
+            ),
+            (
+                Vec::<i64>::from_value(&vec![Value::Int(1), Value::Nil].into_value()).map(|v| v[0]),
+                ConversionError::UnexpectedType("int", "nil"),
+            ),
+        ];
+        for (result, expected) in errors {
+            assert_eq!(result, Err(expected));
+        }
+    }
+
+    #[test]
+    fn test_typed_native_functions() {
+        fn scale(x: i64, unit: String) -> Result<f64, String> {
+            match unit.as_str() {
+                "km" => Ok(x as f64 * 1000.0),
+                "m" => Ok(x as f64),
+                _ => Err(format!("unknown unit {}", unit)),
+            }
+        }
+
+        let mut runtime = Runtime::new();
+        runtime.register_typed_fn("scale", scale);
+        runtime.register_typed_fn("sum", |xs: Vec<i64>| Ok::<_, String>(xs.iter().sum::<i64>()));
+        runtime.register_typed_fn("pair", |a: i64, b: Option<bool>| Ok::<_, String>((a, b)));
+
+        assert_eq!(
+            runtime.evaluate("scale(3 \"km\")"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Float(3000.0)))
+        );
+        assert_eq!(
+            runtime.evaluate("scale(3 \"mi\")"),
+            EvaluationResult::EvaluationError(EvaluationError::NativeError("unknown unit mi".to_string()))
+        );
+        assert_eq!(
+            runtime.evaluate("scale(\"3\" \"km\")"),
+            EvaluationResult::EvaluationError(EvaluationError::ConversionError(
+                ConversionError::UnexpectedType("int", "string")
+            ))
+        );
+        assert_eq!(
+            runtime.evaluate("sum(pair(4 nil))"),
+            EvaluationResult::EvaluationError(EvaluationError::ConversionError(
+                ConversionError::UnexpectedType("int", "nil")
+            ))
+        );
+        assert_eq!(
+            runtime.evaluate("sum(pair(4 5))"),
+            EvaluationResult::EvaluationError(EvaluationError::ConversionError(
+                ConversionError::UnexpectedType("bool", "int")
+            ))
+        );
+        assert_eq!(
+            runtime.evaluate("sum((0..9223372036854775807))"),
+            EvaluationResult::EvaluationError(EvaluationError::ConversionError(
+                ConversionError::OutOfRange("list", "0..9223372036854775807".to_string())
+            ))
+        );
+        assert_eq!(
+            runtime.evaluate("scale(1)"),
+            EvaluationResult::SemanticAnalysisError(vec![
+                AnalysisError::FunctionCallWithIncorrectArity(Identifier::new("scale".to_string()))
+            ])
+        );
+    }
+
+    #[test]
+    fn test_for_loops() {
+        let cases = vec![
+            ("let total = 0; for x in range(5) { total = total + x }; total", "10"),
+            ("let total = 0; for i in 1..4 { total = total * 10 + i }; total", "123"),
+            ("let total = 0; for i in 1..=4 { total = total * 10 + i }; total", "1234"),
+            ("let total = 0; for i in 3..1 { total = 1 }; total", "0"),
+            ("let s = \"\"; for c in \"añ🦀\" { s = format(\"{}<{}>\" s c) }; s", "\"<a><ñ><🦀>\""),
+            (
+                "let keys = \"\"; for k in json_parse(doc) { keys = format(\"{}{}\" keys k) }; keys",
+                "\"abc\"",
+            ),
+            // ranges work at the limits of ints
+            (
+                "let n = 0; for i in 9223372036854775806..=9223372036854775807 { n = n + 1 }; n",
+                "2",
+            ),
+            // the loop's scope ends with it
+            ("let x = 1; for x in 5..7 { let y = x }; x", "1"),
+            (
+                "let f = (xs) => { let total = 0; for x in xs { total = total + x }; total }; f((1..=100))",
+                "5050",
+            ),
+            ("let total = 0; for x in 1..10 { total = total + x; break }; total", "1"),
+            (
+                "let total = 0; for x in 1..4 { total = total + x; continue; total = 100 }; total",
+                "6",
+            ),
+            // only the inner loop is left
+            (
+                "let n = 0; for i in 0..3 { for j in 0..3 { n = n + 1; break }; n = n + 10 }; n",
+                "33",
+            ),
+            (
+                "let n = 0; for i in 0..3 { for j in 0..3 { continue; n = 100 }; n = n + 1 }; n",
+                "3",
+            ),
+            // values being worked on when a loop is left are dropped
+            ("let n = 0; for i in 0..3 { n = n + { break; 1 } }; n", "0"),
+            ("let n = 0; for i in 0..3 { n = n + i * { continue; 1 } }; n", "0"),
+            // a loop in a function that a native function calls
+            (
+                "let count = (a b) => { let c = a; for x in 0..b { c = c + 1 }; c }; reduce((0..4) count 0)",
+                "6",
+            ),
+            ("map((0..4) ((x) => x * x))", "[0, 1, 4, 9]"),
+            ("1..=3", "1..=3"),
+            ("(-2)..(1 + 1)", "-2..2"),
+        ];
+        for (source, expected) in cases {
+            let mut runtime = Runtime::new();
+            runtime.define_const("doc", Value::String(Rc::from(r#"{"c": 1, "a": 2, "b": 3}"#)));
+            assert_evaluates_in(&mut runtime, source, expected);
+        }
+    }
+
+    #[test]
+    fn test_for_loop_errors() {
+        let mut runtime = Runtime::new();
+        assert_eq!(
+            runtime.evaluate("break"),
+            EvaluationResult::SemanticAnalysisError(vec![AnalysisError::BreakOutsideLoop])
+        );
+        assert_eq!(
+            runtime.evaluate("for x in 0..3 { let f = () => { continue } }"),
+            EvaluationResult::SemanticAnalysisError(vec![AnalysisError::ContinueOutsideLoop])
+        );
+        assert_eq!(
+            runtime.evaluate("for x in 0..3 { x = 1 }"),
+            EvaluationResult::SemanticAnalysisError(vec![AnalysisError::ReassigningConst(
+                Identifier::new("x".to_string())
+            )])
+        );
+        assert_eq!(
+            runtime.evaluate("for x in 0..3 { 1 }; x"),
+            EvaluationResult::SemanticAnalysisError(vec![AnalysisError::UnboundIdentifier(
+                Identifier::new("x".to_string())
+            )])
+        );
+        assert_eq!(
+            runtime.evaluate("for x in 5 { 1 }"),
+            EvaluationResult::EvaluationError(EvaluationError::InvalidOperation(
+                "Can't iterate over 5".to_string()
+            ))
+        );
+        assert_eq!(
+            runtime.evaluate("0..1.5"),
+            EvaluationResult::EvaluationError(EvaluationError::InvalidOperation(
+                "Can't make a range from 0 to 1.5".to_string()
+            ))
+        );
+        // an error inside a loop leaves nothing behind for the next evaluation
+        assert_eq!(
+            runtime.evaluate("for x in 0..3 { 1 / 0 }"),
+            EvaluationResult::EvaluationError(EvaluationError::DivisionByZero)
+        );
+        assert_eq!(
+            runtime.evaluate("let n = 0; for x in 0..3 { n = n + x }; n"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(3)))
+        );
+    }
+
+    #[test]
+    fn test_generators() {
+        let prelude = "
+            let squares = (n) => { for i in 0..n { yield i * i } };
+            let naturals = () => { let n = 0; for i in 0..9223372036854775807 { yield n; n = n + 1 } };
+            let add = (a b) => a + b;
+        ";
+        let cases = vec![
+            ("let total = 0; for x in squares(4) { total = total + x }; total", "14"),
+            ("map(squares(3) ((x) => x + 1))", "[1, 2, 5]"),
+            ("filter(squares(5) ((x) => contains(\"1 9\" format(\"{}\" x))))", "[1, 9]"),
+            ("reduce(squares(4) add 0)", "14"),
+            ("sort(squares(3) ((a b) => b - a))", "[4, 1, 0]"),
+            // generators that never finish are only run as far as they're needed
+            ("zip(naturals() chars(\"ab\"))", "[[0, \"a\"], [1, \"b\"]]"),
+            ("any(naturals() ((n) => contains(format(\"{}\" n) \"7\")))", "true"),
+            ("let n = 0; for x in naturals() { n = x; break }; n", "0"),
+            ("enumerate(squares(2))", "[[0, 0], [1, 1]]"),
+            // generators can go through other generators
+            ("let evens = () => { for n in squares(3) { yield n * 2 } }; map(evens() add1)", "[1, 3, 9]"),
+            ("let g = () => { yield 1; { let x = 2; yield x }; yield 3 }; map(g() add1)", "[2, 3, 4]"),
+            // a finished generator stays finished
+            ("let g = squares(3); let a = reduce(g add 0); reduce(g add 0)", "0"),
+            ("let g = naturals(); for x in g { break }; reduce(zip(g (0..2)) ((a p) => a + 1) 0)", "2"),
+            ("squares(2)", "<generator>"),
+        ];
+        for (source, expected) in cases {
+            let mut runtime = Runtime::new();
+            runtime.evaluate(prelude);
+            runtime.evaluate("let add1 = (x) => x + 1");
+            assert_evaluates_in(&mut runtime, source, expected);
+        }
+
+        // calling a generator function doesn't run any of it yet
+        let mut runtime = Runtime::new();
+        runtime.evaluate("let runs = 0; let g = () => { runs = runs + 1; yield runs }; let it = g()");
+        assert_eq!(
+            runtime.evaluate("runs"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(0)))
+        );
+        assert_eq!(
+            runtime.evaluate("map(it ((x) => x))"),
+            EvaluationResult::Success(ReturnValue::Value(vec![1].into_value()))
+        );
+    }
+
+    #[test]
+    fn test_generator_errors() {
+        let mut runtime = Runtime::new();
+        assert_eq!(
+            runtime.evaluate("yield 1"),
+            EvaluationResult::SemanticAnalysisError(vec![AnalysisError::YieldOutsideFunction])
+        );
+
+        runtime.evaluate("let bad = () => { yield 1; yield 1 / 0 }; let g = bad()");
+        assert_eq!(
+            runtime.evaluate("for x in g { }"),
+            EvaluationResult::EvaluationError(EvaluationError::DivisionByZero)
+        );
+        // a generator that failed is finished
+        assert_eq!(
+            runtime.evaluate("map(g ((x) => x))"),
+            EvaluationResult::Success(ReturnValue::Value(Value::List(Rc::new(vec![]))))
+        );
+
+        runtime.evaluate("let me = nil; let f = () => { for x in me { yield x } }; me = f()");
+        assert_eq!(
+            runtime.evaluate("for x in me { }"),
+            EvaluationResult::EvaluationError(EvaluationError::InvalidOperation(
+                "Can't resume a generator that's already running".to_string()
+            ))
+        );
+        // each generator resuming the next runs inside the last on the native
+        // stack, so they can only nest so deeply, which the default runtime
+        // reaches before the stack runs out
+        let mut runtime = Runtime::new();
+        runtime.evaluate("let deep = (n) => { for x in deep((n + 1)) { yield x } }");
+        match runtime.evaluate("for x in deep(0) { }") {
+            EvaluationResult::EvaluationError(EvaluationError::StackOverflow(_)) => (),
+            result => panic!("{:?}", result),
+        }
+        runtime.evaluate("let wrap = (g) => { for x in g { yield x } }");
+        runtime.evaluate("let two = () => { yield 1; yield 2 }");
+        assert_evaluates_in(&mut runtime, "map(wrap(wrap(wrap(two()))) ((x) => x))", "[1, 2]");
+        assert_eq!(
+            runtime.evaluate("let one = (a) => { yield a }; one(1 2)"),
+            EvaluationResult::SemanticAnalysisError(vec![
+                AnalysisError::FunctionCallWithIncorrectArity(Identifier::new("one".to_string()))
+            ])
+        );
+    }
+
+    #[test]
+    fn test_handler_table() {
+        let program = parser::ProgramParser::new()
+            .parse(Lexer::new("try { 1 / 0 } catch (e) { e } finally { 2 }"))
+            .unwrap();
+        let handlers = vec![
+            Handler {
+                start: 0,
+                end: 4,
+                target: 7,
+                depth: 0,
+                catches: true,
+            },
+            // an error in the catch block still runs the finally block
+            Handler {
+                start: 8,
+                end: 10,
+                target: 13,
+                depth: 0,
+                catches: false,
+            },
+        ];
+        assert_eq!(
+            codegen::generate(program, &mut Globals::new()),
+            Chunk {
+                code: vec![
+                    Instruction::Constant(0),
+                    Instruction::Constant(1),
+                    Instruction::Div,
+                    Instruction::Pop,
+                    // the finally block is copied onto each way out of the try
+                    Instruction::Constant(2),
+                    Instruction::Pop,
+                    Instruction::Jump(10),
+                    Instruction::SetLocal(0),
+                    Instruction::GetLocal(0),
+                    Instruction::Pop,
+                    Instruction::Constant(2),
+                    Instruction::Pop,
+                    Instruction::Jump(4),
+                    Instruction::Constant(2),
+                    Instruction::Pop,
+                    Instruction::EndFinally,
+                ],
+                constants: vec![Value::Int(1), Value::Int(0), Value::Int(2)],
+                handlers,
+                generator: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_exceptions() {
+        let prelude = "
+            let fail = (n) => { throw n * 2 };
+            let safe_div = (a b) => { let r = nil; try { r = a / b } catch (e) { r = e }; r };
+            let bad = () => { yield 1; throw 7 };
+        ";
+        let cases = vec![
+            ("let r = nil; try { throw 1 } catch (e) { r = e }; r", "1"),
+            ("let r = nil; try { throw \"oops\" } catch (e) { r = e }; r", "\"oops\""),
+            // runtime errors are caught as a map of their kind and message
+            ("safe_div(1 0)", "{\"kind\": \"DivisionByZero\", \"message\": \"Division by zero\"}"),
+            ("safe_div(6 3)", "2"),
+            (
+                "let r = nil; try { json_parse(\"[\") } catch (e) { r = e }; r",
+                "{\"kind\": \"InvalidJson\", \"message\": \"Invalid JSON: Unexpected end of input at position 1\"}",
+            ),
+            // errors unwind through the frames of functions, and of the natives calling them
+            ("let r = 0; try { fail(3) } catch (e) { r = e }; r", "6"),
+            ("let r = 0; try { map((0..3) ((x) => fail(x))) } catch (e) { r = e + 1 }; r", "1"),
+            ("let r = 0; try { for x in bad() { r = r + x } } catch (e) { r = r + e }; r", "8"),
+            ("let g = () => { try { yield 1; yield 1 / 0 } catch (e) { yield 9 } }; map(g() ((x) => x))", "[1, 9]"),
+            // the values a failed expression had pushed are dropped
+            ("10 + { try { 1 + (2 * { throw 5 }) } catch (e) { }; 2 }", "12"),
+            (
+                "let n = 0; try { for i in 0..3 { for j in 0..3 { n = n + 1; throw n } } } catch (e) { }; \
+                 for k in 0..2 { n = n + 1 }; n",
+                "3",
+            ),
+            ("let n = 0; for i in 0..4 { try { n = n + 12 ~/ (i - 2) } catch (e) { n = n + 100 } }; n", "94"),
+            // finally blocks run however their try finishes
+            ("let n = 0; try { n = n + 1 } finally { n = n * 10 }; n", "10"),
+            ("let n = 0; try { throw 5 } catch (e) { n = e } finally { n = n * 10 }; n", "50"),
+            ("let n = 0; try { try { throw 1 } finally { n = n + 10 } } catch (e) { n = n + e }; n", "11"),
+            (
+                "let n = 0; try { try { throw 1 } catch (e) { throw e + 1 } finally { n = n + 10 } } \
+                 catch (e) { n = n + e }; n",
+                "12",
+            ),
+            ("let n = 0; for i in 0..5 { try { break } finally { n = n + 1 } }; n", "1"),
+            ("let n = 0; for i in 0..3 { try { continue; n = 100 } finally { n = n + 1 } }; n", "3"),
+            (
+                "let n = 0; try { for i in 0..3 { try { break } finally { throw 4 } } } catch (e) { n = e }; n",
+                "4",
+            ),
+            ("let n = 0; try { try { throw 1 } finally { throw 2 } } catch (e) { n = e }; n", "2"),
+            // each copy of a finally block has its own scope
+            ("let f = () => { let n = 0; try { n = 1 } finally { let w = n + 1; n = w * 10 }; n }; f()", "20"),
+            (
+                "let h = () => { let n = 0; try { throw 2 } catch (e) { } finally { let w = 9; let v = 10; n = w }; n }; \
+                 h()",
+                "9",
+            ),
+            (
+                "let f = () => { let n = 0; try { try { throw 3 } finally { let w = 4; n = w } } catch (e) { n = n + e }; \
+                 n }; f()",
+                "7",
+            ),
+            (
+                "let f = () => { let n = 0; for i in 0..3 { try { break } finally { let w = 5; n = n + w } }; n }; f()",
+                "5",
+            ),
+            ("let w = 1; let f = () => { try { 1 } finally { let w = 2 }; w }; f()", "1"),
+            (
+                "let f = () => { try { throw 1 } catch (e) { let c = 2 } finally { let w = 3 }; let after = 4; after }; \
+                 f()",
+                "4",
+            ),
+        ];
+        for (source, expected) in cases {
+            let mut runtime = Runtime::new();
+            runtime.evaluate(prelude);
+            assert_evaluates_in(&mut runtime, source, expected);
+        }
+    }
+
+    #[test]
+    fn test_exception_errors() {
+        let mut runtime = Runtime::new();
+        assert_eq!(
+            runtime.evaluate("throw 1"),
+            EvaluationResult::EvaluationError(EvaluationError::Thrown(Value::Int(1)))
+        );
+        // an error that goes through a finally block is still the same error
+        assert_eq!(
+            runtime.evaluate("let n = 0; try { 1 / 0 } finally { n = 1 }"),
+            EvaluationResult::EvaluationError(EvaluationError::DivisionByZero)
+        );
+        assert_eq!(
+            runtime.evaluate("n"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Int(1)))
+        );
+        assert_eq!(
+            runtime.evaluate("try { } catch (e) { e = 1 }"),
+            EvaluationResult::SemanticAnalysisError(vec![AnalysisError::ReassigningConst(
+                Identifier::new("e".to_string())
+            )])
+        );
+        assert_eq!(
+            runtime.evaluate("try { throw 1 } catch (err) { }; err"),
+            EvaluationResult::SemanticAnalysisError(vec![AnalysisError::UnboundIdentifier(
+                Identifier::new("err".to_string())
+            )])
+        );
+        assert!(matches!(runtime.evaluate("try { 1 }"), EvaluationResult::ParseError(_)));
+    }
 }
@@ -8,15 +8,85 @@ pub enum Type {
     Const,
 }
 
-#[derive(Debug, Default, Clone)]
+// a declaration, along with the number of parameters of the function it holds
+// when that's known
+#[derive(Debug, Clone)]
+struct Symbol {
+    declaration_type: Type,
+    arity: Option<usize>,
+}
+
+/// Declarations by scope, from the runtime's builtins and then the program's
+/// globals to the innermost scope
+#[derive(Debug, Clone)]
 pub struct SymbolTable {
-    table: HashMap<String, Type>,
+    scopes: Vec<HashMap<String, Symbol>>,
+}
+
+impl Default for SymbolTable {
+    fn default() -> SymbolTable {
+        SymbolTable {
+            scopes: vec![HashMap::new(), HashMap::new()],
+        }
+    }
 }
 
 impl SymbolTable {
     pub fn new() -> SymbolTable {
         SymbolTable::default()
     }
+
+    fn symbol(&self, name: &str) -> Option<&Symbol> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    fn get(&self, name: &str) -> Option<&Type> {
+        self.symbol(name).map(|symbol| &symbol.declaration_type)
+    }
+
+    // the arity of the function that the innermost declaration of a name holds
+    fn arity(&self, name: &str) -> Option<usize> {
+        self.symbol(name).and_then(|symbol| symbol.arity)
+    }
+
+    fn set_arity(&mut self, name: &str, arity: Option<usize>) {
+        let symbol = self.scopes.iter_mut().rev().find_map(|scope| scope.get_mut(name));
+        if let Some(symbol) = symbol {
+            symbol.arity = arity;
+        }
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    fn declared_in_current_scope(&self, name: &str) -> bool {
+        self.scopes.last().unwrap().contains_key(name)
+    }
+
+    fn declare(&mut self, name: String, declaration_type: Type) {
+        let symbol = Symbol {
+            declaration_type,
+            arity: None,
+        };
+        self.scopes.last_mut().unwrap().insert(name, symbol);
+    }
+
+    fn declare_builtin(&mut self, name: String, arity: Option<usize>) {
+        let symbol = Symbol {
+            declaration_type: Type::Const,
+            arity,
+        };
+        self.scopes[0].insert(name, symbol);
+    }
+
+    fn push_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    fn pop_scope(&mut self) {
+        self.scopes.pop();
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -25,11 +95,15 @@ pub enum AnalysisError {
     RedeclaringIdentifier(Identifier),
     ReassigningConst(Identifier),
     FunctionCallWithIncorrectArity(Identifier),
+    BreakOutsideLoop,
+    ContinueOutsideLoop,
+    YieldOutsideFunction,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AnalysisWarning {
     ReassigningIdentifier(Identifier),
+    ShadowingIdentifier(Identifier),
 }
 
 #[derive(Debug)]
@@ -55,7 +129,9 @@ impl AnalysisResults {
 #[derive(Debug, Default, Clone)]
 pub struct Analyzer {
     symbol_table: SymbolTable,
-    function_arity: HashMap<String, usize>,
+    // how many loops enclose the code being analyzed, within its function
+    loop_depth: usize,
+    in_function: bool,
 }
 
 impl Analyzer {
@@ -63,11 +139,37 @@ impl Analyzer {
         Analyzer::default()
     }
 
+    /// Declares a global that the runtime defines rather than a program,
+    /// along with its arity unless it takes any number of arguments. Builtins
+    /// can't be reassigned, but a program may declare its own global instead.
+    pub fn declare_builtin(&mut self, name: &str, arity: Option<usize>) {
+        self.symbol_table.declare_builtin(name.to_string(), arity);
+    }
+
+    // arities belong to declarations, so an inner declaration that shadows a
+    // function doesn't change what's known about the function
     fn add_function_arity(&mut self, ident: &Identifier, rhs: &Expr) {
-        if let Expr::Function(ref idents, _) = &rhs {
-            self.function_arity
-                .insert(ident.clone().value, idents.len());
+        let arity = match rhs {
+            Expr::Function(ref idents, _) => Some(idents.len()),
+            _ => None,
+        };
+        self.symbol_table.set_arity(&ident.value, arity);
+    }
+
+    // redeclaring in the same scope is an error, but shadowing a declaration
+    // from an enclosing scope is only worth a warning
+    fn check_declaration(&self, ident: &Identifier) -> AnalysisResults {
+        let mut results = AnalysisResults::new();
+        if self.symbol_table.declared_in_current_scope(&ident.value) {
+            results
+                .errors
+                .push(AnalysisError::RedeclaringIdentifier(ident.clone()))
+        } else if self.symbol_table.contains(&ident.value) {
+            results
+                .warnings
+                .push(AnalysisWarning::ShadowingIdentifier(ident.clone()))
         }
+        results
     }
 
     fn analyze_expr(&mut self, expr: &Expr) -> AnalysisResults {
@@ -75,18 +177,18 @@ impl Analyzer {
         match expr {
             Expr::Literal(ref _v) => (),
             Expr::Identifier(ref ident) => {
-                if !self.symbol_table.table.contains_key(&ident.value) {
+                if !self.symbol_table.contains(&ident.value) {
                     results
                         .errors
                         .push(AnalysisError::UnboundIdentifier(ident.clone()))
                 }
             }
             Expr::Assignment(ref ident, ref rhs) => {
-                if !self.symbol_table.table.contains_key(&ident.value) {
+                if !self.symbol_table.contains(&ident.value) {
                     results
                         .errors
                         .push(AnalysisError::UnboundIdentifier(ident.clone()))
-                } else if self.symbol_table.table.get(&ident.value).unwrap() == &Type::Const {
+                } else if self.symbol_table.get(&ident.value).unwrap() == &Type::Const {
                     results
                         .errors
                         .push(AnalysisError::ReassigningConst(ident.clone()))
@@ -100,29 +202,16 @@ impl Analyzer {
                 results.append(self.analyze_expr(rhs))
             }
             Expr::LetAssignment(ref ident, ref rhs) => {
-                if self.symbol_table.table.contains_key(&ident.value) {
-                    results
-                        .errors
-                        .push(AnalysisError::RedeclaringIdentifier(ident.clone()))
-                }
-
-                self.symbol_table
-                    .table
-                    .insert(ident.value.clone(), Type::Let);
+                results.append(self.check_declaration(ident));
+                self.symbol_table.declare(ident.value.clone(), Type::Let);
 
                 self.add_function_arity(ident, rhs);
 
                 results.append(self.analyze_expr(rhs))
             }
             Expr::ConstAssignment(ref ident, ref rhs) => {
-                if self.symbol_table.table.contains_key(&ident.value) {
-                    results
-                        .errors
-                        .push(AnalysisError::RedeclaringIdentifier(ident.clone()))
-                }
-                self.symbol_table
-                    .table
-                    .insert(ident.value.clone(), Type::Const);
+                results.append(self.check_declaration(ident));
+                self.symbol_table.declare(ident.value.clone(), Type::Const);
 
                 self.add_function_arity(ident, rhs);
                 results.append(self.analyze_expr(rhs))
@@ -131,22 +220,40 @@ impl Analyzer {
                 results.append(self.analyze_expr(a));
                 results.append(self.analyze_expr(b));
             }
+            Expr::UnaryOp(_, ref a) => {
+                results.append(self.analyze_expr(a));
+            }
             Expr::Function(params, body) => {
                 let mut fn_analyzer = self.clone();
+                // a loop around a function doesn't make `break` valid inside it
+                fn_analyzer.loop_depth = 0;
+                fn_analyzer.in_function = true;
 
-                // add params to symbol table
-                fn_analyzer.symbol_table.table.extend(
-                    params
-                    .iter()
-                    .map(|param| (param.value.clone(), Type::Const)));
+                // params are declared in the function's own scope
+                fn_analyzer.symbol_table.push_scope();
+                for param in params {
+                    fn_analyzer
+                        .symbol_table
+                        .declare(param.value.clone(), Type::Const);
+                }
 
                 let fn_results = fn_analyzer.analyze(body);
                 results.append(fn_results);
             }
+            Expr::Block(ref body) => {
+                self.symbol_table.push_scope();
+                results.append(self.analyze(body));
+                self.symbol_table.pop_scope();
+            }
+
+            Expr::Documented(_, ref expr) => {
+                results.append(self.analyze_expr(expr));
+            }
 
             Expr::CallFunction(ref ident, ref arg_exprs) => {
-                let expected_len = self.function_arity.get(&ident.value).unwrap();
-                if arg_exprs.len() != *expected_len {
+                // the arity is only known for names bound directly to a function literal
+                let expected_len = self.symbol_table.arity(&ident.value);
+                if expected_len.is_some_and(|len| arg_exprs.len() != len) {
                     results
                         .errors
                         .push(AnalysisError::FunctionCallWithIncorrectArity(ident.clone()))
@@ -154,6 +261,54 @@ impl Analyzer {
 
                 results.append(self.analyze(arg_exprs));
             }
+
+            Expr::Range(ref start, ref end, _) => {
+                results.append(self.analyze_expr(start));
+                results.append(self.analyze_expr(end));
+            }
+            // the loop variable is declared in the same scope as the body's
+            // own declarations
+            Expr::For(ref ident, ref iterable, ref body) => {
+                results.append(self.analyze_expr(iterable));
+                self.symbol_table.push_scope();
+                self.symbol_table.declare(ident.value.clone(), Type::Const);
+                self.loop_depth += 1;
+                results.append(self.analyze(body));
+                self.loop_depth -= 1;
+                self.symbol_table.pop_scope();
+            }
+            Expr::Break if self.loop_depth == 0 => {
+                results.errors.push(AnalysisError::BreakOutsideLoop)
+            }
+            Expr::Continue if self.loop_depth == 0 => {
+                results.errors.push(AnalysisError::ContinueOutsideLoop)
+            }
+            Expr::Break | Expr::Continue => (),
+            Expr::Yield(ref expr) => {
+                if !self.in_function {
+                    results.errors.push(AnalysisError::YieldOutsideFunction)
+                }
+                results.append(self.analyze_expr(expr));
+            }
+            Expr::Throw(ref expr) => results.append(self.analyze_expr(expr)),
+            // each block has a scope of its own, and the error is declared in
+            // the catch block's
+            Expr::Try(ref body, ref catch, ref finally) => {
+                self.symbol_table.push_scope();
+                results.append(self.analyze(body));
+                self.symbol_table.pop_scope();
+                if let Some((ref ident, ref handler)) = catch {
+                    self.symbol_table.push_scope();
+                    self.symbol_table.declare(ident.value.clone(), Type::Const);
+                    results.append(self.analyze(handler));
+                    self.symbol_table.pop_scope();
+                }
+                if let Some(ref finally) = finally {
+                    self.symbol_table.push_scope();
+                    results.append(self.analyze(finally));
+                    self.symbol_table.pop_scope();
+                }
+            }
         }
         results
     }
@@ -161,7 +316,7 @@ impl Analyzer {
     pub fn analyze(&mut self, exprs: &[Expr]) -> AnalysisResults {
         let mut results = AnalysisResults::new();
         for expr in exprs {
-            results.append(self.analyze_expr(&expr))
+            results.append(self.analyze_expr(expr))
         }
         results
     }
@@ -0,0 +1,392 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use arithmetic;
+use bytecode::{nesting, Value, MAX_LIST_LEN, MAX_NESTING};
+use convert::{FromValue, IntoValue};
+use runtime::Runtime;
+use vm::{EvaluationError, VM};
+
+fn list(items: Vec<Value>) -> Value {
+    Value::List(Rc::new(items))
+}
+
+// adds an item to a list being built, unless the list is already as long
+// as a list may be
+fn push(items: &mut Vec<Value>, item: Value) -> Result<(), EvaluationError> {
+    if items.len() == MAX_LIST_LEN {
+        return Err(too_long());
+    }
+    items.push(item);
+    Ok(())
+}
+
+fn too_long() -> EvaluationError {
+    EvaluationError::InvalidArgument(format!(
+        "Can't make a list of more than {} items",
+        MAX_LIST_LEN
+    ))
+}
+
+// a list holding values the script chose, which could otherwise be nested
+// one level deeper each time it's built from the last one
+fn nested_list(items: Vec<Value>) -> Result<Value, EvaluationError> {
+    let list = list(items);
+    if nesting(&list, &mut HashMap::new()) > MAX_NESTING {
+        return Err(EvaluationError::InvalidArgument(format!(
+            "Can't nest lists and maps more than {} deep",
+            MAX_NESTING
+        )));
+    }
+    Ok(list)
+}
+
+// iterates over something whose every item goes into the list being built,
+// refusing up front ranges that would make it too long
+fn iterate_into_list(iterable: &Value) -> Result<Value, EvaluationError> {
+    match iterable {
+        Value::Range(range) if range.count() > MAX_LIST_LEN as i128 => Err(too_long()),
+        iterable => VM::iterate(iterable.clone()),
+    }
+}
+
+// predicates have to return a bool, rather than anything being truthy
+fn test(vm: &mut VM, f: &Value, item: &Value) -> Result<bool, EvaluationError> {
+    bool::from_value(&vm.call(f, vec![item.clone()])?).map_err(EvaluationError::from)
+}
+
+// anything a loop can go through, taking items from generators one at a time
+fn items(vm: &mut VM, iterable: &Value) -> Result<Vec<Value>, EvaluationError> {
+    let mut iterator = iterate_into_list(iterable)?;
+    let mut items = Vec::new();
+    while let Some(item) = vm.next_item(&mut iterator)? {
+        push(&mut items, item)?;
+    }
+    Ok(items)
+}
+
+fn map(vm: &mut VM, args: &[Value]) -> Result<Value, EvaluationError> {
+    let mut iterator = iterate_into_list(&args[0])?;
+    let mut mapped = Vec::new();
+    while let Some(item) = vm.next_item(&mut iterator)? {
+        let item = vm.call(&args[1], vec![item])?;
+        push(&mut mapped, item)?;
+    }
+    nested_list(mapped)
+}
+
+fn filter(vm: &mut VM, args: &[Value]) -> Result<Value, EvaluationError> {
+    let mut iterator = VM::iterate(args[0].clone())?;
+    let mut kept = Vec::new();
+    while let Some(item) = vm.next_item(&mut iterator)? {
+        if test(vm, &args[1], &item)? {
+            push(&mut kept, item)?;
+        }
+    }
+    Ok(list(kept))
+}
+
+/// Folds the items into the initial value, calling `f(acc item)` for each
+fn reduce(vm: &mut VM, args: &[Value]) -> Result<Value, EvaluationError> {
+    let mut iterator = VM::iterate(args[0].clone())?;
+    let mut acc = args[2].clone();
+    while let Some(item) = vm.next_item(&mut iterator)? {
+        acc = vm.call(&args[1], vec![acc, item])?;
+    }
+    Ok(acc)
+}
+
+// stops at the first item that decides the answer, so they can be given
+// generators that never finish
+fn any(vm: &mut VM, args: &[Value]) -> Result<Value, EvaluationError> {
+    let mut iterator = VM::iterate(args[0].clone())?;
+    while let Some(item) = vm.next_item(&mut iterator)? {
+        if test(vm, &args[1], &item)? {
+            return Ok(Value::Bool(true));
+        }
+    }
+    Ok(Value::Bool(false))
+}
+
+fn all(vm: &mut VM, args: &[Value]) -> Result<Value, EvaluationError> {
+    let mut iterator = VM::iterate(args[0].clone())?;
+    while let Some(item) = vm.next_item(&mut iterator)? {
+        if !test(vm, &args[1], &item)? {
+            return Ok(Value::Bool(false));
+        }
+    }
+    Ok(Value::Bool(true))
+}
+
+// strings sort among themselves, and numbers by value
+fn natural_order(a: &Value, b: &Value) -> Result<Ordering, EvaluationError> {
+    match (a, b) {
+        (Value::String(a), Value::String(b)) => Ok(a.cmp(b)),
+        (a, b) => arithmetic::compare(a, b),
+    }
+}
+
+// a stable merge sort, since the comparison can fail, and a comparator that
+// isn't consistent mustn't be able to panic as the standard sorts may
+fn merge_sort<F>(items: Vec<Value>, compare: &mut F) -> Result<Vec<Value>, EvaluationError>
+where
+    F: FnMut(&Value, &Value) -> Result<Ordering, EvaluationError>,
+{
+    if items.len() <= 1 {
+        return Ok(items);
+    }
+    let mut left = items;
+    let right = left.split_off(left.len() / 2);
+    let left = merge_sort(left, compare)?;
+    let right = merge_sort(right, compare)?;
+
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+        if compare(b, a)? == Ordering::Less {
+            merged.push(right.next().unwrap());
+        } else {
+            merged.push(left.next().unwrap());
+        }
+    }
+    merged.extend(left);
+    merged.extend(right);
+    Ok(merged)
+}
+
+/// Sorts numbers or strings, or anything else given a comparator, which
+/// returns a negative number, zero, or a positive number as `a` is less
+/// than, equal to, or greater than `b`
+fn sort(vm: &mut VM, args: &[Value]) -> Result<Value, EvaluationError> {
+    let items = match args {
+        [iterable] => merge_sort(items(vm, iterable)?, &mut natural_order)?,
+        [iterable, comparator] => {
+            let items = items(vm, iterable)?;
+            let mut compare = |a: &Value, b: &Value| {
+                let result = vm.call(comparator, vec![a.clone(), b.clone()])?;
+                arithmetic::compare(&result, &Value::Int(0))
+            };
+            merge_sort(items, &mut compare)?
+        }
+        args => return Err(EvaluationError::IncorrectArity(2, args.len())),
+    };
+    Ok(list(items))
+}
+
+/// Pairs up the items of two iterables, stopping at the end of the shorter one
+fn zip(vm: &mut VM, args: &[Value]) -> Result<Value, EvaluationError> {
+    let mut a = VM::iterate(args[0].clone())?;
+    let mut b = VM::iterate(args[1].clone())?;
+    let mut pairs = Vec::new();
+    while let Some(x) = vm.next_item(&mut a)? {
+        match vm.next_item(&mut b)? {
+            Some(y) => push(&mut pairs, list(vec![x, y]))?,
+            None => break,
+        }
+    }
+    nested_list(pairs)
+}
+
+/// Pairs each item with its index
+fn enumerate(vm: &mut VM, args: &[Value]) -> Result<Value, EvaluationError> {
+    let mut iterator = iterate_into_list(&args[0])?;
+    let mut pairs = Vec::new();
+    while let Some(item) = vm.next_item(&mut iterator)? {
+        let pair = list(vec![Value::Int(pairs.len() as i64), item]);
+        push(&mut pairs, pair)?;
+    }
+    nested_list(pairs)
+}
+
+/// The ints from `start` up to but not including `end`, counting by `step`:
+/// `range(end)`, `range(start end)` or `range(start end step)`
+fn range(_: &mut VM, args: &[Value]) -> Result<Value, EvaluationError> {
+    let ints = args
+        .iter()
+        .map(i64::from_value)
+        .collect::<Result<Vec<i64>, _>>()?;
+    let (start, end, step) = match ints[..] {
+        [end] => (0, end, 1),
+        [start, end] => (start, end, 1),
+        [start, end, step] => (start, end, step),
+        _ => return Err(EvaluationError::IncorrectArity(3, args.len())),
+    };
+    if step == 0 {
+        return Err(EvaluationError::InvalidArgument(
+            "Can't count by a step of 0".to_string(),
+        ));
+    }
+
+    // the number of ints, rounded up, which is worked out first so that the
+    // list isn't built only to be refused
+    let span = i128::from(end) - i128::from(start);
+    let width = i128::from(step);
+    let count = (span + width - width.signum()) / width;
+    if count > MAX_LIST_LEN as i128 {
+        return Err(too_long());
+    }
+
+    let mut ints = Vec::new();
+    let mut i = start;
+    while (step > 0 && i < end) || (step < 0 && i > end) {
+        ints.push(i);
+        i = match i.checked_add(step) {
+            Some(next) => next,
+            None => break,
+        };
+    }
+    Ok(ints.into_value())
+}
+
+pub fn register(runtime: &mut Runtime) {
+    runtime.register_higher_order_fn("map", Some(2), map);
+    runtime.register_higher_order_fn("filter", Some(2), filter);
+    runtime.register_higher_order_fn("reduce", Some(3), reduce);
+    runtime.register_higher_order_fn("any", Some(2), any);
+    runtime.register_higher_order_fn("all", Some(2), all);
+    runtime.register_higher_order_fn("sort", None, sort);
+    runtime.register_higher_order_fn("zip", Some(2), zip);
+    runtime.register_higher_order_fn("enumerate", Some(1), enumerate);
+    runtime.register_higher_order_fn("range", None, range);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use convert::ConversionError;
+    use runtime::tests::assert_evaluates_in;
+    use runtime::EvaluationResult;
+    use vm::ReturnValue;
+
+    #[test]
+    fn test_collections() {
+        let cases = vec![
+            ("map(range(4) ((x) => x * x))", "[0, 1, 4, 9]"),
+            ("let offset = 10; map(range(3) ((x) => x + offset))", "[10, 11, 12]"),
+            ("map(split(\"a b\" \" \") upper)", "[\"A\", \"B\"]"),
+            ("filter(range(10) ((x) => contains(\"2357\" format(\"{}\" x))))", "[2, 3, 5, 7]"),
+            ("reduce(range(1 5) ((acc x) => acc * x) 1)", "24"),
+            ("reduce(range(0) ((acc x) => acc + x) \"empty\")", "\"empty\""),
+            ("any(range(5) ((x) => contains(\"4\" format(\"{}\" x))))", "true"),
+            ("all(chars(\"abc\") ((c) => contains(\"ab\" c)))", "false"),
+            ("all(range(0) ((x) => x))", "true"),
+            ("sort(chars(\"dacb\"))", "[\"a\", \"b\", \"c\", \"d\"]"),
+            ("sort(map(chars(\"3142\") int))", "[1, 2, 3, 4]"),
+            ("sort(range(5) ((a b) => b - a))", "[4, 3, 2, 1, 0]"),
+            // equal items keep their order
+            (
+                "sort(split(\"bb a ccc dd e\" \" \") ((a b) => len(a) - len(b)))",
+                "[\"a\", \"e\", \"bb\", \"dd\", \"ccc\"]",
+            ),
+            ("zip(range(3) chars(\"ab\"))", "[[0, \"a\"], [1, \"b\"]]"),
+            ("enumerate(chars(\"xy\"))", "[[0, \"x\"], [1, \"y\"]]"),
+            ("range(2 11 4)", "[2, 6, 10]"),
+            ("range(3 0 (-1))", "[3, 2, 1]"),
+            ("range(9223372036854775806 9223372036854775807 5)", "[9223372036854775806]"),
+            // functions passed in can call higher order functions themselves
+            (
+                "map(range(3) ((n) => reduce(range((n + 1)) ((a b) => a + b) 0)))",
+                "[0, 1, 3]",
+            ),
+        ];
+        for (source, expected) in cases {
+            let mut runtime = Runtime::new();
+            runtime.register_typed_fn("len", |s: String| -> Result<i64, String> {
+                Ok(s.chars().count() as i64)
+            });
+            assert_evaluates_in(&mut runtime, source, expected);
+        }
+    }
+
+    #[test]
+    fn test_collection_errors() {
+        let cases = vec![
+            ("map(range(3) ((a b) => a))", EvaluationError::IncorrectArity(2, 1)),
+            (
+                "filter(range(3) ((x) => x))",
+                EvaluationError::ConversionError(ConversionError::UnexpectedType("bool", "int")),
+            ),
+            ("map(range(3) 1)", EvaluationError::InvalidOperation("Can't call 1".to_string())),
+            ("map(range(3) ((x) => 1 / (x - 1)))", EvaluationError::DivisionByZero),
+            (
+                "sort(split(\"a 1\" \" \") ((a b) => a))",
+                EvaluationError::InvalidOperation("Can't compare \"1\" and 0".to_string()),
+            ),
+            (
+                "range(0 5 0)",
+                EvaluationError::InvalidArgument("Can't count by a step of 0".to_string()),
+            ),
+            ("range()", EvaluationError::IncorrectArity(3, 0)),
+            // lists that would be too long to make are refused before they're built
+            (
+                "range(9223372036854775807)",
+                EvaluationError::InvalidArgument(
+                    "Can't make a list of more than 16777216 items".to_string(),
+                ),
+            ),
+            (
+                "map((0..9223372036854775807) ((x) => x))",
+                EvaluationError::InvalidArgument(
+                    "Can't make a list of more than 16777216 items".to_string(),
+                ),
+            ),
+            (
+                "sort(((-1)..=16777215))",
+                EvaluationError::InvalidArgument(
+                    "Can't make a list of more than 16777216 items".to_string(),
+                ),
+            ),
+            // as are lists nested deeply enough to overflow the stack when
+            // they're dropped or printed, however they're built
+            (
+                "let x = range(1); for i in 0..1000 { x = enumerate(x) }",
+                EvaluationError::InvalidArgument(
+                    "Can't nest lists and maps more than 512 deep".to_string(),
+                ),
+            ),
+            (
+                "let y = range(1); for i in 0..1000 { y = map(range(1) ((n) => y)) }",
+                EvaluationError::InvalidArgument(
+                    "Can't nest lists and maps more than 512 deep".to_string(),
+                ),
+            ),
+            // each copy of a list is only measured once, so doubling it up
+            // each time doesn't take exponentially long
+            (
+                "let z = range(1); for i in 0..1000 { z = zip(z z) }",
+                EvaluationError::InvalidArgument(
+                    "Can't nest lists and maps more than 512 deep".to_string(),
+                ),
+            ),
+        ];
+        for (source, expected) in cases {
+            let mut runtime = Runtime::new();
+            assert_eq!(
+                runtime.evaluate(source),
+                EvaluationResult::EvaluationError(expected),
+                "{}",
+                source
+            );
+            // the runtime can carry on after an error inside a callback
+            assert_eq!(
+                runtime.evaluate("map(range(2) ((x) => x + 1))"),
+                EvaluationResult::Success(ReturnValue::Value(vec![1, 2].into_value())),
+                "{}",
+                source
+            );
+        }
+
+        // recursion through a callback runs out of native stack long before
+        // the call depth limit, so it has a limit of its own
+        let mut runtime = Runtime::new();
+        let source = "let f = (n) => map(range(1) ((x) => f((n + 1))))";
+        assert_eq!(runtime.evaluate(source), EvaluationResult::Success(ReturnValue::Empty));
+        match runtime.evaluate("f(0)") {
+            EvaluationResult::EvaluationError(EvaluationError::StackOverflow(_)) => (),
+            result => panic!("{:?}", result),
+        }
+        assert_evaluates_in(&mut runtime, "map(range(2) ((x) => x))", "[0, 1]");
+    }
+}
@@ -0,0 +1,219 @@
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::rc::Rc;
+
+use bytecode::Value;
+use convert::{FromValue, IntoValue};
+use runtime::Runtime;
+use vm::EvaluationError;
+
+/// What the file functions may touch. By default scripts can't use the file
+/// system at all; a host opts in by allowing directories, and everything
+/// within them (following symlinks) can be read, and written unless
+/// `read_only` is set.
+///
+/// Paths are checked before they're opened, and then opened by name, so a
+/// process that can change the directories inside a root in between, such
+/// as by swapping one for a symlink, can make a script reach outside it. The
+/// roots should only be writable by the script and by processes the host
+/// trusts.
+#[derive(Debug, Clone, Default)]
+pub struct Capabilities {
+    // canonical, so that they can be compared with resolved paths
+    roots: Vec<PathBuf>,
+    pub read_only: bool,
+}
+
+impl Capabilities {
+    /// Allows reading and writing within `root`, which must already exist;
+    /// one that doesn't allows nothing
+    pub fn allow_root<P: AsRef<Path>>(mut self, root: P) -> Capabilities {
+        if let Ok(root) = root.as_ref().canonicalize() {
+            self.roots.push(root);
+        }
+        self
+    }
+
+    pub fn read_only(mut self, read_only: bool) -> Capabilities {
+        self.read_only = read_only;
+        self
+    }
+
+    // the real location of a path, which needn't exist yet, if it's inside
+    // one of the roots
+    fn resolve(&self, path: &str, write: bool) -> Result<PathBuf, EvaluationError> {
+        let denied = || EvaluationError::PermissionDenied(path.to_string());
+        if write && self.read_only {
+            return Err(denied());
+        }
+        let resolved = canonicalize(Path::new(path)).ok_or_else(denied)?;
+        if self.roots.iter().any(|root| resolved.starts_with(root)) {
+            Ok(resolved)
+        } else {
+            Err(denied())
+        }
+    }
+}
+
+// canonicalizes the longest part of the path that exists, and appends the
+// rest, which mustn't go back up with `..` or be a symlink whose target is
+// missing, since writing through it would create a file wherever it points
+fn canonicalize(path: &Path) -> Option<PathBuf> {
+    if let Ok(resolved) = path.canonicalize() {
+        return Some(resolved);
+    }
+    if path.symlink_metadata().is_ok() {
+        return None;
+    }
+    let name = match path.components().next_back()? {
+        Component::Normal(name) => name,
+        _ => return None,
+    };
+    let parent = match path.parent() {
+        Some(parent) if parent != Path::new("") => parent,
+        _ => Path::new("."),
+    };
+    canonicalize(parent).map(|parent| parent.join(name))
+}
+
+fn io_error(e: io::Error) -> EvaluationError {
+    EvaluationError::IoError(e.to_string())
+}
+
+fn read_file(capabilities: &Capabilities, args: &[Value]) -> Result<Value, EvaluationError> {
+    let path = capabilities.resolve(&String::from_value(&args[0])?, false)?;
+    fs::read_to_string(path).map(IntoValue::into_value).map_err(io_error)
+}
+
+fn write_file(capabilities: &Capabilities, args: &[Value]) -> Result<Value, EvaluationError> {
+    let path = capabilities.resolve(&String::from_value(&args[0])?, true)?;
+    fs::write(path, String::from_value(&args[1])?).map_err(io_error)?;
+    Ok(Value::Nil)
+}
+
+/// The names of a directory's entries, sorted
+fn list_dir(capabilities: &Capabilities, args: &[Value]) -> Result<Value, EvaluationError> {
+    let path = capabilities.resolve(&String::from_value(&args[0])?, false)?;
+    let mut names = Vec::new();
+    for entry in fs::read_dir(path).map_err(io_error)? {
+        names.push(entry.map_err(io_error)?.file_name().to_string_lossy().into_owned());
+    }
+    names.sort();
+    Ok(names.into_value())
+}
+
+fn exists(capabilities: &Capabilities, args: &[Value]) -> Result<Value, EvaluationError> {
+    let path = capabilities.resolve(&String::from_value(&args[0])?, false)?;
+    Ok(Value::Bool(path.exists()))
+}
+
+fn register_fs_fn(
+    runtime: &mut Runtime,
+    name: &str,
+    arity: usize,
+    f: fn(&Capabilities, &[Value]) -> Result<Value, EvaluationError>,
+) {
+    let capabilities = Rc::clone(&runtime.capabilities);
+    runtime.register_fn(name, arity, move |args| f(&capabilities.borrow(), args));
+}
+
+pub fn register(runtime: &mut Runtime) {
+    register_fs_fn(runtime, "read_file", 1, read_file);
+    register_fs_fn(runtime, "write_file", 2, write_file);
+    register_fs_fn(runtime, "list_dir", 1, list_dir);
+    register_fs_fn(runtime, "exists", 1, exists);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runtime::tests::assert_evaluates_in;
+    use runtime::EvaluationResult;
+
+    #[test]
+    fn test_file_capabilities() {
+        let dir = std::env::temp_dir().join(format!("bytelang-fs-{}", std::process::id()));
+        let root = dir.join("root");
+        std::fs::create_dir_all(root.join("sub")).unwrap();
+        std::fs::write(root.join("a.txt"), "héllo").unwrap();
+        std::fs::write(dir.join("secret.txt"), "secret").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(dir.join("secret.txt"), root.join("link.txt")).unwrap();
+        // links to files and directories outside the root that don't exist yet
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(dir.join("planted.txt"), root.join("dangling.txt")).unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(dir.join("planted"), root.join("dangling")).unwrap();
+
+        let runtime_with = |capabilities: Capabilities| {
+            let mut runtime = Runtime::new();
+            runtime.set_capabilities(capabilities);
+            for (name, path) in &[("root", &root), ("dir", &dir)] {
+                let path = path.to_str().unwrap();
+                runtime.define_const(name, Value::String(Rc::from(path)));
+            }
+            runtime
+        };
+        let denied = |runtime: &mut Runtime, source: &str, path: &Path| {
+            assert_eq!(
+                runtime.evaluate(source),
+                EvaluationResult::EvaluationError(EvaluationError::PermissionDenied(
+                    path.to_str().unwrap().to_string()
+                )),
+                "{}",
+                source
+            );
+        };
+
+        // nothing is allowed by default
+        let mut runtime = runtime_with(Capabilities::default());
+        denied(&mut runtime, "exists(root)", &root);
+        // nor by a root that didn't exist when it was allowed
+        let mut runtime = runtime_with(Capabilities::default().allow_root(dir.join("later")));
+        std::fs::create_dir(dir.join("later")).unwrap();
+        denied(&mut runtime, "exists(format(\"{}/later\" dir))", &dir.join("later"));
+
+        let mut runtime = runtime_with(Capabilities::default().allow_root(&root));
+        runtime.evaluate("let file = format(\"{}/a.txt\" root)");
+        runtime.evaluate("let new = format(\"{}/sub/b.txt\" root)");
+        assert_evaluates_in(&mut runtime, "read_file(file)", "\"héllo\"");
+        assert_evaluates_in(&mut runtime, "exists(new)", "false");
+        assert_evaluates_in(&mut runtime, "write_file(new \"wörld\")", "nil");
+        assert_evaluates_in(&mut runtime, "read_file(new)", "\"wörld\"");
+        assert_evaluates_in(&mut runtime, "list_dir(format(\"{}/sub\" root))", "[\"b.txt\"]");
+        match runtime.evaluate("read_file(format(\"{}/missing.txt\" root))") {
+            EvaluationResult::EvaluationError(EvaluationError::IoError(_)) => (),
+            result => panic!("{:?}", result),
+        }
+
+        denied(&mut runtime, "list_dir(dir)", &dir);
+        denied(&mut runtime, "read_file(format(\"{}/secret.txt\" dir))", &dir.join("secret.txt"));
+        let escape = root.join("sub/../../secret.txt");
+        denied(&mut runtime, "read_file(format(\"{}/sub/../../secret.txt\" root))", &escape);
+        let escape = root.join("missing/../../secret.txt");
+        denied(
+            &mut runtime,
+            "write_file(format(\"{}/missing/../../secret.txt\" root) \"x\")",
+            &escape,
+        );
+        #[cfg(unix)]
+        {
+            denied(&mut runtime, "read_file(format(\"{}/link.txt\" root))", &root.join("link.txt"));
+            let dangling = root.join("dangling.txt");
+            denied(&mut runtime, "write_file(format(\"{}/dangling.txt\" root) \"x\")", &dangling);
+            denied(&mut runtime, "exists(format(\"{}/dangling.txt\" root))", &dangling);
+            let dangling = root.join("dangling/new.txt");
+            denied(&mut runtime, "write_file(format(\"{}/dangling/new.txt\" root) \"x\")", &dangling);
+            assert!(!dir.join("planted.txt").exists());
+        }
+
+        // capabilities can be narrowed after a script has started using them
+        runtime.set_capabilities(Capabilities::default().allow_root(&root).read_only(true));
+        assert_evaluates_in(&mut runtime, "read_file(file)", "\"héllo\"");
+        denied(&mut runtime, "write_file(file \"x\")", &root.join("a.txt"));
+        assert_eq!(std::fs::read_to_string(root.join("a.txt")).unwrap(), "héllo");
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
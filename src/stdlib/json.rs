@@ -0,0 +1,591 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::iter;
+use std::rc::Rc;
+
+use num_bigint::BigInt;
+
+use bytecode::Value;
+use convert::{type_name, ConversionError, FromValue};
+use runtime::Runtime;
+use vm::EvaluationError;
+
+// deeper documents and values are rejected rather than overflowing the stack
+// of the parser or the writer
+const MAX_DEPTH: usize = 512;
+
+// the most spaces `stringify` will indent each level by
+pub const MAX_INDENT: usize = 16;
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error<T>(&self, message: &str) -> Result<T, EvaluationError> {
+        Err(EvaluationError::InvalidJson(format!(
+            "{} at position {}",
+            message, self.position
+        )))
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.position).cloned()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, literal: &str) -> Result<(), EvaluationError> {
+        if self.input[self.position..].starts_with(literal) {
+            self.position += literal.len();
+            Ok(())
+        } else {
+            self.error(&format!("Expected {}", literal))
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, EvaluationError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'n') => self.expect("null").map(|_| Value::Nil),
+            Some(b't') => self.expect("true").map(|_| Value::Bool(true)),
+            Some(b'f') => self.expect("false").map(|_| Value::Bool(false)),
+            Some(b'"') => Ok(Value::String(Rc::from(self.string()?))),
+            Some(b'[') => self.nested(Parser::list),
+            Some(b'{') => self.nested(Parser::map),
+            Some(b'-') | Some(b'0'..=b'9') => self.number(),
+            Some(_) => self.error("Unexpected character"),
+            None => self.error("Unexpected end of input"),
+        }
+    }
+
+    fn nested(
+        &mut self,
+        parse: fn(&mut Parser<'a>) -> Result<Value, EvaluationError>,
+    ) -> Result<Value, EvaluationError> {
+        if self.depth == MAX_DEPTH {
+            return self.error("Too deeply nested");
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    // the items of a list or the entries of a map, up to the closing bracket
+    fn sequence<F>(&mut self, close: u8, mut item: F) -> Result<(), EvaluationError>
+    where
+        F: FnMut(&mut Parser<'a>) -> Result<(), EvaluationError>,
+    {
+        self.position += 1;
+        self.skip_whitespace();
+        if self.peek() == Some(close) {
+            self.position += 1;
+            return Ok(());
+        }
+        loop {
+            item(self)?;
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(c) if c == close => {
+                    self.position += 1;
+                    return Ok(());
+                }
+                _ => return self.error(&format!("Expected , or {}", close as char)),
+            }
+        }
+    }
+
+    fn list(&mut self) -> Result<Value, EvaluationError> {
+        let mut items = Vec::new();
+        self.sequence(b']', |parser| {
+            items.push(parser.value()?);
+            Ok(())
+        })?;
+        Ok(Value::List(Rc::new(items)))
+    }
+
+    // a key that appears more than once keeps its last value
+    fn map(&mut self) -> Result<Value, EvaluationError> {
+        let mut entries = BTreeMap::new();
+        self.sequence(b'}', |parser| {
+            parser.skip_whitespace();
+            if parser.peek() != Some(b'"') {
+                return parser.error("Expected a string key");
+            }
+            let key = parser.string()?;
+            parser.skip_whitespace();
+            parser.expect(":")?;
+            entries.insert(key, parser.value()?);
+            Ok(())
+        })?;
+        Ok(Value::Map(Rc::new(entries)))
+    }
+
+    fn string(&mut self) -> Result<String, EvaluationError> {
+        self.position += 1;
+        let mut s = String::new();
+        loop {
+            let start = self.position;
+            while let Some(c) = self.peek() {
+                if c == b'"' || c == b'\\' || c < 0x20 {
+                    break;
+                }
+                self.position += 1;
+            }
+            s.push_str(&self.input[start..self.position]);
+            match self.peek() {
+                Some(b'"') => {
+                    self.position += 1;
+                    return Ok(s);
+                }
+                Some(b'\\') => {
+                    self.position += 1;
+                    s.push(self.escape()?);
+                }
+                Some(_) => return self.error("Control character in string"),
+                None => return self.error("Unterminated string"),
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Result<char, EvaluationError> {
+        let c = match self.peek() {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                self.position += 1;
+                return self.unicode_escape();
+            }
+            _ => return self.error("Invalid escape"),
+        };
+        self.position += 1;
+        Ok(c)
+    }
+
+    // characters outside the basic multilingual plane are escaped as a pair
+    // of surrogates
+    fn unicode_escape(&mut self) -> Result<char, EvaluationError> {
+        let high = self.hex_digits()?;
+        let code = if (0xD800..0xDC00).contains(&high) {
+            self.expect("\\u")?;
+            let low = self.hex_digits()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return self.error("Invalid surrogate pair");
+            }
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+        match ::std::char::from_u32(code) {
+            Some(c) => Ok(c),
+            None => self.error("Invalid surrogate pair"),
+        }
+    }
+
+    fn hex_digits(&mut self) -> Result<u32, EvaluationError> {
+        let digits = self.input.get(self.position..self.position + 4);
+        match digits.and_then(|digits| u32::from_str_radix(digits, 16).ok()) {
+            Some(code) if digits.unwrap().bytes().all(|b| b.is_ascii_hexdigit()) => {
+                self.position += 4;
+                Ok(code)
+            }
+            _ => self.error("Invalid unicode escape"),
+        }
+    }
+
+    fn digits(&mut self) -> bool {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+        self.position > start
+    }
+
+    // integers stay exact, becoming big ints if need be, and anything with a
+    // fraction or an exponent is a float
+    fn number(&mut self) -> Result<Value, EvaluationError> {
+        let start = self.position;
+        if self.peek() == Some(b'-') {
+            self.position += 1;
+        }
+        if self.peek() == Some(b'0') {
+            self.position += 1;
+        } else if !self.digits() {
+            return self.error("Invalid number");
+        }
+
+        let mut is_float = false;
+        if self.peek() == Some(b'.') {
+            is_float = true;
+            self.position += 1;
+            if !self.digits() {
+                return self.error("Invalid number");
+            }
+        }
+        if let Some(b'e') | Some(b'E') = self.peek() {
+            is_float = true;
+            self.position += 1;
+            if let Some(b'+') | Some(b'-') = self.peek() {
+                self.position += 1;
+            }
+            if !self.digits() {
+                return self.error("Invalid number");
+            }
+        }
+
+        let text = &self.input[start..self.position];
+        if is_float {
+            match text.parse::<f64>() {
+                Ok(value) if value.is_finite() => Ok(Value::Float(value)),
+                _ => self.error("Number is out of range"),
+            }
+        } else if let Ok(value) = text.parse::<i64>() {
+            Ok(Value::Int(value))
+        } else {
+            Ok(Value::BigInt(text.parse::<BigInt>().unwrap()))
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Value, EvaluationError> {
+    let mut parser = Parser {
+        input,
+        position: 0,
+        depth: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.position < input.len() {
+        return parser.error("Unexpected trailing characters");
+    }
+    Ok(value)
+}
+
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+// a line break and then `indent` spaces for each level of depth
+fn write_indentation(out: &mut String, indent: usize, depth: usize) {
+    out.push('\n');
+    for _ in 0..depth {
+        out.extend(iter::repeat_n(' ', indent));
+    }
+}
+
+// the opening bracket, each item on its own line when indenting, and then the
+// closing bracket
+fn write_sequence<I, F>(
+    out: &mut String,
+    brackets: (char, char),
+    items: I,
+    indent: usize,
+    depth: usize,
+    mut write_item: F,
+) -> Result<(), EvaluationError>
+where
+    I: ExactSizeIterator,
+    F: FnMut(&mut String, I::Item) -> Result<(), EvaluationError>,
+{
+    out.push(brackets.0);
+    let empty = items.len() == 0;
+    for (i, item) in items.enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        if indent > 0 {
+            write_indentation(out, indent, depth + 1);
+        }
+        write_item(out, item)?;
+    }
+    if indent > 0 && !empty {
+        write_indentation(out, indent, depth);
+    }
+    out.push(brackets.1);
+    Ok(())
+}
+
+fn write_value(
+    out: &mut String,
+    value: &Value,
+    indent: usize,
+    depth: usize,
+) -> Result<(), EvaluationError> {
+    match value {
+        Value::List(_) | Value::Map(_) if depth == MAX_DEPTH => {
+            return Err(EvaluationError::InvalidArgument(format!(
+                "Can't convert values nested more than {} deep to JSON",
+                MAX_DEPTH
+            )))
+        }
+        Value::Nil => out.push_str("null"),
+        Value::Bool(b) => write!(out, "{}", b).unwrap(),
+        Value::Int(i) => write!(out, "{}", i).unwrap(),
+        Value::BigInt(i) => write!(out, "{}", i).unwrap(),
+        // debug formatting keeps the `.0` of whole floats, so they're read back as floats
+        Value::Float(f) if f.is_finite() => write!(out, "{:?}", f).unwrap(),
+        Value::Float(f) => {
+            return Err(EvaluationError::InvalidArgument(format!(
+                "Can't convert {} to JSON",
+                f
+            )))
+        }
+        Value::String(s) => write_string(out, s),
+        Value::List(items) => {
+            write_sequence(out, ('[', ']'), items.iter(), indent, depth, |out, item| {
+                write_value(out, item, indent, depth + 1)
+            })?
+        }
+        Value::Map(entries) => {
+            write_sequence(out, ('{', '}'), entries.iter(), indent, depth, |out, (key, value)| {
+                write_string(out, key);
+                out.push_str(if indent > 0 { ": " } else { ":" });
+                write_value(out, value, indent, depth + 1)
+            })?
+        }
+        Value::Function(_)
+        | Value::Builtin(_)
+        | Value::NativeFunction(_)
+        | Value::Range(_)
+        | Value::Iterator(_)
+        | Value::Generator(_) => {
+            return Err(EvaluationError::InvalidArgument(format!(
+                "Can't convert a {} to JSON",
+                type_name(value)
+            )))
+        }
+    }
+    Ok(())
+}
+
+/// Writes a value on one line if `indent` is 0, and otherwise with each list
+/// item and map entry on its own line, indented by that many spaces per level,
+/// up to `MAX_INDENT`
+pub fn stringify(value: &Value, indent: i64) -> Result<String, EvaluationError> {
+    if indent < 0 || indent > MAX_INDENT as i64 {
+        return Err(EvaluationError::InvalidArgument(format!(
+            "Can't indent by {} spaces; the most is {}",
+            indent, MAX_INDENT
+        )));
+    }
+    let mut out = String::new();
+    write_value(&mut out, value, indent as usize, 0)?;
+    Ok(out)
+}
+
+pub fn register(runtime: &mut Runtime) {
+    runtime.register_fn("json_parse", 1, |args| match &args[0] {
+        Value::String(s) => parse(s),
+        value => Err(EvaluationError::ConversionError(ConversionError::UnexpectedType(
+            "string",
+            type_name(value),
+        ))),
+    });
+    runtime.register_fn("json_stringify", 2, |args| {
+        let indent = i64::from_value(&args[1])?;
+        Ok(Value::String(Rc::from(stringify(&args[0], indent)?)))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runtime::tests::{assert_evaluates_in, assert_fails};
+    use runtime::EvaluationResult;
+    use vm::ReturnValue;
+
+    #[test]
+    fn test_json() {
+        let cases = vec![
+            (
+                r#" {"name": "Zoë", "tags": ["a", "b"], "age": 31, "ok": true, "none": null} "#,
+                "{\"age\": 31, \"name\": \"Zoë\", \"none\": nil, \"ok\": true, \"tags\": [\"a\", \"b\"]}",
+            ),
+            (r#"[1.5, -0, 1e2, 12345678901234567890]"#, "[1.5, 0, 100, 12345678901234567890]"),
+            (r#""tab\there \"q\" \u00e9 \ud83e\udd80""#, "\"tab\there \"q\" é 🦀\""),
+            (r#"{"a": 1, "a": 2}"#, "{\"a\": 2}"),
+        ];
+        for (document, expected) in cases {
+            let mut runtime = Runtime::new();
+            runtime.define_const("document", Value::String(Rc::from(document)));
+            assert_evaluates_in(&mut runtime, "json_parse(document)", expected);
+        }
+
+        let mut runtime = Runtime::new();
+        runtime.define_const("document", Value::String(Rc::from(r#"{"b": [1, {}], "a": []}"#)));
+        assert_eq!(
+            runtime.evaluate("json_stringify(json_parse(document) 0)"),
+            EvaluationResult::Success(ReturnValue::DisplayString(
+                r#""{"a":[],"b":[1,{}]}""#.to_string()
+            ))
+        );
+        assert_eq!(
+            runtime.evaluate("json_stringify(json_parse(document) 2)"),
+            EvaluationResult::Success(ReturnValue::DisplayString(
+                "\"{\n  \"a\": [],\n  \"b\": [\n    1,\n    {}\n  ]\n}\"".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_json_errors() {
+        let cases = vec![
+            ("[1, 2", "Expected , or ] at position 5"),
+            ("{\"a\" 1}", "Expected : at position 5"),
+            ("{1: 2}", "Expected a string key at position 1"),
+            ("01", "Unexpected trailing characters at position 1"),
+            ("\"\\x\"", "Invalid escape at position 2"),
+            ("\"\\ud800\"", "Expected \\u at position 7"),
+            ("1e999", "Number is out of range at position 5"),
+            ("nul", "Expected null at position 0"),
+            ("", "Unexpected end of input at position 0"),
+        ];
+        for (document, expected) in cases {
+            assert_eq!(
+                parse(document),
+                Err(EvaluationError::InvalidJson(expected.to_string())),
+                "{}",
+                document
+            );
+        }
+        assert_eq!(
+            parse(&"[".repeat(10_000)),
+            Err(EvaluationError::InvalidJson("Too deeply nested at position 512".to_string()))
+        );
+        // values are held to the same depth as documents
+        let nested = |depth| {
+            (0..depth).fold(Value::Nil, |value, _| Value::List(Rc::new(vec![value])))
+        };
+        let document = stringify(&nested(MAX_DEPTH), 0).unwrap();
+        assert_eq!(parse(&document), Ok(nested(MAX_DEPTH)));
+        assert_eq!(
+            stringify(&nested(MAX_DEPTH + 1), 2),
+            Err(EvaluationError::InvalidArgument(
+                "Can't convert values nested more than 512 deep to JSON".to_string()
+            ))
+        );
+        assert_eq!(
+            stringify(&Value::Float(f64::INFINITY), 0),
+            Err(EvaluationError::InvalidArgument("Can't convert inf to JSON".to_string()))
+        );
+
+        let cases = vec![
+            (
+                "let f = () => 1; json_stringify(f 0)",
+                EvaluationError::InvalidArgument("Can't convert a function to JSON".to_string()),
+            ),
+            (
+                "json_stringify(print 0)",
+                EvaluationError::InvalidArgument("Can't convert a function to JSON".to_string()),
+            ),
+            (
+                "json_stringify(1 (-1))",
+                EvaluationError::InvalidArgument(
+                    "Can't indent by -1 spaces; the most is 16".to_string(),
+                ),
+            ),
+            (
+                "json_stringify(chars(\"a\") 9223372036854775807)",
+                EvaluationError::InvalidArgument(
+                    "Can't indent by 9223372036854775807 spaces; the most is 16".to_string(),
+                ),
+            ),
+            (
+                "json_parse(1)",
+                EvaluationError::ConversionError(ConversionError::UnexpectedType("string", "int")),
+            ),
+        ];
+        for (source, expected) in cases {
+            assert_fails(source, expected);
+        }
+    }
+
+    // a xorshift generator, so that the round trip tests see the same
+    // values on every run
+    struct Values(u64);
+
+    impl Values {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: u64) -> usize {
+            (self.next() % n) as usize
+        }
+
+        fn string(&mut self) -> String {
+            let chars = [
+                'a', 'Z', ' ', '"', '\\', '/', '\n', '\t', '\u{1}', '\u{7f}', 'é', '日', '🦀',
+            ];
+            (0..self.below(8)).map(|_| chars[self.below(chars.len() as u64)]).collect()
+        }
+
+        fn value(&mut self, depth: usize) -> Value {
+            let kinds = if depth == 0 { 6 } else { 8 };
+            match self.below(kinds) {
+                0 => Value::Nil,
+                1 => Value::Bool(self.next() & 1 == 0),
+                2 => Value::Int(self.next() as i64 >> self.below(64)),
+                3 => loop {
+                    let f = f64::from_bits(self.next());
+                    if f.is_finite() {
+                        break Value::Float(f);
+                    }
+                },
+                4 => Value::BigInt(BigInt::from(i64::MAX) * BigInt::from(self.next()) + 1),
+                5 => Value::String(Rc::from(self.string())),
+                6 => Value::List(Rc::new((0..self.below(4)).map(|_| self.value(depth - 1)).collect())),
+                _ => {
+                    let mut entries = BTreeMap::new();
+                    for _ in 0..self.below(4) {
+                        entries.insert(self.string(), self.value(depth - 1));
+                    }
+                    Value::Map(Rc::new(entries))
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let mut values = Values(0x2545_f491_4f6c_dd1d);
+        for _ in 0..2000 {
+            let value = values.value(3);
+            for indent in &[0, 2] {
+                let document = stringify(&value, *indent).unwrap();
+                let parsed = parse(&document).unwrap();
+                assert_eq!(parsed, value, "{}", document);
+                assert_eq!(stringify(&parsed, *indent).unwrap(), document);
+            }
+        }
+    }
+}
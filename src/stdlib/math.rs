@@ -0,0 +1,229 @@
+use std::cmp::Ordering;
+use std::f64::consts;
+use std::str::FromStr;
+
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, Signed};
+
+use arithmetic;
+use bytecode::Value;
+use convert::{type_name, ConversionError, FromValue};
+use pretty_print::pretty_print;
+use runtime::Runtime;
+use vm::EvaluationError;
+
+fn not_a_number(value: &Value) -> EvaluationError {
+    EvaluationError::ConversionError(ConversionError::UnexpectedType("number", type_name(value)))
+}
+
+fn invalid(message: String) -> Result<Value, EvaluationError> {
+    Err(EvaluationError::InvalidArgument(message))
+}
+
+// functions of floats, which also accept ints
+fn register_float_fn(runtime: &mut Runtime, name: &str, f: fn(f64) -> f64) {
+    runtime.register_fn(name, 1, move |args| Ok(Value::Float(f(f64::from_value(&args[0])?))));
+}
+
+// ints are already whole, so rounding only applies to floats
+fn register_rounding_fn(runtime: &mut Runtime, name: &str, f: fn(f64) -> f64) {
+    runtime.register_fn(name, 1, move |args| match args[0] {
+        Value::Int(_) | Value::BigInt(_) => Ok(args[0].clone()),
+        Value::Float(x) => Ok(Value::Float(f(x))),
+        ref value => Err(not_a_number(value)),
+    });
+}
+
+fn sqrt(args: &[Value]) -> Result<Value, EvaluationError> {
+    let x = f64::from_value(&args[0])?;
+    if x < 0.0 {
+        return invalid(format!("Can't take the square root of {}", pretty_print(&args[0])));
+    }
+    Ok(Value::Float(x.sqrt()))
+}
+
+// the inverse of sine and cosine are only defined between -1 and 1
+fn register_inverse_trig_fn(runtime: &mut Runtime, name: &'static str, f: fn(f64) -> f64) {
+    runtime.register_fn(name, 1, move |args| {
+        let x = f64::from_value(&args[0])?;
+        if !(-1.0..=1.0).contains(&x) {
+            return invalid(format!("Can't take the {} of {}", name, pretty_print(&args[0])));
+        }
+        Ok(Value::Float(f(x)))
+    });
+}
+
+fn abs(args: &[Value]) -> Result<Value, EvaluationError> {
+    match args[0] {
+        Value::Int(x) => Ok(match x.checked_abs() {
+            Some(v) => Value::Int(v),
+            None => arithmetic::normalize(BigInt::from(x).abs()),
+        }),
+        Value::BigInt(ref x) => Ok(arithmetic::normalize(x.abs())),
+        Value::Float(x) => Ok(Value::Float(x.abs())),
+        ref value => Err(not_a_number(value)),
+    }
+}
+
+// the first argument is returned when they're equal
+fn register_extremum_fn(runtime: &mut Runtime, name: &str, keep: Ordering) {
+    runtime.register_fn(name, 2, move |args| {
+        if arithmetic::compare(&args[0], &args[1])? == keep.reverse() {
+            Ok(args[1].clone())
+        } else {
+            Ok(args[0].clone())
+        }
+    });
+}
+
+/// Floats are truncated towards zero, and strings are parsed as decimal
+fn int(args: &[Value]) -> Result<Value, EvaluationError> {
+    match args[0] {
+        Value::Int(_) | Value::BigInt(_) => Ok(args[0].clone()),
+        Value::Float(x) => match BigInt::from_f64(x.trunc()) {
+            Some(v) => Ok(arithmetic::normalize(v)),
+            None => invalid(format!("Can't convert {} to an int", x)),
+        },
+        Value::Bool(x) => Ok(Value::Int(x as i64)),
+        Value::String(ref s) => match BigInt::from_str(s.trim()) {
+            Ok(v) => Ok(arithmetic::normalize(v)),
+            Err(_) => invalid(format!("Can't convert {} to an int", pretty_print(&args[0]))),
+        },
+        ref value => Err(not_a_number(value)),
+    }
+}
+
+fn float(args: &[Value]) -> Result<Value, EvaluationError> {
+    match args[0] {
+        Value::Int(_) | Value::BigInt(_) | Value::Float(_) => Ok(Value::Float(f64::from_value(&args[0])?)),
+        Value::Bool(x) => Ok(Value::Float(if x { 1.0 } else { 0.0 })),
+        Value::String(ref s) => match f64::from_str(s.trim()) {
+            Ok(v) if v.is_finite() => Ok(Value::Float(v)),
+            _ => invalid(format!("Can't convert {} to a float", pretty_print(&args[0]))),
+        },
+        ref value => Err(not_a_number(value)),
+    }
+}
+
+pub fn register(runtime: &mut Runtime) {
+    runtime.define_const("pi", Value::Float(consts::PI));
+    runtime.define_const("e", Value::Float(consts::E));
+
+    runtime.register_fn("sqrt", 1, sqrt);
+    runtime.register_fn("pow", 2, |args| arithmetic::pow(args[0].clone(), args[1].clone()));
+    runtime.register_fn("abs", 1, abs);
+    register_rounding_fn(runtime, "floor", f64::floor);
+    register_rounding_fn(runtime, "ceil", f64::ceil);
+    // halfway cases round away from zero
+    register_rounding_fn(runtime, "round", f64::round);
+    register_extremum_fn(runtime, "min", Ordering::Less);
+    register_extremum_fn(runtime, "max", Ordering::Greater);
+
+    register_float_fn(runtime, "sin", f64::sin);
+    register_float_fn(runtime, "cos", f64::cos);
+    register_float_fn(runtime, "tan", f64::tan);
+    register_float_fn(runtime, "atan", f64::atan);
+    register_inverse_trig_fn(runtime, "asin", f64::asin);
+    register_inverse_trig_fn(runtime, "acos", f64::acos);
+    runtime.register_fn("atan2", 2, |args| {
+        let y = f64::from_value(&args[0])?;
+        let x = f64::from_value(&args[1])?;
+        Ok(Value::Float(y.atan2(x)))
+    });
+
+    runtime.register_fn("int", 1, int);
+    runtime.register_fn("float", 1, float);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runtime::tests::assert_fails;
+    use runtime::EvaluationResult;
+    use vm::ReturnValue;
+
+    #[test]
+    fn test_math() {
+        let big = |digits: &str| Value::BigInt(BigInt::from_str(digits).unwrap());
+        let cases = vec![
+            ("sqrt(16)", Value::Float(4.0)),
+            ("sqrt(2.25)", Value::Float(1.5)),
+            ("pow(2 10)", Value::Int(1024)),
+            // equal floats are checked by subtracting them, since there are no comparisons
+            ("pow(2 0.5) - sqrt(2)", Value::Float(0.0)),
+            ("pow(2 64)", big("18446744073709551616")),
+            ("abs((-3))", Value::Int(3)),
+            ("abs((-2.5))", Value::Float(2.5)),
+            ("abs((-9223372036854775808))", big("9223372036854775808")),
+            ("floor(2.7)", Value::Float(2.0)),
+            ("floor((-2.5))", Value::Float(-3.0)),
+            ("ceil(2.1)", Value::Float(3.0)),
+            ("round(2.5)", Value::Float(3.0)),
+            ("round((-2.5))", Value::Float(-3.0)),
+            ("floor(7)", Value::Int(7)),
+            ("min(3 2.5)", Value::Float(2.5)),
+            ("max(3 2.5)", Value::Int(3)),
+            ("max(2 2.0)", Value::Int(2)),
+            ("min(pow(2 70) 1)", Value::Int(1)),
+            ("sin(0)", Value::Float(0.0)),
+            ("cos(pi)", Value::Float(-1.0)),
+            ("atan2(1 1) * 4 - pi", Value::Float(0.0)),
+            ("asin(1) * 2 - pi", Value::Float(0.0)),
+            ("e", Value::Float(consts::E)),
+            ("int(2.9)", Value::Int(2)),
+            ("int((-2.9))", Value::Int(-2)),
+            ("int(1e20)", big("100000000000000000000")),
+            ("int(\" 42 \")", Value::Int(42)),
+            ("int(true)", Value::Int(1)),
+            ("float(3)", Value::Float(3.0)),
+            ("float(\"2.5\")", Value::Float(2.5)),
+        ];
+        for (source, expected) in cases {
+            assert_eq!(
+                Runtime::new().evaluate(source),
+                EvaluationResult::Success(ReturnValue::Value(expected)),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_math_errors() {
+        let cases = vec![
+            (
+                "sqrt((-1))",
+                EvaluationError::InvalidArgument("Can't take the square root of -1".to_string()),
+            ),
+            ("acos(2)", EvaluationError::InvalidArgument("Can't take the acos of 2".to_string())),
+            (
+                "int(\"4x\")",
+                EvaluationError::InvalidArgument("Can't convert \"4x\" to an int".to_string()),
+            ),
+            (
+                "int((float(\"1e308\") * 10))",
+                EvaluationError::InvalidArgument("Can't convert inf to an int".to_string()),
+            ),
+            (
+                "float(\"nan\")",
+                EvaluationError::InvalidArgument("Can't convert \"nan\" to a float".to_string()),
+            ),
+            (
+                "abs(\"3\")",
+                EvaluationError::ConversionError(ConversionError::UnexpectedType("number", "string")),
+            ),
+            (
+                "sin(true)",
+                EvaluationError::ConversionError(ConversionError::UnexpectedType("float", "bool")),
+            ),
+            (
+                "max(1 \"2\")",
+                EvaluationError::InvalidOperation("Can't compare 1 and \"2\"".to_string()),
+            ),
+            ("pow(2 (-1))", EvaluationError::NegativeExponent),
+        ];
+        for (source, expected) in cases {
+            assert_fails(source, expected);
+        }
+    }
+}
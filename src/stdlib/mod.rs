@@ -0,0 +1,23 @@
+//! Native functions and constants that every runtime defines
+
+pub mod collections;
+pub mod fs;
+pub mod json;
+pub mod math;
+pub mod random;
+pub mod regex;
+pub mod string;
+pub mod time;
+
+use runtime::Runtime;
+
+pub fn register(runtime: &mut Runtime) {
+    collections::register(runtime);
+    fs::register(runtime);
+    json::register(runtime);
+    math::register(runtime);
+    random::register(runtime);
+    regex::register(runtime);
+    string::register(runtime);
+    time::register(runtime);
+}
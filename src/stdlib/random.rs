@@ -0,0 +1,163 @@
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bytecode::Value;
+use convert::FromValue;
+use runtime::Runtime;
+use vm::EvaluationError;
+
+/// A SplitMix64 generator, which gives the same numbers for a seed on every
+/// platform and in every version, so that scripts' output can be compared
+/// between runs
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Default for Rng {
+    // seeded from the time, for when reproducing a run doesn't matter
+    fn default() -> Rng {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        Rng::new(nanos)
+    }
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A float in [0, 1), from the top 53 bits
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// An int in [0, n), rejecting the values that would make some results
+    /// more likely than others
+    pub fn below(&mut self, n: u64) -> u64 {
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return value % n;
+            }
+        }
+    }
+}
+
+/// An int between `lo` and `hi`, including both
+fn random_int(rng: &mut Rng, args: &[Value]) -> Result<Value, EvaluationError> {
+    let lo = i64::from_value(&args[0])?;
+    let hi = i64::from_value(&args[1])?;
+    if lo > hi {
+        return Err(EvaluationError::InvalidArgument(format!(
+            "Can't pick an int between {} and {}",
+            lo, hi
+        )));
+    }
+    let span = (i128::from(hi) - i128::from(lo) + 1) as u128;
+    let offset = if span > u128::from(u64::MAX) {
+        rng.next_u64()
+    } else {
+        rng.below(span as u64)
+    };
+    Ok(Value::Int((i128::from(lo) + i128::from(offset)) as i64))
+}
+
+/// A shuffled copy of a list
+fn shuffle(rng: &mut Rng, args: &[Value]) -> Result<Value, EvaluationError> {
+    let mut items = Vec::<Value>::from_value(&args[0])?;
+    for i in (1..items.len()).rev() {
+        items.swap(i, rng.below(i as u64 + 1) as usize);
+    }
+    Ok(Value::List(Rc::new(items)))
+}
+
+fn register_random_fn(
+    runtime: &mut Runtime,
+    name: &str,
+    arity: usize,
+    f: fn(&mut Rng, &[Value]) -> Result<Value, EvaluationError>,
+) {
+    let rng = Rc::clone(&runtime.rng);
+    runtime.register_fn(name, arity, move |args| f(&mut rng.borrow_mut(), args));
+}
+
+pub fn register(runtime: &mut Runtime) {
+    register_random_fn(runtime, "random", 0, |rng, _| Ok(Value::Float(rng.next_f64())));
+    register_random_fn(runtime, "random_int", 2, random_int);
+    register_random_fn(runtime, "shuffle", 1, shuffle);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_print::pretty_print;
+    use runtime::EvaluationResult;
+    use vm::ReturnValue;
+
+    #[test]
+    fn test_random() {
+        fn value(runtime: &mut Runtime, source: &str) -> Value {
+            match runtime.evaluate(source) {
+                EvaluationResult::Success(ReturnValue::Value(v)) => v,
+                result => panic!("{}: {:?}", source, result),
+            }
+        }
+        let run = |seed: u64| {
+            let mut runtime = Runtime::new();
+            runtime.set_seed(seed);
+            let mut values = Vec::new();
+            for _ in 0..20 {
+                values.push(value(&mut runtime, "random()"));
+                values.push(value(&mut runtime, "random_int((-3) 3)"));
+                values.push(value(&mut runtime, "shuffle(chars(\"abcdef\"))"));
+            }
+            values
+        };
+        assert_eq!(run(42), run(42));
+        assert_ne!(run(42), run(43));
+        // the numbers for a seed mustn't change between versions either
+        let mut runtime = Runtime::new();
+        runtime.set_seed(42);
+        assert_eq!(pretty_print(&value(&mut runtime, "random_int(1 1000)")), "414");
+        assert_eq!(
+            pretty_print(&value(&mut runtime, "shuffle(chars(\"abcdef\"))")),
+            "[\"c\", \"e\", \"f\", \"a\", \"d\", \"b\"]"
+        );
+
+        let mut seen = Vec::new();
+        for _ in 0..200 {
+            let f = f64::from_value(&value(&mut runtime, "random()")).unwrap();
+            assert!((0.0..1.0).contains(&f), "{}", f);
+            seen.push(i64::from_value(&value(&mut runtime, "random_int((-3) 3)")).unwrap());
+        }
+        seen.sort();
+        seen.dedup();
+        assert_eq!(seen, vec![-3, -2, -1, 0, 1, 2, 3]);
+
+        // the whole range of ints doesn't overflow
+        let source = "random_int((-9223372036854775807 - 1) 9223372036854775807)";
+        match value(&mut runtime, source) {
+            Value::Int(_) => (),
+            v => panic!("{:?}", v),
+        }
+        assert_eq!(
+            runtime.evaluate("random_int(2 1)"),
+            EvaluationResult::EvaluationError(EvaluationError::InvalidArgument(
+                "Can't pick an int between 2 and 1".to_string()
+            ))
+        );
+    }
+}
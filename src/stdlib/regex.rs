@@ -0,0 +1,169 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+
+use regex::Regex;
+
+use bytecode::Value;
+use convert::{FromValue, IntoValue};
+use runtime::Runtime;
+use vm::EvaluationError;
+
+// the cache is emptied when it's full, so that scripts building patterns
+// dynamically can't grow it without bound
+const CACHE_CAPACITY: usize = 64;
+
+/// Compiled patterns, shared by the regex functions of one runtime
+#[derive(Default)]
+struct PatternCache {
+    patterns: HashMap<String, Rc<Regex>>,
+}
+
+impl PatternCache {
+    fn get(&mut self, pattern: &str) -> Result<Rc<Regex>, EvaluationError> {
+        if let Some(regex) = self.patterns.get(pattern) {
+            return Ok(Rc::clone(regex));
+        }
+        let regex = Rc::new(
+            Regex::new(pattern).map_err(|e| EvaluationError::InvalidRegex(e.to_string()))?,
+        );
+        if self.patterns.len() >= CACHE_CAPACITY {
+            self.patterns.clear();
+        }
+        self.patterns.insert(pattern.to_string(), Rc::clone(&regex));
+        Ok(regex)
+    }
+}
+
+// the pattern and the string to search are the first two arguments of every
+// regex function
+fn register_regex_fn<F>(
+    runtime: &mut Runtime,
+    cache: &Rc<RefCell<PatternCache>>,
+    name: &str,
+    arity: usize,
+    f: F,
+) where
+    F: Fn(&Regex, &str, &[Value]) -> Result<Value, EvaluationError> + 'static,
+{
+    let cache = Rc::clone(cache);
+    runtime.register_fn(name, arity, move |args| {
+        let regex = cache.borrow_mut().get(&String::from_value(&args[0])?)?;
+        f(&regex, &String::from_value(&args[1])?, &args[2..])
+    });
+}
+
+/// Whether the pattern matches anywhere in the string
+fn re_match(regex: &Regex, s: &str, _: &[Value]) -> Result<Value, EvaluationError> {
+    Ok(Value::Bool(regex.is_match(s)))
+}
+
+fn re_find_all(regex: &Regex, s: &str, _: &[Value]) -> Result<Value, EvaluationError> {
+    Ok(regex.find_iter(s).map(|m| m.as_str()).collect::<Vec<&str>>().into_value())
+}
+
+/// Replaces every match, where `$1` or `$name` in the replacement refer to groups
+fn re_replace(regex: &Regex, s: &str, args: &[Value]) -> Result<Value, EvaluationError> {
+    let replacement = String::from_value(&args[0])?;
+    Ok(regex.replace_all(s, replacement.as_str()).into_owned().into_value())
+}
+
+/// The groups of the first match, by number and by name, or nil if there's
+/// no match; groups that didn't take part in the match are nil
+fn re_captures(regex: &Regex, s: &str, _: &[Value]) -> Result<Value, EvaluationError> {
+    let captures = match regex.captures(s) {
+        Some(captures) => captures,
+        None => return Ok(Value::Nil),
+    };
+    let group = |m: Option<::regex::Match>| m.map(|m| m.as_str()).into_value();
+
+    let mut groups = BTreeMap::new();
+    for (i, name) in regex.capture_names().enumerate() {
+        groups.insert(i.to_string(), group(captures.get(i)));
+        if let Some(name) = name {
+            groups.insert(name.to_string(), group(captures.get(i)));
+        }
+    }
+    Ok(Value::Map(Rc::new(groups)))
+}
+
+pub fn register(runtime: &mut Runtime) {
+    register_with_cache(runtime, &Rc::new(RefCell::new(PatternCache::default())));
+}
+
+fn register_with_cache(runtime: &mut Runtime, cache: &Rc<RefCell<PatternCache>>) {
+    register_regex_fn(runtime, cache, "re_match", 2, re_match);
+    register_regex_fn(runtime, cache, "re_find_all", 2, re_find_all);
+    register_regex_fn(runtime, cache, "re_replace", 3, re_replace);
+    register_regex_fn(runtime, cache, "re_captures", 2, re_captures);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use convert::ConversionError;
+    use runtime::tests::assert_evaluates;
+    use runtime::EvaluationResult;
+    use vm::ReturnValue;
+
+    #[test]
+    fn test_regex() {
+        let cases = vec![
+            ("re_match(\"^\\d+$\" \"2024\")", "true"),
+            ("re_match(\"^\\d+$\" \"20x4\")", "false"),
+            ("re_find_all(\"\\w+\" \"héllo wörld\")", "[\"héllo\", \"wörld\"]"),
+            ("re_find_all(\"x\" \"abc\")", "[]"),
+            ("re_replace(\"(\\w+)@(\\w+)\" \"ann@home bob@work\" \"$2:$1\")", "\"home:ann work:bob\""),
+            (
+                "re_captures(\"(?P<year>\\d{4})-(\\d{2})(-x)?\" \"on 2024-05\")",
+                "{\"0\": \"2024-05\", \"1\": \"2024\", \"2\": \"05\", \"3\": nil, \"year\": \"2024\"}",
+            ),
+            ("re_captures(\"\\d\" \"abc\")", "nil"),
+        ];
+        for (source, expected) in cases {
+            assert_evaluates(source, expected);
+        }
+
+        // the regex functions share one cache of compiled patterns, which
+        // compiles each pattern once
+        let cache = Rc::new(RefCell::new(PatternCache::default()));
+        let mut runtime = Runtime::new();
+        register_with_cache(&mut runtime, &cache);
+        runtime.evaluate("let p = \"a+\"; re_match(p \"aa\"); re_find_all(p \"a b aa\")");
+        assert_eq!(
+            runtime.evaluate("re_replace(p \"caat\" \"o\")"),
+            EvaluationResult::Success(ReturnValue::DisplayString("\"cot\"".to_string()))
+        );
+        runtime.evaluate("re_captures(\"b\" \"abc\")");
+        assert_eq!(cache.borrow().patterns.len(), 2);
+        let first = cache.borrow_mut().get("a+").unwrap();
+        assert!(Rc::ptr_eq(&first, &cache.borrow_mut().get("a+").unwrap()));
+
+        // patterns that don't compile aren't cached
+        runtime.evaluate("re_match(\"(unclosed\" \"x\")");
+        assert_eq!(cache.borrow().patterns.len(), 2);
+
+        // it's emptied when a new pattern arrives and it's full
+        for i in 2..CACHE_CAPACITY {
+            runtime.evaluate(&format!("re_match(\"{}\" \"x\")", i));
+        }
+        assert_eq!(cache.borrow().patterns.len(), CACHE_CAPACITY);
+        runtime.evaluate("re_match(p \"x\")");
+        assert_eq!(cache.borrow().patterns.len(), CACHE_CAPACITY);
+        runtime.evaluate("re_match(\"new\" \"x\")");
+        assert_eq!(cache.borrow().patterns.len(), 1);
+        assert!(!Rc::ptr_eq(&first, &cache.borrow_mut().get("a+").unwrap()));
+
+        let mut runtime = Runtime::new();
+        match runtime.evaluate("re_match(\"(unclosed\" \"x\")") {
+            EvaluationResult::EvaluationError(EvaluationError::InvalidRegex(_)) => (),
+            result => panic!("{:?}", result),
+        }
+        assert_eq!(
+            runtime.evaluate("re_find_all(1 \"x\")"),
+            EvaluationResult::EvaluationError(EvaluationError::ConversionError(
+                ConversionError::UnexpectedType("string", "int")
+            ))
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use std::rc::Rc;
+
+use bytecode::Value;
+use convert::{type_name, ConversionError, FromValue, IntoValue};
+use pretty_print::display;
+use runtime::Runtime;
+use vm::EvaluationError;
+
+// the longest string, in bytes, that `repeat` will make
+const MAX_REPEAT_LEN: u64 = 1 << 24;
+
+// borrows a string argument rather than copying it, as `String::from_value` would
+fn string_arg(value: &Value) -> Result<&str, EvaluationError> {
+    match value {
+        Value::String(s) => Ok(s),
+        value => Err(EvaluationError::ConversionError(ConversionError::UnexpectedType(
+            "string",
+            type_name(value),
+        ))),
+    }
+}
+
+fn string(s: String) -> Value {
+    Value::String(Rc::from(s))
+}
+
+fn split(args: &[Value]) -> Result<Value, EvaluationError> {
+    let s = string_arg(&args[0])?;
+    let separator = string_arg(&args[1])?;
+    if separator.is_empty() {
+        return Err(EvaluationError::InvalidArgument(
+            "Can't split on an empty separator".to_string(),
+        ));
+    }
+    Ok(s.split(separator).collect::<Vec<&str>>().into_value())
+}
+
+fn join(args: &[Value]) -> Result<Value, EvaluationError> {
+    let parts = Vec::<String>::from_value(&args[0])?;
+    Ok(string(parts.join(string_arg(&args[1])?)))
+}
+
+fn register_string_fn(runtime: &mut Runtime, name: &str, f: fn(&str) -> String) {
+    runtime.register_fn(name, 1, move |args| Ok(string(f(string_arg(&args[0])?))));
+}
+
+fn register_predicate(runtime: &mut Runtime, name: &str, f: fn(&str, &str) -> bool) {
+    runtime.register_fn(name, 2, move |args| {
+        Ok(Value::Bool(f(string_arg(&args[0])?, string_arg(&args[1])?)))
+    });
+}
+
+fn replace(args: &[Value]) -> Result<Value, EvaluationError> {
+    let s = string_arg(&args[0])?;
+    Ok(string(s.replace(string_arg(&args[1])?, string_arg(&args[2])?)))
+}
+
+/// Positions count characters rather than bytes, and nil means not found
+fn find(args: &[Value]) -> Result<Value, EvaluationError> {
+    let s = string_arg(&args[0])?;
+    let position = s
+        .find(string_arg(&args[1])?)
+        .map(|byte| s[..byte].chars().count() as i64);
+    Ok(position.into_value())
+}
+
+fn chars(args: &[Value]) -> Result<Value, EvaluationError> {
+    let s = string_arg(&args[0])?;
+    Ok(s.chars().map(String::from).collect::<Vec<String>>().into_value())
+}
+
+fn repeat(args: &[Value]) -> Result<Value, EvaluationError> {
+    let s = string_arg(&args[0])?;
+    let count = i64::from_value(&args[1])?;
+    if count < 0 {
+        return Err(EvaluationError::InvalidArgument(format!(
+            "Can't repeat a string {} times",
+            count
+        )));
+    }
+    match (s.len() as u64).checked_mul(count as u64) {
+        Some(len) if len <= MAX_REPEAT_LEN => Ok(string(s.repeat(count as usize))),
+        _ => Err(EvaluationError::InvalidArgument(format!(
+            "Can't repeat a string of {} bytes {} times",
+            s.len(),
+            count
+        ))),
+    }
+}
+
+/// Replaces each `{}` in the template with the next argument, as `print`
+/// would show it; `{{` and `}}` are literal braces
+fn format(args: &[Value]) -> Result<Value, EvaluationError> {
+    let (template, values) = match args.split_first() {
+        Some((template, values)) => (string_arg(template)?, values),
+        None => return Err(EvaluationError::IncorrectArity(1, 0)),
+    };
+
+    let mut result = String::new();
+    let mut values = values.iter();
+    let mut placeholders = 0;
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('{', Some('{')) | ('}', Some('}')) => {
+                chars.next();
+                result.push(c);
+            }
+            ('{', Some('}')) => {
+                chars.next();
+                placeholders += 1;
+                if let Some(value) = values.next() {
+                    result.push_str(&display(value));
+                }
+            }
+            ('{', _) | ('}', _) => {
+                return Err(EvaluationError::InvalidArgument(format!(
+                    "Unmatched {} in format string",
+                    c
+                )))
+            }
+            _ => result.push(c),
+        }
+    }
+
+    let given = args.len() - 1;
+    if placeholders != given {
+        return Err(EvaluationError::InvalidArgument(format!(
+            "Format string has {} placeholders but {} values were given",
+            placeholders, given
+        )));
+    }
+    Ok(string(result))
+}
+
+pub fn register(runtime: &mut Runtime) {
+    runtime.register_fn("split", 2, split);
+    runtime.register_fn("join", 2, join);
+    register_string_fn(runtime, "trim", |s| s.trim().to_string());
+    register_string_fn(runtime, "upper", str::to_uppercase);
+    register_string_fn(runtime, "lower", str::to_lowercase);
+    runtime.register_fn("replace", 3, replace);
+    register_predicate(runtime, "contains", |s, sub| s.contains(sub));
+    register_predicate(runtime, "starts_with", |s, prefix| s.starts_with(prefix));
+    runtime.register_fn("find", 2, find);
+    runtime.register_fn("chars", 1, chars);
+    runtime.register_fn("repeat", 2, repeat);
+    runtime.register_variadic_fn("format", format);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runtime::tests::{assert_evaluates, assert_fails};
+
+    #[test]
+    fn test_strings() {
+        let cases = vec![
+            ("split(\"a,b,,c\" \",\")", "[\"a\", \"b\", \"\", \"c\"]"),
+            ("split(\"naïve café\" \"ï\")", "[\"na\", \"ve café\"]"),
+            ("join(split(\"a b c\" \" \") \"→\")", "\"a→b→c\""),
+            ("trim(\"  ünï  \")", "\"ünï\""),
+            ("upper(\"straße\")", "\"STRASSE\""),
+            ("lower(\"ÀÉÎ\")", "\"àéî\""),
+            ("replace(\"日本語の本\" \"本\" \"ほん\")", "\"日ほん語のほん\""),
+            ("contains(\"crème brûlée\" \"brû\")", "true"),
+            ("starts_with(\"🦀 rust\" \"🦀\")", "true"),
+            ("find(\"日本語\" \"語\")", "2"),
+            ("find(\"日本語\" \"x\")", "nil"),
+            ("chars(\"añ🦀\")", "[\"a\", \"ñ\", \"🦀\"]"),
+            ("repeat(\"ab\" 3)", "\"ababab\""),
+            ("format(\"{} is {} {{ok}}\" \"π\" 3.14)", "\"π is 3.14 {ok}\""),
+            ("format(\"{}\" chars(\"hé\"))", "\"[\"h\", \"é\"]\""),
+        ];
+        for (source, expected) in cases {
+            assert_evaluates(source, expected);
+        }
+    }
+
+    #[test]
+    fn test_string_errors() {
+        let cases = vec![
+            (
+                "split(\"abc\" \"\")",
+                EvaluationError::InvalidArgument("Can't split on an empty separator".to_string()),
+            ),
+            (
+                "repeat(\"a\" (-1))",
+                EvaluationError::InvalidArgument("Can't repeat a string -1 times".to_string()),
+            ),
+            (
+                "repeat(\"ab\" 9223372036854775807)",
+                EvaluationError::InvalidArgument(
+                    "Can't repeat a string of 2 bytes 9223372036854775807 times".to_string(),
+                ),
+            ),
+            (
+                "repeat(\"ab\" 10000000)",
+                EvaluationError::InvalidArgument(
+                    "Can't repeat a string of 2 bytes 10000000 times".to_string(),
+                ),
+            ),
+            (
+                "format(\"{} {}\" 1)",
+                EvaluationError::InvalidArgument(
+                    "Format string has 2 placeholders but 1 values were given".to_string(),
+                ),
+            ),
+            (
+                "format(\"{\")",
+                EvaluationError::InvalidArgument("Unmatched { in format string".to_string()),
+            ),
+            (
+                "upper(1)",
+                EvaluationError::ConversionError(ConversionError::UnexpectedType("string", "int")),
+            ),
+            (
+                "join(chars(\"ab\") 1)",
+                EvaluationError::ConversionError(ConversionError::UnexpectedType("string", "int")),
+            ),
+        ];
+        for (source, expected) in cases {
+            assert_fails(source, expected);
+        }
+    }
+}
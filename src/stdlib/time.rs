@@ -0,0 +1,131 @@
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use bytecode::Value;
+use runtime::Runtime;
+
+/// Where `now` and `monotonic` get the time from, which tests can replace
+/// with a `FakeClock`
+pub trait Clock {
+    /// Seconds since the Unix epoch
+    fn now(&self) -> f64;
+
+    /// Seconds since some fixed point, which never go backwards
+    fn monotonic(&self) -> f64;
+}
+
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl Default for SystemClock {
+    fn default() -> SystemClock {
+        SystemClock {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> f64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs_f64())
+            .unwrap_or(0.0)
+    }
+
+    // seconds since the clock was made
+    fn monotonic(&self) -> f64 {
+        self.start.elapsed().as_secs_f64()
+    }
+}
+
+/// A clock that only moves when it's told to. Clones share the same time, so
+/// a test can keep one and advance the clone it gave to a runtime.
+#[derive(Debug, Clone, Default)]
+pub struct FakeClock {
+    now: Rc<Cell<f64>>,
+    monotonic: Rc<Cell<f64>>,
+}
+
+impl FakeClock {
+    /// A clock at `now` seconds since the Unix epoch, and 0 monotonic seconds
+    pub fn new(now: f64) -> FakeClock {
+        FakeClock {
+            now: Rc::new(Cell::new(now)),
+            monotonic: Rc::new(Cell::new(0.0)),
+        }
+    }
+
+    pub fn advance(&self, seconds: f64) {
+        self.now.set(self.now.get() + seconds);
+        self.monotonic.set(self.monotonic.get() + seconds);
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> f64 {
+        self.now.get()
+    }
+
+    fn monotonic(&self) -> f64 {
+        self.monotonic.get()
+    }
+}
+
+/// The clock shared by a runtime and its time functions
+#[derive(Clone)]
+pub struct SharedClock(pub Rc<RefCell<Box<dyn Clock>>>);
+
+impl Default for SharedClock {
+    fn default() -> SharedClock {
+        SharedClock(Rc::new(RefCell::new(Box::new(SystemClock::default()))))
+    }
+}
+
+impl fmt::Debug for SharedClock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Clock")
+    }
+}
+
+pub fn register(runtime: &mut Runtime) {
+    let clock = runtime.clock.clone();
+    runtime.register_fn("now", 0, move |_| Ok(Value::Float(clock.0.borrow().now())));
+    let clock = runtime.clock.clone();
+    runtime.register_fn("monotonic", 0, move |_| Ok(Value::Float(clock.0.borrow().monotonic())));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runtime::EvaluationResult;
+    use vm::ReturnValue;
+
+    #[test]
+    fn test_clock() {
+        let clock = FakeClock::new(1_700_000_000.5);
+        let mut runtime = Runtime::new();
+        runtime.set_clock(Box::new(clock.clone()));
+        runtime.evaluate("let start = monotonic()");
+
+        clock.advance(2.25);
+        assert_eq!(
+            runtime.evaluate("now()"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Float(1_700_000_002.75)))
+        );
+        assert_eq!(
+            runtime.evaluate("monotonic() - start"),
+            EvaluationResult::Success(ReturnValue::Value(Value::Float(2.25)))
+        );
+
+        // the system clock is used by default
+        let mut runtime = Runtime::new();
+        match runtime.evaluate("now()") {
+            EvaluationResult::Success(ReturnValue::Value(Value::Float(now))) => assert!(now > 1.5e9),
+            result => panic!("{:?}", result),
+        }
+    }
+}
@@ -1,21 +1,233 @@
-use std::collections::HashMap;
-use std::str;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, Write};
+use std::mem;
+use std::ptr;
+use std::rc::Rc;
 
-use bytecode::{Heap, Instruction, SymbolTable, Value};
-use pretty_print::pretty_print;
+use arithmetic;
+use bytecode::{Builtin, Capture, Cell, Chunk, Closure, Globals, Instruction, Iter, Range, Value};
+use convert::ConversionError;
+use pretty_print::{display, pretty_print};
 
 #[derive(Debug, PartialEq)]
 pub enum EvaluationError {
     MissingVariable(String),
     InvalidOperation(String),
+    StackOverflow(usize),
+    StackUnderflow,
+    DivisionByZero,
+    IntegerOverflow,
+    NegativeExponent,
+    NegativeShiftCount,
+    // the number of arguments a function expects, and the number it was called with
+    IncorrectArity(usize, usize),
+    InvalidSlot(u16),
+    InvalidConstant(u16),
+    IoError(String),
+    ConversionError(ConversionError),
+    // an error returned by a native function
+    NativeError(String),
+    // an argument of the right type that a function still can't accept
+    InvalidArgument(String),
+    InvalidRegex(String),
+    InvalidJson(String),
+    // a file function was used on a path its capabilities don't allow
+    PermissionDenied(String),
+    // a value thrown by a script that nothing caught
+    Thrown(Value),
 }
 
-#[derive(Debug, Default)]
+impl EvaluationError {
+    /// The name scripts see for the error in the `kind` of a caught error
+    pub fn kind(&self) -> &'static str {
+        match self {
+            EvaluationError::MissingVariable(_) => "MissingVariable",
+            EvaluationError::InvalidOperation(_) => "InvalidOperation",
+            EvaluationError::StackOverflow(_) => "StackOverflow",
+            EvaluationError::StackUnderflow => "StackUnderflow",
+            EvaluationError::DivisionByZero => "DivisionByZero",
+            EvaluationError::IntegerOverflow => "IntegerOverflow",
+            EvaluationError::NegativeExponent => "NegativeExponent",
+            EvaluationError::NegativeShiftCount => "NegativeShiftCount",
+            EvaluationError::IncorrectArity(_, _) => "IncorrectArity",
+            EvaluationError::InvalidSlot(_) => "InvalidSlot",
+            EvaluationError::InvalidConstant(_) => "InvalidConstant",
+            EvaluationError::IoError(_) => "IoError",
+            EvaluationError::ConversionError(_) => "ConversionError",
+            EvaluationError::NativeError(_) => "NativeError",
+            EvaluationError::InvalidArgument(_) => "InvalidArgument",
+            EvaluationError::InvalidRegex(_) => "InvalidRegex",
+            EvaluationError::InvalidJson(_) => "InvalidJson",
+            EvaluationError::PermissionDenied(_) => "PermissionDenied",
+            EvaluationError::Thrown(_) => "Thrown",
+        }
+    }
+
+    // errors that mean the bytecode itself is broken can't be caught, since
+    // the frame they happened in can't be trusted to carry on
+    fn is_catchable(&self) -> bool {
+        !matches!(
+            self,
+            EvaluationError::StackUnderflow
+                | EvaluationError::InvalidSlot(_)
+                | EvaluationError::InvalidConstant(_)
+        )
+    }
+
+    /// What a catch block is given: the value itself if one was thrown, and
+    /// otherwise a map of the error's `kind` and `message`
+    pub fn into_value(self) -> Value {
+        if let EvaluationError::Thrown(value) = self {
+            return value;
+        }
+        let mut entries = BTreeMap::new();
+        entries.insert("kind".to_string(), Value::String(Rc::from(self.kind())));
+        entries.insert("message".to_string(), Value::String(Rc::from(self.to_string())));
+        Value::Map(Rc::new(entries))
+    }
+}
+
+impl fmt::Display for EvaluationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EvaluationError::MissingVariable(name) => write!(f, "{} isn't defined", name),
+            EvaluationError::StackOverflow(depth) => {
+                write!(f, "Stack overflow at a call depth of {}", depth)
+            }
+            EvaluationError::StackUnderflow => f.write_str("Stack underflow"),
+            EvaluationError::DivisionByZero => f.write_str("Division by zero"),
+            EvaluationError::IntegerOverflow => f.write_str("Integer overflow"),
+            EvaluationError::NegativeExponent => f.write_str("Negative exponent"),
+            EvaluationError::NegativeShiftCount => f.write_str("Negative shift count"),
+            EvaluationError::IncorrectArity(expected, found) => {
+                write!(f, "Expected {} arguments but got {}", expected, found)
+            }
+            EvaluationError::InvalidSlot(slot) => write!(f, "Invalid slot {}", slot),
+            EvaluationError::InvalidConstant(index) => write!(f, "Invalid constant {}", index),
+            EvaluationError::ConversionError(e) => write!(f, "Conversion error: {}", e),
+            EvaluationError::InvalidRegex(message) => write!(f, "Invalid regex: {}", message),
+            EvaluationError::InvalidJson(message) => write!(f, "Invalid JSON: {}", message),
+            EvaluationError::PermissionDenied(path) => write!(f, "Permission denied: {}", path),
+            EvaluationError::Thrown(value) => write!(f, "Uncaught {}", pretty_print(value)),
+            EvaluationError::InvalidOperation(message)
+            | EvaluationError::IoError(message)
+            | EvaluationError::NativeError(message)
+            | EvaluationError::InvalidArgument(message) => f.write_str(message),
+        }
+    }
+}
+
+impl From<ConversionError> for EvaluationError {
+    fn from(e: ConversionError) -> EvaluationError {
+        EvaluationError::ConversionError(e)
+    }
+}
+
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 1000;
+
+// calls between script functions only add to `frames`, but each time native
+// code calls back into a script, or a generator is resumed, the VM runs inside
+// another Rust call, which takes tens of kilobytes of native stack in a debug
+// build; these are limited separately so that they fail before the stack does
+pub const DEFAULT_MAX_NATIVE_DEPTH: usize = 32;
+
+// a frame's variable, which becomes a cell once a closure captures it
+#[derive(Debug)]
+enum Local {
+    Value(Value),
+    Cell(Cell),
+}
+
+fn locals(args: Vec<Value>) -> Vec<Local> {
+    args.into_iter().map(Local::Value).collect()
+}
+
+// the cell for a variable a closure is capturing, which the variable is moved
+// into the first time it's captured
+fn capture_local(locals: &mut [Local], slot: u16) -> Result<Cell, EvaluationError> {
+    let local = locals.get_mut(slot as usize).ok_or(EvaluationError::InvalidSlot(slot))?;
+    if let Local::Value(value) = local {
+        let cell = Rc::new(RefCell::new(mem::replace(value, Value::Nil)));
+        *local = Local::Cell(cell);
+    }
+    match local {
+        Local::Cell(cell) => Ok(Rc::clone(cell)),
+        Local::Value(_) => unreachable!(),
+    }
+}
+
+/// A single function activation: the chunk being run, the position within it,
+/// its variables, and where its values begin on the data stack
+#[derive(Debug)]
+pub struct Frame {
+    chunk: Rc<Chunk>,
+    ip: usize,
+    // slots for the arguments, then for the variables declared in the function
+    locals: Vec<Local>,
+    captured: Vec<Cell>,
+    stack_base: usize,
+    // for each loop being run, the height of the data stack with its iterator on top
+    loops: Vec<usize>,
+    // the errors that the finally blocks being run will throw again
+    pending: Vec<EvaluationError>,
+}
+
+/// Where `print` and `println` write to, which is stdout unless replaced
+pub struct Output(pub Box<dyn Write>);
+
+impl Default for Output {
+    fn default() -> Output {
+        Output(Box::new(io::stdout()))
+    }
+}
+
+impl fmt::Debug for Output {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Output")
+    }
+}
+
+enum GeneratorState {
+    // its frame, and the values it had on the data stack
+    Suspended(Frame, Vec<Value>),
+    Running,
+    Finished,
+}
+
+/// A call to a generator function, which runs a little further each time
+/// it's asked for an item, up to its next `yield`
+pub struct Generator {
+    state: RefCell<GeneratorState>,
+}
+
+impl fmt::Debug for Generator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Generator")
+    }
+}
+
+// generators are only equal to themselves
+impl PartialEq for Generator {
+    fn eq(&self, other: &Generator) -> bool {
+        ptr::eq(self, other)
+    }
+}
+
+#[derive(Debug)]
 pub struct VM {
-    pub code_stack: Vec<Instruction>,
+    pub frames: Vec<Frame>,
     pub data_stack: Vec<Value>,
-    pub heap: Heap,
-    pub symbol_table: SymbolTable,
+    pub globals: Globals,
+    pub max_call_depth: usize,
+    // how many runs of the VM may be nested inside native calls and generators
+    pub max_native_depth: usize,
+    native_depth: usize,
+    pub trace: bool,
+    pub output: Output,
+    // the frame of a generator that has just yielded, its stack and the item it yielded
+    suspended: Option<(Frame, Vec<Value>, Value)>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -25,198 +237,604 @@ pub enum ReturnValue {
     Empty,
 }
 
+impl Default for VM {
+    fn default() -> VM {
+        VM::new()
+    }
+}
+
 impl VM {
     pub fn new() -> VM {
         VM {
-            code_stack: Vec::new(),
+            frames: Vec::new(),
             data_stack: Vec::new(),
-            heap: Vec::with_capacity(1000),
-            symbol_table: HashMap::new(),
+            globals: Globals::new(),
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            max_native_depth: DEFAULT_MAX_NATIVE_DEPTH,
+            native_depth: 0,
+            trace: false,
+            output: Output::default(),
+            suspended: None,
         }
     }
 
-    pub fn from(heap: Heap, symbol_table: SymbolTable) -> VM {
-        VM {
-            heap,
-            symbol_table,
-            code_stack: Vec::new(),
-            data_stack: Vec::new(),
-        }
+    fn frame(&self) -> &Frame {
+        self.frames.last().unwrap()
     }
 
-    pub fn merge(&mut self, child: VM) {
-        for (k, v) in child.symbol_table {
-            if self.symbol_table.contains_key(&k) {
-                self.heap.push(child.heap[v.0].clone());
-                let location = self.heap.len() - 1;
-                self.symbol_table.insert(k, (location, 0));
-            }
-        }
+    fn frame_mut(&mut self) -> &mut Frame {
+        self.frames.last_mut().unwrap()
     }
 
     fn push(&mut self, value: Value) {
         self.data_stack.push(value)
     }
 
+    // a frame may only pop values it pushed itself
+    fn pop(&mut self) -> Result<Value, EvaluationError> {
+        if self.data_stack.len() <= self.frame().stack_base {
+            return Result::Err(EvaluationError::StackUnderflow);
+        }
+        self.data_stack.pop().ok_or(EvaluationError::StackUnderflow)
+    }
+
+    // the operands of a binary operator, left first; the right operand is the
+    // one on top of the stack
+    fn pop_operands(&mut self) -> Result<(Value, Value), EvaluationError> {
+        let b = self.pop()?;
+        let a = self.pop()?;
+        Ok((a, b))
+    }
+
     fn run_instruction(&mut self, ins: &Instruction) -> Result<(), EvaluationError> {
         let mut error: Option<EvaluationError> = None;
         match ins {
-            Instruction::Push(ref v) => {
-                self.push(v.clone());
+            Instruction::Constant(index) => {
+                let value = self.frame().chunk.constants.get(*index as usize).cloned();
+                match value {
+                    Some(value) => self.push(value),
+                    None => error = Some(EvaluationError::InvalidConstant(*index)),
+                }
             }
-            Instruction::AssignAndPush(ref bytes) => {
-                let pointer = Value::Pointer(self.heap.len() as u8);
-                self.heap.push(Value::Bytes(bytes.clone()));
-                self.push(pointer);
+            Instruction::GetLocal(slot) => {
+                let value = match self.frame().locals.get(*slot as usize) {
+                    Some(Local::Value(value)) => value.clone(),
+                    Some(Local::Cell(cell)) => cell.borrow().clone(),
+                    None => return Err(EvaluationError::InvalidSlot(*slot)),
+                };
+                self.push(value)
             }
-            Instruction::Lookup(ref ident) => match self.symbol_table.get(ident) {
-                Some(&(location, _)) => {
-                    let value = &self.heap[location];
-                    self.data_stack.push(value.clone());
+            Instruction::SetLocal(slot) => {
+                let value = self.pop()?;
+                let locals = &mut self.frame_mut().locals;
+                let slot = *slot as usize;
+                // a slot past the end is one that hasn't been declared yet,
+                // and the slots before it may not have been either
+                if slot >= locals.len() {
+                    locals.resize_with(slot + 1, || Local::Value(Value::Nil));
                 }
-                // we'd expect that semantic analyzer to catch these errors, so we'd never expect this error
-                None => error = Some(EvaluationError::MissingVariable(ident.to_string())),
-            },
-            Instruction::SetVar(ref ident) => {
-                let value = self.data_stack.pop().unwrap();
-                self.heap.push(value);
-                let location = self.heap.len() - 1;
-                self.symbol_table.insert(ident.to_string(), (location, 0));
+                locals[slot] = Local::Value(value);
             }
-            Instruction::Add => {
-                let a = self.data_stack.pop().unwrap();
-                let b = self.data_stack.pop().unwrap();
-                match (a, b) {
-                    (Value::Int(x), Value::Int(y)) => self.data_stack.push(Value::Int(x + y)),
-                    (Value::Float(x), Value::Float(y)) => self.data_stack.push(Value::Float(x + y)),
-                    (Value::Int(x), Value::Float(y)) => self.data_stack.push(Value::Float(f64::from(x) + y)),
-                    (Value::Float(x), Value::Int(y)) => self.data_stack.push(Value::Float(x + f64::from(y))), 
-                    (x, y) => {
-                        error = Some(EvaluationError::InvalidOperation(
-                            format!("Can't add {} and {}", pretty_print(&x), pretty_print(&y)),
-                        ));
+            Instruction::AssignLocal(slot) => {
+                let value = self.pop()?;
+                match self.frame_mut().locals.get_mut(*slot as usize) {
+                    Some(Local::Value(local)) => *local = value,
+                    Some(Local::Cell(cell)) => *cell.borrow_mut() = value,
+                    None => error = Some(EvaluationError::InvalidSlot(*slot)),
+                }
+            }
+            Instruction::GetCaptured(index) => {
+                let value = match self.frame().captured.get(*index as usize) {
+                    Some(cell) => cell.borrow().clone(),
+                    None => return Err(EvaluationError::InvalidSlot(*index)),
+                };
+                self.push(value)
+            }
+            Instruction::SetCaptured(index) => {
+                let value = self.pop()?;
+                match self.frame().captured.get(*index as usize) {
+                    Some(cell) => *cell.borrow_mut() = value,
+                    None => error = Some(EvaluationError::InvalidSlot(*index)),
+                }
+            }
+            // globals are only checked when they're run, so that functions can
+            // refer to themselves and to anything defined after they were created
+            Instruction::GetGlobal(index) => {
+                let index = *index as usize;
+                match self.globals.values.get(index) {
+                    Some(Some(value)) => {
+                        let value = value.clone();
+                        self.push(value)
+                    }
+                    // we'd expect that semantic analyzer to catch these errors, so we'd never expect this error
+                    _ => {
+                        let name = self.globals.names.get(index).cloned().unwrap_or_default();
+                        error = Some(EvaluationError::MissingVariable(name))
                     }
                 }
             }
+            Instruction::SetGlobal(index) => {
+                let value = self.pop()?;
+                match self.globals.values.get_mut(*index as usize) {
+                    Some(global) => *global = Some(value),
+                    None => error = Some(EvaluationError::InvalidSlot(*index)),
+                }
+            }
+            Instruction::Pop => {
+                self.pop()?;
+            }
+            Instruction::Add => {
+                let (a, b) = self.pop_operands()?;
+                self.push(arithmetic::add(a, b)?);
+            }
             Instruction::Sub => {
-                let a = self.data_stack.pop().unwrap();
-                let b = self.data_stack.pop().unwrap();
-                match (a, b) {
-                    (Value::Int(x), Value::Int(y)) => self.data_stack.push(Value::Int(x - y)),
-                    (Value::Float(x), Value::Float(y)) => self.data_stack.push(Value::Float(x - y)),
-                    (Value::Int(x), Value::Float(y)) => self.data_stack.push(Value::Float(f64::from(x) - y)),
-                    (Value::Float(x), Value::Int(y)) => self.data_stack.push(Value::Float(x - f64::from(y))), 
-                    (x, y) => {
-                        error = Some(EvaluationError::InvalidOperation(
-                            format!("Can't subtract {} and {}", pretty_print(&x), pretty_print(&y)),
-                        ));
+                let (a, b) = self.pop_operands()?;
+                self.push(arithmetic::sub(a, b)?);
+            }
+            Instruction::Mul => {
+                let (a, b) = self.pop_operands()?;
+                self.push(arithmetic::mul(a, b)?);
+            }
+            Instruction::Div => {
+                let (a, b) = self.pop_operands()?;
+                self.push(arithmetic::div(a, b)?);
+            }
+            Instruction::Mod => {
+                let (a, b) = self.pop_operands()?;
+                self.push(arithmetic::modulo(a, b)?);
+            }
+            Instruction::FloorDiv => {
+                let (a, b) = self.pop_operands()?;
+                self.push(arithmetic::floor_div(a, b)?);
+            }
+            Instruction::Pow => {
+                let (a, b) = self.pop_operands()?;
+                self.push(arithmetic::pow(a, b)?);
+            }
+            Instruction::BitAnd => {
+                let (a, b) = self.pop_operands()?;
+                self.push(arithmetic::bit_and(a, b)?);
+            }
+            Instruction::BitOr => {
+                let (a, b) = self.pop_operands()?;
+                self.push(arithmetic::bit_or(a, b)?);
+            }
+            Instruction::BitXor => {
+                let (a, b) = self.pop_operands()?;
+                self.push(arithmetic::bit_xor(a, b)?);
+            }
+            Instruction::ShiftLeft => {
+                let (a, b) = self.pop_operands()?;
+                self.push(arithmetic::shift_left(a, b)?);
+            }
+            Instruction::ShiftRight => {
+                let (a, b) = self.pop_operands()?;
+                self.push(arithmetic::shift_right(a, b)?);
+            }
+            Instruction::Negate => {
+                let a = self.pop()?;
+                self.push(arithmetic::negate(a)?);
+            }
+            Instruction::BitNot => {
+                let a = self.pop()?;
+                self.push(arithmetic::bit_not(a)?);
+            }
+
+            Instruction::MakeFunction(arity, ref chunk, ref captures) => {
+                let frame = self.frame_mut();
+                let mut captured = Vec::with_capacity(captures.len());
+                for capture in captures {
+                    let cell = match *capture {
+                        Capture::Local(slot) => capture_local(&mut frame.locals, slot)?,
+                        Capture::Captured(index) => match frame.captured.get(index as usize) {
+                            Some(cell) => Rc::clone(cell),
+                            None => return Err(EvaluationError::InvalidSlot(index)),
+                        },
+                    };
+                    captured.push(cell);
+                }
+                self.push(Value::Function(Closure {
+                    arity: *arity,
+                    chunk: Rc::clone(chunk),
+                    captured,
+                }));
+            }
+
+            Instruction::Call(argc) => {
+                let f = self.pop()?;
+                if self.data_stack.len() < self.frame().stack_base + argc {
+                    return Err(EvaluationError::StackUnderflow);
+                }
+
+                let args = self.data_stack.split_off(self.data_stack.len() - argc);
+                match f {
+                    Value::Function(ref closure) if closure.chunk.generator => {
+                        let generator = self.make_generator(closure, args)?;
+                        self.push(generator);
+                    }
+                    // the function's frame is run by the same loop as its caller's
+                    Value::Function(closure) => self.push_frame(closure, args)?,
+                    f => {
+                        let result = self.call(&f, args)?;
+                        self.push(result);
                     }
                 }
             }
-            Instruction::Mul => {
-                let a = self.data_stack.pop().unwrap();
-                let b = self.data_stack.pop().unwrap();
-                match (a, b) {
-                    (Value::Int(x), Value::Int(y)) => self.data_stack.push(Value::Int(x * y)),
-                    (Value::Float(x), Value::Float(y)) => self.data_stack.push(Value::Float(x * y)),
-                    (Value::Int(x), Value::Float(y)) => self.data_stack.push(Value::Float(f64::from(x) * y)),
-                    (Value::Float(x), Value::Int(y)) => self.data_stack.push(Value::Float(x * f64::from(y))), 
-                    (x, y) => {
-                        error = Some(EvaluationError::InvalidOperation(
-                            format!("Can't multiply {} and {}", pretty_print(&x), pretty_print(&y)),
-                        ));
+
+            Instruction::MakeRange(inclusive) => {
+                let end = self.pop()?;
+                let start = self.pop()?;
+                match (&start, &end) {
+                    (Value::Int(start), Value::Int(end)) => self.push(Value::Range(Range {
+                        start: *start,
+                        end: *end,
+                        inclusive: *inclusive,
+                    })),
+                    _ => {
+                        error = Some(EvaluationError::InvalidOperation(format!(
+                            "Can't make a range from {} to {}",
+                            pretty_print(&start),
+                            pretty_print(&end)
+                        )))
                     }
                 }
             }
-            Instruction::Div => {
-                let a = self.data_stack.pop().unwrap();
-                let b = self.data_stack.pop().unwrap();
-                match (a, b) {
-                    (Value::Int(x), Value::Int(y)) => self.data_stack.push(Value::Int(x / y)),
-                    (Value::Float(x), Value::Float(y)) => self.data_stack.push(Value::Float(x / y)),
-                    (Value::Int(x), Value::Float(y)) => self.data_stack.push(Value::Float(f64::from(x) / y)),
-                    (Value::Float(x), Value::Int(y)) => self.data_stack.push(Value::Float(x / f64::from(y))), 
-                    (x, y) => {
-                        error = Some(EvaluationError::InvalidOperation(
-                            format!("Can't divide {} and {}", pretty_print(&x), pretty_print(&y)),
-                        ));
+            Instruction::Jump(offset) => self.jump(*offset),
+            Instruction::GetIter => {
+                let iterable = self.pop()?;
+                self.push(VM::iterate(iterable)?);
+                let height = self.data_stack.len();
+                self.frame_mut().loops.push(height);
+            }
+            Instruction::ForIter(offset) => {
+                let item = match self.data_stack.last_mut() {
+                    Some(Value::Iterator(iter)) => iter.advance(),
+                    Some(Value::Generator(generator)) => {
+                        let generator = Rc::clone(generator);
+                        self.resume(&generator)?
                     }
+                    _ => return Err(EvaluationError::InvalidOperation("No loop to run".to_string())),
+                };
+                match item {
+                    Some(item) => self.push(item),
+                    None => self.jump(*offset),
                 }
             }
+            Instruction::Break(offset) | Instruction::Continue(offset) => {
+                match self.frame().loops.last() {
+                    Some(&height) => self.data_stack.truncate(height),
+                    None => return Err(EvaluationError::InvalidOperation("No loop to leave".to_string())),
+                }
+                self.jump(*offset);
+            }
+            Instruction::EndLoop => {
+                self.pop()?;
+                self.frame_mut().loops.pop();
+            }
+            // the generator's frame and the values it pushed are set aside
+            // until it's resumed, which is what returns the item
+            Instruction::Yield => {
+                let item = self.pop()?;
+                let mut frame = self.frames.pop().unwrap();
+                let stack = self.data_stack.split_off(frame.stack_base);
+                for height in &mut frame.loops {
+                    *height -= frame.stack_base;
+                }
+                self.suspended = Some((frame, stack, item));
+            }
+            Instruction::Throw => return Err(EvaluationError::Thrown(self.pop()?)),
+            Instruction::EndFinally => match self.frame_mut().pending.pop() {
+                Some(e) => return Err(e),
+                None => return Err(EvaluationError::InvalidOperation("No error to throw".to_string())),
+            },
+        }
+        match error {
+            Some(e) => Result::Err(e),
+            None => Result::Ok(()),
+        }
+    }
 
-            &Instruction::MakeFunction(ref function_stack) => {
-                let function = Value::Function(
-                    function_stack.clone(),
-                    // TODO: consider implementation without cloning the heap and symbol tables
-                    self.heap.clone(),
-                    self.symbol_table.clone(),
-                );
-                self.data_stack.push(function);
+    /// What a loop goes through for a value: lists, the keys of maps, the
+    /// chars of strings, ranges and generators
+    pub fn iterate(iterable: Value) -> Result<Value, EvaluationError> {
+        let iter = match iterable {
+            Value::List(items) => Iter::List(items, 0),
+            // maps are iterated over by key, in order
+            Value::Map(entries) => {
+                let keys = entries.keys().map(|k| Value::String(Rc::from(k.as_str())));
+                Iter::List(Rc::new(keys.collect()), 0)
             }
+            Value::String(s) => Iter::Chars(s, 0),
+            Value::Range(range) => {
+                let (start, end) = range.bounds();
+                Iter::Range(start, end)
+            }
+            // generators are their own iterators
+            Value::Generator(generator) => return Ok(Value::Generator(generator)),
+            value => {
+                return Err(EvaluationError::InvalidOperation(format!(
+                    "Can't iterate over {}",
+                    pretty_print(&value)
+                )))
+            }
+        };
+        Ok(Value::Iterator(iter))
+    }
 
-            &Instruction::Call => {
-                let f = self.data_stack.pop().unwrap();
+    /// The next item of something `iterate` returned, or `None` once it's finished
+    pub fn next_item(&mut self, iterator: &mut Value) -> Result<Option<Value>, EvaluationError> {
+        match iterator {
+            Value::Iterator(iter) => Ok(iter.advance()),
+            Value::Generator(generator) => self.resume(generator),
+            value => Err(EvaluationError::InvalidOperation(format!(
+                "Can't iterate over {}",
+                pretty_print(value)
+            ))),
+        }
+    }
 
-                if let Value::Function(instructions, heap, symbol_table) = f {
-                    let mut vm = VM::from(heap, symbol_table);
-                    let result = vm.run(instructions);
+    fn make_generator(&mut self, closure: &Closure, args: Vec<Value>) -> Result<Value, EvaluationError> {
+        if closure.arity != args.len() {
+            return Err(EvaluationError::IncorrectArity(closure.arity, args.len()));
+        }
+        let frame = Frame {
+            chunk: Rc::clone(&closure.chunk),
+            ip: 0,
+            locals: locals(args),
+            captured: closure.captured.clone(),
+            stack_base: 0,
+            loops: Vec::new(),
+            pending: Vec::new(),
+        };
+        Ok(Value::Generator(Rc::new(Generator {
+            state: RefCell::new(GeneratorState::Suspended(frame, Vec::new())),
+        })))
+    }
 
-                    if let Result::Ok(ReturnValue::Value(v)) = result {
-                        self.data_stack.push(v);
-                    }
+    // runs a generator up to its next `yield`, or to the end of its function
+    fn resume(&mut self, generator: &Generator) -> Result<Option<Value>, EvaluationError> {
+        let state = mem::replace(&mut *generator.state.borrow_mut(), GeneratorState::Running);
+        let (mut frame, stack) = match state {
+            GeneratorState::Suspended(frame, stack) => (frame, stack),
+            GeneratorState::Running => {
+                return Err(EvaluationError::InvalidOperation(
+                    "Can't resume a generator that's already running".to_string(),
+                ))
+            }
+            GeneratorState::Finished => {
+                *generator.state.borrow_mut() = GeneratorState::Finished;
+                return Ok(None);
+            }
+        };
+        let depth = self.frames.len() - 1;
+        if depth >= self.max_call_depth || self.native_depth >= self.max_native_depth {
+            *generator.state.borrow_mut() = GeneratorState::Suspended(frame, stack);
+            return Err(EvaluationError::StackOverflow(depth));
+        }
+
+        let stack_base = self.data_stack.len();
+        frame.stack_base = stack_base;
+        for height in &mut frame.loops {
+            *height += stack_base;
+        }
+        self.data_stack.extend(stack);
+        self.frames.push(frame);
+
+        let result = self.execute_nested(depth + 2);
+        let (state, result) = match (result, self.suspended.take()) {
+            (Ok(()), Some((frame, stack, item))) => {
+                (GeneratorState::Suspended(frame, stack), Ok(Some(item)))
+            }
+            // the generator's function has finished, or failed
+            (result, _) => {
+                self.frames.truncate(depth + 1);
+                self.data_stack.truncate(stack_base);
+                (GeneratorState::Finished, result.map(|()| None))
+            }
+        };
+        *generator.state.borrow_mut() = state;
+        result
+    }
+
+    fn jump(&mut self, offset: isize) {
+        let frame = self.frame_mut();
+        // the instruction pointer has already moved past the jump
+        frame.ip = (frame.ip as isize - 1 + offset) as usize;
+    }
 
-                    self.merge(vm);
+    fn push_frame(&mut self, closure: Closure, args: Vec<Value>) -> Result<(), EvaluationError> {
+        let depth = self.frames.len() - 1;
+        if closure.arity != args.len() {
+            return Err(EvaluationError::IncorrectArity(closure.arity, args.len()));
+        }
+        if depth >= self.max_call_depth {
+            return Err(EvaluationError::StackOverflow(depth));
+        }
+        let stack_base = self.data_stack.len();
+        self.frames.push(Frame {
+            chunk: closure.chunk,
+            ip: 0,
+            locals: locals(args),
+            captured: closure.captured,
+            stack_base,
+            loops: Vec::new(),
+            pending: Vec::new(),
+        });
+        Ok(())
+    }
+
+    /// Calls a function value from Rust, such as a native function calling a
+    /// function it was given, and returns its result once it's finished
+    pub fn call(&mut self, f: &Value, args: Vec<Value>) -> Result<Value, EvaluationError> {
+        match f {
+            Value::Function(closure) if closure.chunk.generator => self.make_generator(closure, args),
+            Value::Function(closure) => {
+                let depth = self.frames.len();
+                if self.native_depth >= self.max_native_depth {
+                    return Err(EvaluationError::StackOverflow(depth - 1));
+                }
+                let stack_base = self.data_stack.len();
+                self.push_frame(closure.clone(), args)?;
+                match self.execute_nested(depth + 1) {
+                    Ok(()) => {
+                        self.return_from_call();
+                        Ok(self.data_stack.pop().unwrap())
+                    }
+                    // the frames the call left behind are discarded, so that
+                    // the caller can carry on if it chooses to
+                    Err(e) => {
+                        self.frames.truncate(depth);
+                        self.data_stack.truncate(stack_base);
+                        Err(e)
+                    }
                 }
             }
+            Value::NativeFunction(native) => match native.arity {
+                Some(arity) if arity != args.len() => {
+                    Err(EvaluationError::IncorrectArity(arity, args.len()))
+                }
+                _ => (native.function)(self, &args),
+            },
+            Value::Builtin(builtin) => self.call_builtin(*builtin, args),
+            f => Err(EvaluationError::InvalidOperation(format!(
+                "Can't call {}",
+                pretty_print(f)
+            ))),
         }
-        match error {
-            Some(e) => Result::Err(e),
-            None => Result::Ok(()),
+    }
+
+    fn call_builtin(&mut self, builtin: Builtin, args: Vec<Value>) -> Result<Value, EvaluationError> {
+        match builtin {
+            // arguments are separated by spaces
+            Builtin::Print | Builtin::Println => {
+                let text: Vec<String> = args.iter().map(display).collect();
+                let output = &mut self.output.0;
+                let written = if builtin == Builtin::Println {
+                    writeln!(output, "{}", text.join(" "))
+                } else {
+                    write!(output, "{}", text.join(" "))
+                };
+                written.map_err(|e| EvaluationError::IoError(e.to_string()))?;
+                Ok(Value::Nil)
+            }
         }
     }
 
-    pub fn run(&mut self, code_stack: Vec<Instruction>) -> Result<ReturnValue, EvaluationError> {
-        self.code_stack = code_stack;
+    // every call leaves exactly one value for its caller, which is nil when
+    // the function's body ended in a statement rather than an expression
+    fn return_from_call(&mut self) {
+        let frame = self.frames.pop().unwrap();
 
-        // TODO: move to cli argument
-        let debug = true;
+        let result = if self.data_stack.len() > frame.stack_base {
+            self.data_stack.pop().unwrap()
+        } else {
+            Value::Nil
+        };
+        self.data_stack.truncate(frame.stack_base);
+        self.push(result);
+    }
 
-        let mut error: Option<EvaluationError> = None;
+    // finds the handler for an error that covers the instruction being run,
+    // leaving every frame above it; frames below `depth` belong to whoever
+    // called `execute`, which gets the error back if nothing above it handles it
+    fn unwind(&mut self, error: EvaluationError, depth: usize) -> Result<(), EvaluationError> {
+        if !error.is_catchable() {
+            return Err(error);
+        }
         loop {
-            if self.code_stack.is_empty() {
-                break;
-            }
-            if error.is_some() {
-                break;
+            let frame = self.frame();
+            // the instruction pointer has already moved past the failed
+            // instruction, which for the callers is their call
+            let ip = frame.ip - 1;
+            let handler = frame.chunk.handlers.iter().find(|h| h.start <= ip && ip < h.end);
+            if let Some(handler) = handler.cloned() {
+                let height = frame.stack_base + handler.depth;
+                self.data_stack.truncate(height);
+                let frame = self.frame_mut();
+                frame.loops.retain(|&loop_height| loop_height <= height);
+                frame.ip = handler.target;
+                if handler.catches {
+                    self.push(error.into_value());
+                } else {
+                    frame.pending.push(error);
+                }
+                return Ok(());
             }
-            let ins = self.code_stack.remove(0);
-            match self.run_instruction(&ins) {
-                Ok(_) => (),
-                Err(e) => error = Some(e),
+            if self.frames.len() == depth {
+                return Err(error);
             }
-            if debug {
-                println!("========");
-                println!("INS: {:?}", ins);
-                println!("CODE: {:?}", self.code_stack);
-                println!("DATA: {:?}", self.data_stack);
-                println!("SYMBOL TABLE: {:?}", self.symbol_table);
-                println!("HEAP: {:?}", self.heap);
+            let frame = self.frames.pop().unwrap();
+            self.data_stack.truncate(frame.stack_base);
+        }
+    }
+
+    // runs until the function in the frame at `depth` has finished, leaving
+    // its frame for the caller to return from
+    fn execute(&mut self, depth: usize) -> Result<(), EvaluationError> {
+        loop {
+            let (chunk, ip) = {
+                let frame = self.frame();
+                (Rc::clone(&frame.chunk), frame.ip)
+            };
+            match chunk.code.get(ip) {
+                Some(ins) => {
+                    self.frame_mut().ip += 1;
+                    if let Err(e) = self.run_instruction(ins) {
+                        self.unwind(e, depth)?;
+                        continue;
+                    }
+                    // the generator's frame is gone, so its caller takes over
+                    if let Instruction::Yield = ins {
+                        return Result::Ok(());
+                    }
+                    if self.trace {
+                        println!("========");
+                        println!("INS: {:?}", ins);
+                        println!("DEPTH: {}", self.frames.len() - 1);
+                        println!("DATA: {:?}", self.data_stack);
+                        println!("LOCALS: {:?}", self.frame().locals);
+                    }
+                }
+                None if self.frames.len() == depth => return Result::Ok(()),
+                None => self.return_from_call(),
             }
         }
-        match error {
-            Some(e) => Result::Err(e),
-            None => {
+    }
+
+    // runs the VM from inside a native call or a generator, which the caller
+    // has already checked `max_native_depth` allows
+    fn execute_nested(&mut self, depth: usize) -> Result<(), EvaluationError> {
+        self.native_depth += 1;
+        let result = self.execute(depth);
+        self.native_depth -= 1;
+        result
+    }
+
+    pub fn run(&mut self, chunk: Chunk) -> Result<ReturnValue, EvaluationError> {
+        self.frames.push(Frame {
+            chunk: Rc::new(chunk),
+            ip: 0,
+            locals: Vec::new(),
+            captured: Vec::new(),
+            stack_base: self.data_stack.len(),
+            loops: Vec::new(),
+            pending: Vec::new(),
+        });
+
+        let result = self.execute(1);
+
+        self.frames.clear();
+
+        match result {
+            Err(e) => {
+                self.data_stack.clear();
+                Result::Err(e)
+            }
+            Ok(()) => {
                 let value = self.data_stack.pop();
                 match value {
-                    Option::Some(Value::Pointer(p)) => {
-                        let heaped = &self.heap[p as usize];
-                        match heaped {
-                            Value::Bytes(ref bytes) => Result::Ok(ReturnValue::DisplayString(
-                                str::from_utf8(bytes).unwrap().to_string(),
-                            )),
-                            _ => Result::Ok(ReturnValue::Value(heaped.clone())),
-                        }
-                    }
+                    Option::Some(Value::String(s)) => Result::Ok(ReturnValue::DisplayString(format!("\"{}\"", s))),
+                    Option::Some(Value::Nil) => Result::Ok(ReturnValue::Empty),
                     Option::Some(v) => Result::Ok(ReturnValue::Value(v)),
                     Option::None => Result::Ok(ReturnValue::Empty),
                 }